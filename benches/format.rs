@@ -0,0 +1,56 @@
+//! Compares formatting addresses with `to_string` to writing them into a stack buffer with `write_to`.
+//!
+//! Run with `cargo bench --bench format`. Each line reports the mean time per address over the whole corpus.
+
+use address::{IPv4Address, IPv6Address, SocketAddressV6, SocketAddressV6Text};
+use std::hint::black_box;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::time::{Duration, Instant};
+
+/// The number of passes over the corpus per measurement.
+const ROUNDS: usize = 2_000;
+
+fn main() {
+    let ipv4: Vec<IPv4Address> = (0..1_000u32)
+        .map(|i| IPv4Address::from(Ipv4Addr::from(i.wrapping_mul(2_654_435_761))))
+        .collect();
+    let sockets: Vec<SocketAddressV6> = (0..1_000u128)
+        .map(|i| {
+            let bits: u128 = i.wrapping_mul(0x9E37_79B9_7F4A_7C15_F39C_C060_5CED_C835);
+            IPv6Address::from(Ipv6Addr::from(if i % 2 == 0 { bits >> 64 } else { bits })).to_socket(i as u16)
+        })
+        .collect();
+
+    let mut buffer: [u8; SocketAddressV6Text::CAPACITY] = [0; SocketAddressV6Text::CAPACITY];
+
+    let to_string: Duration = measure(&ipv4, |ip| ip.to_string().len());
+    let write_to: Duration = measure(&ipv4, |ip| ip.write_to(&mut buffer));
+    report("ipv4", ipv4.len(), to_string, write_to);
+
+    let to_string: Duration = measure(&sockets, |socket| socket.to_string().len());
+    let write_to: Duration = measure(&sockets, |socket| socket.write_to(&mut buffer));
+    report("socket v6", sockets.len(), to_string, write_to);
+}
+
+/// Measures `ROUNDS` passes of the `format` function over the `corpus`.
+fn measure<T, F: FnMut(&T) -> usize>(corpus: &[T], mut format: F) -> Duration {
+    let start: Instant = Instant::now();
+    for _ in 0..ROUNDS {
+        for value in corpus {
+            black_box(format(black_box(value)));
+        }
+    }
+    start.elapsed()
+}
+
+/// Prints the time per address of each formatter & the speedup of the stack buffer.
+fn report(name: &str, len: usize, to_string: Duration, write_to: Duration) {
+    let count: f64 = (len * ROUNDS) as f64;
+    println!(
+        "{}: to_string {:.1} ns, write_to {:.1} ns, speedup {:.2}x",
+        name,
+        to_string.as_nanos() as f64 / count,
+        write_to.as_nanos() as f64 / count,
+        to_string.as_secs_f64() / write_to.as_secs_f64()
+    );
+}
@@ -0,0 +1,94 @@
+//! Measures the memory use & clone time of the owned address types.
+//!
+//! Run with `cargo bench --bench owned`. A counting allocator reports the heap use of a `Vec<Authority>`, & each
+//! clone line reports the mean time per element.
+
+use address::{Authority, Domain, Endpoint, Host};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::mem::size_of;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// The number of clones of each corpus per measurement.
+const ROUNDS: usize = 1_000;
+
+/// The system allocator, counting its allocations & allocated bytes.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn main() {
+    println!(
+        "size: Domain {} B, Host {} B, Endpoint {} B, Authority {} B",
+        size_of::<Domain>(),
+        size_of::<Host>(),
+        size_of::<Endpoint>(),
+        size_of::<Authority>()
+    );
+
+    let short: Vec<String> = (0..1_000).map(|i| format!("host-{}.example.com:443", i)).collect();
+    let long: Vec<String> = (0..1_000)
+        .map(|i| format!("service-{}.internal.us-east-1.example.com:443", i))
+        .collect();
+
+    for (name, corpus) in [("short names", &short), ("long names", &long)] {
+        let (authorities, allocations, bytes): (Vec<Authority>, usize, usize) = count(|| {
+            corpus
+                .iter()
+                .map(|text| text.parse().unwrap())
+                .collect::<Vec<Authority>>()
+        });
+        println!(
+            "{}: {} allocations, {} heap bytes for {} authorities",
+            name,
+            allocations,
+            bytes,
+            authorities.len()
+        );
+
+        let time: Duration = measure(|| black_box(authorities.clone()));
+        println!(
+            "{}: clone {:.1} ns per authority",
+            name,
+            time.as_nanos() as f64 / (ROUNDS * authorities.len()) as f64
+        );
+    }
+}
+
+/// Runs the `f` function, counting its allocations & allocated bytes.
+fn count<T, F: FnOnce() -> T>(f: F) -> (T, usize, usize) {
+    let allocations: usize = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes: usize = BYTES.load(Ordering::Relaxed);
+    let value: T = f();
+    (
+        value,
+        ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        BYTES.load(Ordering::Relaxed) - bytes,
+    )
+}
+
+/// Measures `ROUNDS` calls of the `f` function.
+fn measure<T, F: Fn() -> T>(f: F) -> Duration {
+    let start: Instant = Instant::now();
+    for _ in 0..ROUNDS {
+        drop(f());
+    }
+    start.elapsed()
+}
@@ -0,0 +1,74 @@
+//! Measures the domain & host parsers over a corpus of mixed-case names, lowercase names, & IP addresses.
+//!
+//! Run with `cargo bench --bench parse_host`. Each line reports the mean time per input over the whole corpus.
+
+use address::{Domain, DomainRef, Host, HostRef, InlineHost};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// The number of passes over the corpus per measurement.
+const ROUNDS: usize = 2_000;
+
+fn main() {
+    let labels: &[&str] = &[
+        "www",
+        "api",
+        "Mail",
+        "cdn-01",
+        "EXAMPLE",
+        "service",
+        "us-east-1",
+        "internal",
+        "com",
+    ];
+    let names: Vec<String> = (0..1_000usize)
+        .map(|i| {
+            let count: usize = 2 + i % 3;
+            (0..count)
+                .map(|j| labels[(i * 7 + j * 3) % labels.len()])
+                .collect::<Vec<&str>>()
+                .join(".")
+        })
+        .collect();
+    let lowercase: Vec<String> = names.iter().map(|name| name.to_ascii_lowercase()).collect();
+    let hosts: Vec<String> = (0..1_000usize)
+        .map(|i| match i % 4 {
+            0 => format!("10.{}.{}.{}", i % 256, (i / 7) % 256, (i / 3) % 256),
+            1 => format!("2001:db8::{:x}", i),
+            _ => names[i].clone(),
+        })
+        .collect();
+
+    let time: Duration = measure(&names, |text| Domain::parse_text(text).is_ok());
+    report("Domain (mixed case)", names.len(), time);
+    let time: Duration = measure(&lowercase, |text| Domain::parse_text(text).is_ok());
+    report("Domain (lowercase)", lowercase.len(), time);
+    let time: Duration = measure(&lowercase, |text| DomainRef::parse_text(text).is_ok());
+    report("DomainRef", lowercase.len(), time);
+    let time: Duration = measure(&hosts, |text| Host::parse_text(text).is_ok());
+    report("Host", hosts.len(), time);
+    let time: Duration = measure(&hosts, |text| InlineHost::parse_text(text).is_ok());
+    report("InlineHost", hosts.len(), time);
+    let lowercase_hosts: Vec<String> = hosts.iter().map(|host| host.to_ascii_lowercase()).collect();
+    let time: Duration = measure(&lowercase_hosts, |text| HostRef::parse_text(text).is_ok());
+    report("HostRef", lowercase_hosts.len(), time);
+}
+
+/// Measures `ROUNDS` passes of the `parse` function over the `corpus`, after one warm-up pass.
+fn measure<F: Fn(&[u8]) -> bool>(corpus: &[String], parse: F) -> Duration {
+    for text in corpus {
+        assert!(parse(text.as_bytes()), "text={}", text);
+    }
+    let start: Instant = Instant::now();
+    for _ in 0..ROUNDS {
+        for text in corpus {
+            black_box(parse(black_box(text.as_bytes())));
+        }
+    }
+    start.elapsed()
+}
+
+/// Prints the time per input.
+fn report(name: &str, len: usize, time: Duration) {
+    println!("{}: {:.1} ns", name, time.as_nanos() as f64 / (len * ROUNDS) as f64);
+}
@@ -0,0 +1,67 @@
+//! Compares the byte-level IP address parsers to the standard library's `FromStr` parsers.
+//!
+//! The corpus is bytes, as read from a log, so the standard library parsers first validate the UTF-8; this is how
+//! the crate parsed addresses before its parsers read the bytes directly.
+//!
+//! Run with `cargo bench --bench parse_ip`. Each line reports the mean time per address over the whole corpus.
+
+use address::{IPv4Address, IPv6Address};
+use std::hint::black_box;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::{FromStr, from_utf8};
+use std::time::{Duration, Instant};
+
+/// The number of passes over the corpus per measurement.
+const ROUNDS: usize = 2_000;
+
+fn main() {
+    let ipv4: Vec<Vec<u8>> = (0..1_000u32)
+        .map(|i| Ipv4Addr::from(i.wrapping_mul(2_654_435_761)).to_string().into_bytes())
+        .collect();
+    let ipv6: Vec<Vec<u8>> = (0..1_000u128)
+        .map(|i| {
+            let bits: u128 = i.wrapping_mul(0x9E37_79B9_7F4A_7C15_F39C_C060_5CED_C835);
+            Ipv6Addr::from(if i % 2 == 0 { bits >> 64 } else { bits })
+                .to_string()
+                .into_bytes()
+        })
+        .collect();
+
+    let std_v4: Duration = measure(&ipv4, |text| {
+        from_utf8(text).is_ok_and(|text| Ipv4Addr::from_str(text).is_ok())
+    });
+    let ours_v4: Duration = measure(&ipv4, |text| IPv4Address::parse_text(text).is_ok());
+    report("ipv4", ipv4.len(), std_v4, ours_v4);
+
+    let std_v6: Duration = measure(&ipv6, |text| {
+        from_utf8(text).is_ok_and(|text| Ipv6Addr::from_str(text).is_ok())
+    });
+    let ours_v6: Duration = measure(&ipv6, |text| IPv6Address::parse_text(text).is_ok());
+    report("ipv6", ipv6.len(), std_v6, ours_v6);
+}
+
+/// Measures `ROUNDS` passes of the `parse` function over the `corpus`, after one warm-up pass.
+fn measure<F: Fn(&[u8]) -> bool>(corpus: &[Vec<u8>], parse: F) -> Duration {
+    for text in corpus {
+        assert!(parse(text.as_slice()), "text={:?}", text);
+    }
+    let start: Instant = Instant::now();
+    for _ in 0..ROUNDS {
+        for text in corpus {
+            black_box(parse(black_box(text.as_slice())));
+        }
+    }
+    start.elapsed()
+}
+
+/// Prints the time per address of each parser & the speedup of the byte-level parser.
+fn report(name: &str, len: usize, std: Duration, ours: Duration) {
+    let count: f64 = (len * ROUNDS) as f64;
+    println!(
+        "{}: std {:.1} ns, address {:.1} ns, speedup {:.2}x",
+        name,
+        std.as_nanos() as f64 / count,
+        ours.as_nanos() as f64 / count,
+        std.as_secs_f64() / ours.as_secs_f64()
+    );
+}
@@ -0,0 +1,102 @@
+use crate::{AddressKind, AddressRef, Domain, Endpoint, IPAddress, SocketAddress};
+
+/// Any address: an [IPAddress], a [SocketAddress], a [Domain], or an [Endpoint].
+///
+/// Parsing classifies the text by the precedence rules of [`Self::parse_text`].
+#[must_use]
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Address {
+    /// An [IPAddress].
+    IPAddress(IPAddress),
+
+    /// A [SocketAddress].
+    SocketAddress(SocketAddress),
+
+    /// A [Domain].
+    Domain(Domain),
+
+    /// An [Endpoint].
+    Endpoint(Endpoint),
+}
+
+impl<'a> PartialEq<AddressRef<'a>> for Address {
+    fn eq(&self, other: &AddressRef<'a>) -> bool {
+        self.to_ref() == *other
+    }
+}
+
+impl Address {
+    //! Properties
+
+    /// Gets the kind of address.
+    #[must_use]
+    pub const fn kind(&self) -> AddressKind {
+        match self {
+            Self::IPAddress(_) => AddressKind::IPAddress,
+            Self::SocketAddress(_) => AddressKind::SocketAddress,
+            Self::Domain(_) => AddressKind::Domain,
+            Self::Endpoint(_) => AddressKind::Endpoint,
+        }
+    }
+}
+
+impl Address {
+    //! Matching
+
+    /// Checks if the address is an IP address.
+    #[must_use]
+    pub const fn is_ip(&self) -> bool {
+        matches!(self, Self::IPAddress(_))
+    }
+
+    /// Checks if the address is a socket address.
+    #[must_use]
+    pub const fn is_socket(&self) -> bool {
+        matches!(self, Self::SocketAddress(_))
+    }
+
+    /// Checks if the address is a domain.
+    #[must_use]
+    pub const fn is_domain(&self) -> bool {
+        matches!(self, Self::Domain(_))
+    }
+
+    /// Checks if the address is an endpoint.
+    #[must_use]
+    pub const fn is_endpoint(&self) -> bool {
+        matches!(self, Self::Endpoint(_))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Address, AddressKind, AddressRef, Domain, DomainRef, IPv4Address, IPv6Address};
+
+    #[test]
+    fn kind() {
+        let test_cases: &[(Address, AddressKind)] = &[
+            (IPv4Address::LOCALHOST.to_ip().into(), AddressKind::IPAddress),
+            (
+                IPv6Address::LOCALHOST.to_socket(80).to_socket().into(),
+                AddressKind::SocketAddress,
+            ),
+            (Domain::localhost().into(), AddressKind::Domain),
+            (Domain::localhost().to_endpoint(80).into(), AddressKind::Endpoint),
+        ];
+
+        for (address, expected) in test_cases {
+            assert_eq!(address.kind(), *expected, "address={}", address);
+            assert_eq!(address.to_ref().kind(), *expected, "address={}", address);
+        }
+    }
+
+    #[test]
+    fn matching() {
+        let address: Address = Domain::localhost().into();
+        assert!(address.is_domain());
+        assert!(!address.is_ip());
+        assert!(!address.is_socket());
+        assert!(!address.is_endpoint());
+        assert_eq!(address, AddressRef::Domain(DomainRef::LOCALHOST));
+    }
+}
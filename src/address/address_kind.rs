@@ -0,0 +1,60 @@
+use core::fmt::{Display, Formatter};
+
+/// The kind of an [Address](crate::Address) or an [AddressRef](crate::AddressRef).
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum AddressKind {
+    /// An IPv4 or IPv6 address: `127.0.0.1` or `::1`.
+    IPAddress,
+
+    /// A socket address: `127.0.0.1:80` or `[::1]:80`.
+    SocketAddress,
+
+    /// A domain: `localhost`.
+    Domain,
+
+    /// An endpoint: `localhost:80`.
+    Endpoint,
+}
+
+impl AddressKind {
+    //! Properties
+
+    /// Checks if addresses of the kind have a port.
+    #[must_use]
+    pub const fn has_port(self) -> bool {
+        matches!(self, Self::SocketAddress | Self::Endpoint)
+    }
+}
+
+impl Display for AddressKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let s: &str = match self {
+            Self::IPAddress => "IP address",
+            Self::SocketAddress => "socket address",
+            Self::Domain => "domain",
+            Self::Endpoint => "endpoint",
+        };
+        f.pad(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::AddressKind;
+    use alloc::format;
+    use alloc::string::ToString;
+
+    #[test]
+    fn has_port() {
+        assert!(!AddressKind::IPAddress.has_port());
+        assert!(AddressKind::SocketAddress.has_port());
+        assert!(!AddressKind::Domain.has_port());
+        assert!(AddressKind::Endpoint.has_port());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(AddressKind::IPAddress.to_string(), "IP address");
+        assert_eq!(format!("{:>8}", AddressKind::Domain), "  domain");
+    }
+}
@@ -0,0 +1,86 @@
+#[cfg(feature = "alloc")]
+use crate::Address;
+use crate::{AddressKind, DomainRef, EndpointRef, IPAddress, SocketAddress};
+
+/// Any address reference: an [IPAddress], a [SocketAddress], a [DomainRef], or an [EndpointRef].
+///
+/// Parsing classifies the text by the precedence rules of [`Self::parse_text`].
+#[must_use]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum AddressRef<'a> {
+    /// An [IPAddress].
+    IPAddress(IPAddress),
+
+    /// A [SocketAddress].
+    SocketAddress(SocketAddress),
+
+    /// A [DomainRef].
+    Domain(DomainRef<'a>),
+
+    /// An [EndpointRef].
+    Endpoint(EndpointRef<'a>),
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> PartialEq<Address> for AddressRef<'a> {
+    fn eq(&self, other: &Address) -> bool {
+        *self == other.to_ref()
+    }
+}
+
+impl<'a> AddressRef<'a> {
+    //! Properties
+
+    /// Gets the kind of address.
+    #[must_use]
+    pub const fn kind(self) -> AddressKind {
+        match self {
+            Self::IPAddress(_) => AddressKind::IPAddress,
+            Self::SocketAddress(_) => AddressKind::SocketAddress,
+            Self::Domain(_) => AddressKind::Domain,
+            Self::Endpoint(_) => AddressKind::Endpoint,
+        }
+    }
+}
+
+impl<'a> AddressRef<'a> {
+    //! Matching
+
+    /// Checks if the address is an IP address.
+    #[must_use]
+    pub const fn is_ip(self) -> bool {
+        matches!(self, Self::IPAddress(_))
+    }
+
+    /// Checks if the address is a socket address.
+    #[must_use]
+    pub const fn is_socket(self) -> bool {
+        matches!(self, Self::SocketAddress(_))
+    }
+
+    /// Checks if the address is a domain.
+    #[must_use]
+    pub const fn is_domain(self) -> bool {
+        matches!(self, Self::Domain(_))
+    }
+
+    /// Checks if the address is an endpoint.
+    #[must_use]
+    pub const fn is_endpoint(self) -> bool {
+        matches!(self, Self::Endpoint(_))
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::{Address, Domain};
+    use crate::{AddressRef, DomainRef, EndpointRef};
+
+    #[test]
+    fn matching() {
+        let address: AddressRef = AddressRef::Endpoint(EndpointRef::new(DomainRef::LOCALHOST, 80));
+        assert!(address.is_endpoint());
+        assert!(!address.is_domain());
+        assert_eq!(address, Address::Endpoint(Domain::localhost().to_endpoint(80)));
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{Address, AddressRef, Authority, Domain, Endpoint, Host, IPAddress, SocketAddress};
+
+impl Address {
+    //! Conversions
+
+    /// Converts the address to an address reference.
+    pub fn to_ref(&self) -> AddressRef<'_> {
+        match self {
+            Self::IPAddress(ip) => AddressRef::IPAddress(*ip),
+            Self::SocketAddress(socket) => AddressRef::SocketAddress(*socket),
+            Self::Domain(domain) => AddressRef::Domain(domain.to_ref()),
+            Self::Endpoint(endpoint) => AddressRef::Endpoint(endpoint.to_ref()),
+        }
+    }
+
+    /// Converts the address to an optional IP address.
+    #[must_use]
+    pub const fn to_ip(&self) -> Option<IPAddress> {
+        if let Self::IPAddress(ip) = self {
+            Some(*ip)
+        } else {
+            None
+        }
+    }
+
+    /// Converts the address to an optional socket address.
+    #[must_use]
+    pub const fn to_socket(&self) -> Option<SocketAddress> {
+        if let Self::SocketAddress(socket) = self {
+            Some(*socket)
+        } else {
+            None
+        }
+    }
+
+    /// Converts the address to an optional domain.
+    #[must_use]
+    pub fn to_domain(self) -> Option<Domain> {
+        if let Self::Domain(domain) = self {
+            Some(domain)
+        } else {
+            None
+        }
+    }
+
+    /// Converts the address to an optional endpoint.
+    #[must_use]
+    pub fn to_endpoint(self) -> Option<Endpoint> {
+        if let Self::Endpoint(endpoint) = self {
+            Some(endpoint)
+        } else {
+            None
+        }
+    }
+
+    /// Converts the address to an optional host. (an IP address or a domain)
+    #[must_use]
+    pub fn to_host(self) -> Option<Host> {
+        match self {
+            Self::IPAddress(ip) => Some(ip.to_host()),
+            Self::Domain(domain) => Some(domain.to_host()),
+            Self::SocketAddress(_) | Self::Endpoint(_) => None,
+        }
+    }
+
+    /// Converts the address to an optional authority. (a socket address or an endpoint)
+    #[must_use]
+    pub fn to_authority(self) -> Option<Authority> {
+        match self {
+            Self::SocketAddress(socket) => Some(socket.to_authority()),
+            Self::Endpoint(endpoint) => Some(endpoint.to_authority()),
+            Self::IPAddress(_) | Self::Domain(_) => None,
+        }
+    }
+}
+
+impl<'a> From<AddressRef<'a>> for Address {
+    fn from(address: AddressRef<'a>) -> Self {
+        address.to_address()
+    }
+}
+
+impl From<Domain> for Address {
+    fn from(domain: Domain) -> Self {
+        Self::Domain(domain)
+    }
+}
+
+impl From<Endpoint> for Address {
+    fn from(endpoint: Endpoint) -> Self {
+        Self::Endpoint(endpoint)
+    }
+}
+
+impl From<SocketAddress> for Address {
+    fn from(socket: SocketAddress) -> Self {
+        Self::SocketAddress(socket)
+    }
+}
+
+impl<A: Into<IPAddress>> From<A> for Address {
+    fn from(ip: A) -> Self {
+        Self::IPAddress(ip.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Address, Domain, IPv4Address, IPv6Address};
+
+    #[test]
+    fn conversions() {
+        let address: Address = IPv4Address::LOCALHOST.into();
+        assert_eq!(address.to_ip(), Some(IPv4Address::LOCALHOST.to_ip()));
+        assert_eq!(address.to_socket(), None);
+        assert_eq!(address.clone().to_host(), Some(IPv4Address::LOCALHOST.to_host()));
+        assert_eq!(address.to_authority(), None);
+
+        let address: Address = IPv6Address::LOCALHOST.to_socket(80).to_socket().into();
+        assert_eq!(
+            address.to_socket(),
+            Some(IPv6Address::LOCALHOST.to_socket(80).to_socket())
+        );
+        assert_eq!(
+            address.to_authority(),
+            Some(IPv6Address::LOCALHOST.to_host().to_authority(80))
+        );
+
+        let address: Address = Domain::localhost().into();
+        assert_eq!(address.clone().to_domain(), Some(Domain::localhost()));
+        assert_eq!(address.clone().to_endpoint(), None);
+        assert_eq!(Address::from(address.to_ref()), address);
+
+        let address: Address = Domain::localhost().to_endpoint(80).into();
+        assert_eq!(address.clone().to_endpoint(), Some(Domain::localhost().to_endpoint(80)));
+        assert_eq!(address.clone().to_host(), None);
+        assert_eq!(
+            address.to_authority(),
+            Some(Domain::localhost().to_host().to_authority(80))
+        );
+    }
+}
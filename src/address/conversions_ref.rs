@@ -0,0 +1,136 @@
+#[cfg(feature = "alloc")]
+use crate::Address;
+use crate::{AddressRef, AuthorityRef, DomainRef, EndpointRef, HostRef, IPAddress, SocketAddress};
+
+impl<'a> AddressRef<'a> {
+    //! Conversions
+
+    /// Converts the address reference to an owned address.
+    #[cfg(feature = "alloc")]
+    pub fn to_address(self) -> Address {
+        match self {
+            Self::IPAddress(ip) => Address::IPAddress(ip),
+            Self::SocketAddress(socket) => Address::SocketAddress(socket),
+            Self::Domain(domain) => Address::Domain(domain.to_domain()),
+            Self::Endpoint(endpoint) => Address::Endpoint(endpoint.to_endpoint()),
+        }
+    }
+
+    /// Converts the address to an optional IP address.
+    #[must_use]
+    pub const fn to_ip(self) -> Option<IPAddress> {
+        if let Self::IPAddress(ip) = self { Some(ip) } else { None }
+    }
+
+    /// Converts the address to an optional socket address.
+    #[must_use]
+    pub const fn to_socket(self) -> Option<SocketAddress> {
+        if let Self::SocketAddress(socket) = self {
+            Some(socket)
+        } else {
+            None
+        }
+    }
+
+    /// Converts the address to an optional domain reference.
+    #[must_use]
+    pub const fn to_domain_ref(self) -> Option<DomainRef<'a>> {
+        if let Self::Domain(domain) = self {
+            Some(domain)
+        } else {
+            None
+        }
+    }
+
+    /// Converts the address to an optional endpoint reference.
+    #[must_use]
+    pub const fn to_endpoint_ref(self) -> Option<EndpointRef<'a>> {
+        if let Self::Endpoint(endpoint) = self {
+            Some(endpoint)
+        } else {
+            None
+        }
+    }
+
+    /// Converts the address to an optional host reference. (an IP address or a domain)
+    #[must_use]
+    pub const fn to_host_ref(self) -> Option<HostRef<'a>> {
+        match self {
+            Self::IPAddress(ip) => Some(HostRef::Address(ip)),
+            Self::Domain(domain) => Some(HostRef::Name(domain)),
+            Self::SocketAddress(_) | Self::Endpoint(_) => None,
+        }
+    }
+
+    /// Converts the address to an optional authority reference. (a socket address or an endpoint)
+    #[must_use]
+    pub const fn to_authority_ref(self) -> Option<AuthorityRef<'a>> {
+        match self {
+            Self::SocketAddress(socket) => Some(AuthorityRef::new(HostRef::Address(socket.ip()), socket.port())),
+            Self::Endpoint(endpoint) => Some(endpoint.to_authority_ref()),
+            Self::IPAddress(_) | Self::Domain(_) => None,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> From<&'a Address> for AddressRef<'a> {
+    fn from(address: &'a Address) -> Self {
+        address.to_ref()
+    }
+}
+
+impl<'a> From<DomainRef<'a>> for AddressRef<'a> {
+    fn from(domain: DomainRef<'a>) -> Self {
+        Self::Domain(domain)
+    }
+}
+
+impl<'a> From<EndpointRef<'a>> for AddressRef<'a> {
+    fn from(endpoint: EndpointRef<'a>) -> Self {
+        Self::Endpoint(endpoint)
+    }
+}
+
+impl<'a> From<SocketAddress> for AddressRef<'a> {
+    fn from(socket: SocketAddress) -> Self {
+        Self::SocketAddress(socket)
+    }
+}
+
+impl<'a, A: Into<IPAddress>> From<A> for AddressRef<'a> {
+    fn from(ip: A) -> Self {
+        Self::IPAddress(ip.into())
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::{AddressRef, DomainRef, EndpointRef, HostRef, IPv4Address};
+
+    #[test]
+    fn conversions() {
+        let address: AddressRef = IPv4Address::LOCALHOST.into();
+        assert_eq!(address.to_ip(), Some(IPv4Address::LOCALHOST.to_ip()));
+        assert_eq!(address.to_host_ref(), Some(IPv4Address::LOCALHOST.to_host_ref()));
+        assert_eq!(address.to_authority_ref(), None);
+
+        let address: AddressRef = IPv4Address::LOCALHOST.to_socket(80).to_socket().into();
+        assert_eq!(
+            address.to_authority_ref(),
+            Some(IPv4Address::LOCALHOST.to_host_ref().to_authority_ref(80))
+        );
+        assert_eq!(address.to_host_ref(), None);
+
+        let address: AddressRef = DomainRef::LOCALHOST.into();
+        assert_eq!(address.to_domain_ref(), Some(DomainRef::LOCALHOST));
+        assert_eq!(address.to_host_ref(), Some(HostRef::Name(DomainRef::LOCALHOST)));
+        assert_eq!(address.to_endpoint_ref(), None);
+
+        let endpoint: EndpointRef = EndpointRef::new(DomainRef::LOCALHOST, 80);
+        let address: AddressRef = endpoint.into();
+        assert_eq!(address.to_endpoint_ref(), Some(endpoint));
+        assert_eq!(address.to_authority_ref(), Some(endpoint.to_authority_ref()));
+        assert_eq!(address.to_address().to_ref(), address);
+    }
+}
@@ -0,0 +1,13 @@
+#[cfg(feature = "alloc")]
+pub use address::*;
+pub use address_kind::*;
+pub use address_ref::*;
+
+#[cfg(feature = "alloc")]
+mod address;
+mod address_kind;
+mod address_ref;
+
+#[cfg(feature = "alloc")]
+mod conversions;
+mod conversions_ref;
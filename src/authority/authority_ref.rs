@@ -1,4 +1,6 @@
-use crate::{Authority, HostRef};
+#[cfg(feature = "alloc")]
+use crate::Authority;
+use crate::HostRef;
 
 /// An [Authority] reference.
 #[must_use]
@@ -29,6 +31,7 @@ impl<'a> From<AuthorityRef<'a>> for (HostRef<'a>, u16) {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'a> PartialEq<Authority> for AuthorityRef<'a> {
     fn eq(&self, other: &Authority) -> bool {
         *self == other.to_ref()
@@ -68,7 +71,10 @@ impl<'a> AuthorityRef<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Authority, AuthorityRef, Domain, DomainRef, Host, HostRef, IPv4Address};
+    use crate::{AuthorityRef, DomainRef, HostRef, IPv4Address};
+
+    #[cfg(feature = "alloc")]
+    use crate::{Authority, Domain, Host};
 
     #[test]
     fn construction() {
@@ -89,6 +95,7 @@ mod tests {
         assert_eq!(port, 80);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn equality() {
         let eighty: Authority = Authority::new(Host::Name(Domain::localhost()), 80);
@@ -1,14 +1,18 @@
-use crate::{Authority, AuthorityRef, Endpoint, EndpointRef, HostRef, SocketAddress, SocketAddressV4, SocketAddressV6};
+#[cfg(feature = "alloc")]
+use crate::{Authority, Endpoint};
+use crate::{AuthorityRef, EndpointRef, HostRef, SocketAddress, SocketAddressV4, SocketAddressV6};
 
 impl<'a> AuthorityRef<'a> {
     //! Conversions
 
     /// Converts the authority reference to an authority.
+    #[cfg(feature = "alloc")]
     pub fn to_authority(self) -> Authority {
         Authority::new(self.host().to_host(), self.port())
     }
 
     /// Converts the authority reference to an optional endpoint.
+    #[cfg(feature = "alloc")]
     #[must_use]
     pub fn to_endpoint(self) -> Option<Endpoint> {
         if let HostRef::Name(domain) = self.host() {
@@ -39,6 +43,7 @@ impl<'a> AuthorityRef<'a> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'a> From<&'a Authority> for AuthorityRef<'a> {
     fn from(authority: &'a Authority) -> Self {
         authority.to_ref()
@@ -71,11 +76,12 @@ impl<'a> From<SocketAddressV6> for AuthorityRef<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{
-        Authority, AuthorityRef, Domain, DomainRef, Endpoint, EndpointRef, Host, HostRef, IPv4Address, IPv6Address,
-        SocketAddress, SocketAddressV4, SocketAddressV6,
-    };
+    use crate::{AuthorityRef, DomainRef, HostRef, IPv4Address, SocketAddress};
 
+    #[cfg(feature = "alloc")]
+    use crate::{Authority, Domain, Endpoint, EndpointRef, Host, IPv6Address, SocketAddressV4, SocketAddressV6};
+
+    #[cfg(feature = "alloc")]
     #[test]
     fn ref_to_authority() {
         let authority: AuthorityRef = AuthorityRef::new(HostRef::Name(DomainRef::LOCALHOST), 80);
@@ -84,6 +90,7 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn ref_to_endpoint() {
         let authority: AuthorityRef = AuthorityRef::new(HostRef::Name(DomainRef::LOCALHOST), 80);
@@ -118,6 +125,7 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn ref_from() {
         let expected: AuthorityRef = AuthorityRef::new(HostRef::Name(DomainRef::LOCALHOST), 80);
@@ -1,5 +1,5 @@
 use crate::{Authority, SocketAddress, SocketAddressV4, SocketAddressV6};
-use std::net::{SocketAddr, SocketAddrV4, SocketAddrV6};
+use core::net::{SocketAddr, SocketAddrV4, SocketAddrV6};
 
 impl From<SocketAddr> for Authority {
     /// The `flow_info` & `scope_id` are discarded for IPv6 socket addresses.
@@ -24,7 +24,7 @@ impl From<SocketAddrV6> for Authority {
 #[cfg(test)]
 mod tests {
     use crate::{Authority, IPv4Address, IPv6Address};
-    use std::net::{SocketAddr, SocketAddrV4, SocketAddrV6};
+    use core::net::{SocketAddr, SocketAddrV4, SocketAddrV6};
 
     #[test]
     fn authority_from() {
@@ -1,5 +1,5 @@
 use crate::{AuthorityRef, SocketAddress, SocketAddressV4, SocketAddressV6};
-use std::net::{SocketAddr, SocketAddrV4, SocketAddrV6};
+use core::net::{SocketAddr, SocketAddrV4, SocketAddrV6};
 
 impl<'a> From<SocketAddr> for AuthorityRef<'a> {
     /// The `flow_info` & `scope_id` are discarded for IPv6 socket addresses.
@@ -24,7 +24,7 @@ impl<'a> From<SocketAddrV6> for AuthorityRef<'a> {
 #[cfg(test)]
 mod tests {
     use crate::{AuthorityRef, IPv4Address, IPv6Address};
-    use std::net::{SocketAddr, SocketAddrV4, SocketAddrV6};
+    use core::net::{SocketAddr, SocketAddrV4, SocketAddrV6};
 
     #[test]
     fn ref_from() {
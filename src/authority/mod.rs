@@ -1,10 +1,14 @@
+#[cfg(feature = "alloc")]
 pub use authority::*;
 pub use authority_ref::*;
 
+#[cfg(feature = "alloc")]
 mod authority;
 mod authority_ref;
 
+#[cfg(feature = "alloc")]
 mod conversions;
 mod conversions_ref;
+#[cfg(feature = "alloc")]
 mod conversions_std;
 mod conversions_std_ref;
@@ -0,0 +1,80 @@
+use crate::SocketAddress;
+use std::fmt::{Display, Formatter};
+use std::io;
+
+/// An error connecting to a list of socket addresses: every attempt failed.
+#[derive(Debug)]
+pub struct ConnectError {
+    failures: Vec<(SocketAddress, io::Error)>,
+}
+
+impl ConnectError {
+    //! Construction
+
+    /// Creates a new [ConnectError].
+    pub(crate) const fn new(failures: Vec<(SocketAddress, io::Error)>) -> Self {
+        Self { failures }
+    }
+}
+
+impl ConnectError {
+    //! Properties
+
+    /// Gets the failed attempts, in the order they failed. Empty when there were no addresses to connect to.
+    pub fn failures(&self) -> &[(SocketAddress, io::Error)] {
+        self.failures.as_slice()
+    }
+
+    /// Converts the error into the failed attempts.
+    #[must_use]
+    pub fn into_failures(self) -> Vec<(SocketAddress, io::Error)> {
+        self.failures
+    }
+}
+
+impl From<ConnectError> for io::Error {
+    /// The error kind is that of the last failure, or `NotFound` when there were no addresses.
+    fn from(error: ConnectError) -> Self {
+        let kind: io::ErrorKind = error
+            .failures
+            .last()
+            .map_or(io::ErrorKind::NotFound, |(_, error)| error.kind());
+        io::Error::new(kind, error)
+    }
+}
+
+impl Display for ConnectError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.failures.is_empty() {
+            return f.write_str("no addresses to connect to");
+        }
+        write!(f, "all {} connection attempts failed", self.failures.len())?;
+        for (socket, error) in &self.failures {
+            write!(f, "; {}: {}", socket, error)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConnectError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ConnectError, IPv4Address};
+    use std::io;
+
+    #[test]
+    fn display() {
+        let error: ConnectError = ConnectError::new(Vec::new());
+        assert_eq!(error.to_string(), "no addresses to connect to");
+        assert_eq!(io::Error::from(error).kind(), io::ErrorKind::NotFound);
+
+        let failure: io::Error = io::Error::new(io::ErrorKind::ConnectionRefused, "refused");
+        let error: ConnectError = ConnectError::new(vec![(IPv4Address::LOCALHOST.to_ip().to_socket(80), failure)]);
+        assert_eq!(
+            error.to_string(),
+            "all 1 connection attempts failed; 127.0.0.1:80: refused"
+        );
+        assert_eq!(io::Error::from(error).kind(), io::ErrorKind::ConnectionRefused);
+    }
+}
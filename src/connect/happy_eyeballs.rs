@@ -0,0 +1,313 @@
+use crate::{AuthorityRef, ConnectError, Resolver, SocketAddress};
+use std::io;
+use std::net::TcpStream;
+use std::sync::mpsc::{Receiver, Sender, channel};
+use std::time::Duration;
+
+/// Races TCP connection attempts across address families with the Happy Eyeballs v2 algorithm of RFC 8305.
+///
+/// The addresses are interleaved by family & attempted in order, each started when the previous attempt fails or
+/// after the attempt delay, whichever comes first. The first connection to succeed wins. Each attempt runs on its own
+/// thread; attempts still in flight when one succeeds finish in the background & their streams are dropped.
+///
+/// Sort the addresses first, with an [AddressSelector](crate::AddressSelector) or in the order of the resolver.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct HappyEyeballs {
+    attempt_delay: Duration,
+    connect_timeout: Option<Duration>,
+    first_family_count: usize,
+}
+
+impl HappyEyeballs {
+    //! Construction
+
+    /// The recommended connection attempt delay of RFC 8305.
+    pub const DEFAULT_ATTEMPT_DELAY: Duration = Duration::from_millis(250);
+
+    /// The minimum connection attempt delay of RFC 8305.
+    pub const MIN_ATTEMPT_DELAY: Duration = Duration::from_millis(100);
+
+    /// The maximum connection attempt delay of RFC 8305.
+    pub const MAX_ATTEMPT_DELAY: Duration = Duration::from_secs(2);
+
+    /// Creates a new [HappyEyeballs] with the recommended settings: a 250ms attempt delay, no connect timeout, & a first
+    /// address family count of 1.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            attempt_delay: Self::DEFAULT_ATTEMPT_DELAY,
+            connect_timeout: None,
+            first_family_count: 1,
+        }
+    }
+
+    /// Sets the `attempt_delay`, clamped between [`Self::MIN_ATTEMPT_DELAY`] & [`Self::MAX_ATTEMPT_DELAY`].
+    #[must_use]
+    pub fn with_attempt_delay(mut self, attempt_delay: Duration) -> Self {
+        self.attempt_delay = attempt_delay.clamp(Self::MIN_ATTEMPT_DELAY, Self::MAX_ATTEMPT_DELAY);
+        self
+    }
+
+    /// Sets the `connect_timeout` of each attempt. Without one, attempts use the operating system timeout.
+    #[must_use]
+    pub const fn with_connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Sets the number of addresses of the first family to attempt before the other family, at least 1.
+    #[must_use]
+    pub const fn with_first_family_count(mut self, first_family_count: usize) -> Self {
+        self.first_family_count = if first_family_count == 0 { 1 } else { first_family_count };
+        self
+    }
+}
+
+impl Default for HappyEyeballs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HappyEyeballs {
+    //! Properties
+
+    /// Gets the connection attempt delay.
+    #[must_use]
+    pub const fn attempt_delay(&self) -> Duration {
+        self.attempt_delay
+    }
+
+    /// Gets the optional connect timeout of each attempt.
+    #[must_use]
+    pub const fn connect_timeout(&self) -> Option<Duration> {
+        self.connect_timeout
+    }
+
+    /// Gets the number of addresses of the first family to attempt before the other family.
+    #[must_use]
+    pub const fn first_family_count(&self) -> usize {
+        self.first_family_count
+    }
+}
+
+impl HappyEyeballs {
+    //! Ordering
+
+    /// Interleaves the `addresses` by family, as described in RFC 8305 section 4.
+    ///
+    /// The family of the first address goes first with up to [`Self::first_family_count`] addresses, then the families
+    /// alternate. The order within each family is kept.
+    #[must_use]
+    pub fn interleave(&self, addresses: &[SocketAddress]) -> Vec<SocketAddress> {
+        let Some(first) = addresses.first() else {
+            return Vec::new();
+        };
+        let (mut preferred, mut other): (Vec<SocketAddress>, Vec<SocketAddress>) =
+            addresses.iter().partition(|socket| socket.is_v4() == first.is_v4());
+        preferred.reverse();
+        other.reverse();
+
+        let mut interleaved: Vec<SocketAddress> = Vec::with_capacity(addresses.len());
+        for _ in 1..self.first_family_count {
+            interleaved.extend(preferred.pop());
+        }
+        while !preferred.is_empty() || !other.is_empty() {
+            interleaved.extend(preferred.pop());
+            interleaved.extend(other.pop());
+        }
+        interleaved
+    }
+}
+
+impl HappyEyeballs {
+    //! Connect
+
+    /// Connects to the first of the `addresses` to accept a connection.
+    ///
+    /// Returns every failed attempt when no attempt succeeds.
+    pub fn connect(&self, addresses: &[SocketAddress]) -> Result<TcpStream, ConnectError> {
+        type Attempt = (SocketAddress, io::Result<TcpStream>);
+        let (sender, receiver): (Sender<Attempt>, Receiver<Attempt>) = channel();
+
+        let mut order = self.interleave(addresses).into_iter();
+        let mut pending: usize = 0;
+        let mut failures: Vec<(SocketAddress, io::Error)> = Vec::new();
+        loop {
+            if let Some(socket) = order.next() {
+                self.spawn_attempt(socket, sender.clone());
+                pending += 1;
+            }
+            if pending == 0 {
+                return Err(ConnectError::new(failures));
+            }
+
+            let attempt: Option<Attempt> = if order.len() == 0 {
+                receiver.recv().ok()
+            } else {
+                receiver.recv_timeout(self.attempt_delay).ok()
+            };
+            match attempt {
+                Some((_, Ok(stream))) => return Ok(stream),
+                Some((socket, Err(error))) => {
+                    pending -= 1;
+                    failures.push((socket, error));
+                }
+                None => {}
+            }
+        }
+    }
+
+    /// Resolves the `authority` with the `resolver` & connects to the first address to accept a connection.
+    ///
+    /// IP address hosts are not resolved. When every attempt fails, the error wraps the [ConnectError].
+    pub fn connect_authority<R: Resolver + ?Sized>(
+        &self,
+        authority: AuthorityRef,
+        resolver: &R,
+    ) -> io::Result<TcpStream> {
+        let addresses: Vec<SocketAddress> = authority.resolve(resolver)?;
+        self.connect(addresses.as_slice()).map_err(io::Error::from)
+    }
+
+    /// Starts a connection attempt to the `socket` on a new thread, sending the result to the `sender`.
+    fn spawn_attempt(&self, socket: SocketAddress, sender: Sender<(SocketAddress, io::Result<TcpStream>)>) {
+        let connect_timeout: Option<Duration> = self.connect_timeout;
+        std::thread::spawn(move || {
+            let result: io::Result<TcpStream> = match connect_timeout {
+                Some(timeout) => TcpStream::connect_timeout(&socket.to_std(), timeout),
+                None => TcpStream::connect(socket.to_std()),
+            };
+            // The receiver is gone once another attempt has won, & the stream is dropped.
+            let _ = sender.send((socket, result));
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Authority, ConnectError, Domain, HappyEyeballs, IPv4Address, SocketAddress, StaticResolver};
+    use std::net::{TcpListener, TcpStream};
+    use std::time::Duration;
+
+    #[cfg(target_os = "linux")]
+    use crate::connect::test_util::full_listener;
+    #[cfg(target_os = "linux")]
+    use std::time::Instant;
+
+    fn sockets(text: &[&str]) -> Vec<SocketAddress> {
+        text.iter().map(|socket| socket.parse().unwrap()).collect()
+    }
+
+    /// Gets a loopback address that refuses connections.
+    fn closed_port() -> SocketAddress {
+        let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+        SocketAddress::from(listener.local_addr().unwrap())
+    }
+
+    #[test]
+    fn interleave() {
+        let addresses: Vec<SocketAddress> = sockets(&[
+            "[2001:db8::1]:80",
+            "[2001:db8::2]:80",
+            "[2001:db8::3]:80",
+            "192.0.2.1:80",
+            "192.0.2.2:80",
+        ]);
+        let test_cases: &[(usize, &[&str])] = &[
+            (
+                1,
+                &[
+                    "[2001:db8::1]:80",
+                    "192.0.2.1:80",
+                    "[2001:db8::2]:80",
+                    "192.0.2.2:80",
+                    "[2001:db8::3]:80",
+                ],
+            ),
+            (
+                2,
+                &[
+                    "[2001:db8::1]:80",
+                    "[2001:db8::2]:80",
+                    "192.0.2.1:80",
+                    "[2001:db8::3]:80",
+                    "192.0.2.2:80",
+                ],
+            ),
+        ];
+
+        for (first_family_count, expected) in test_cases {
+            let happy_eyeballs: HappyEyeballs = HappyEyeballs::new().with_first_family_count(*first_family_count);
+            assert_eq!(happy_eyeballs.interleave(&addresses), sockets(expected));
+        }
+        assert!(HappyEyeballs::new().interleave(&[]).is_empty());
+    }
+
+    #[test]
+    fn settings() {
+        let happy_eyeballs: HappyEyeballs = HappyEyeballs::default()
+            .with_attempt_delay(Duration::from_millis(1))
+            .with_connect_timeout(Duration::from_secs(1))
+            .with_first_family_count(0);
+        assert_eq!(happy_eyeballs.attempt_delay(), HappyEyeballs::MIN_ATTEMPT_DELAY);
+        assert_eq!(happy_eyeballs.connect_timeout(), Some(Duration::from_secs(1)));
+        assert_eq!(happy_eyeballs.first_family_count(), 1);
+    }
+
+    /// A listener that never accepts still completes the handshake, so the first attempt wins.
+    #[test]
+    fn connect() {
+        let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let open: SocketAddress = listener.local_addr().unwrap().into();
+        let closed: SocketAddress = closed_port();
+
+        let stream: TcpStream = HappyEyeballs::new().connect(&[closed, open]).unwrap();
+        assert_eq!(SocketAddress::from(stream.peer_addr().unwrap()), open);
+    }
+
+    /// The first attempt hangs, so the second starts after the attempt delay & wins before the first times out.
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn connect_attempt_delay() {
+        let (full, _held): (TcpListener, Vec<TcpStream>) = full_listener();
+        let hanging: SocketAddress = full.local_addr().unwrap().into();
+        let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let open: SocketAddress = listener.local_addr().unwrap().into();
+        let happy_eyeballs: HappyEyeballs = HappyEyeballs::new()
+            .with_attempt_delay(HappyEyeballs::MIN_ATTEMPT_DELAY)
+            .with_connect_timeout(Duration::from_secs(2));
+
+        let start: Instant = Instant::now();
+        let stream: TcpStream = happy_eyeballs.connect(&[hanging, open]).unwrap();
+        let elapsed: Duration = start.elapsed();
+        assert_eq!(SocketAddress::from(stream.peer_addr().unwrap()), open);
+        assert!(elapsed >= happy_eyeballs.attempt_delay(), "elapsed={:?}", elapsed);
+        assert!(elapsed < Duration::from_secs(2), "elapsed={:?}", elapsed);
+    }
+
+    #[test]
+    fn connect_failures() {
+        let closed: Vec<SocketAddress> = vec![closed_port(), closed_port()];
+        let error: ConnectError = HappyEyeballs::new().connect(&closed).unwrap_err();
+        assert_eq!(error.failures().len(), 2);
+
+        let error: ConnectError = HappyEyeballs::new().connect(&[]).unwrap_err();
+        assert!(error.failures().is_empty());
+    }
+
+    #[test]
+    fn connect_authority() {
+        let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port: u16 = listener.local_addr().unwrap().port();
+        let resolver: StaticResolver = [(Domain::localhost(), IPv4Address::LOCALHOST.to_ip())]
+            .into_iter()
+            .collect();
+
+        let authority: Authority = Domain::localhost().to_host().to_authority(port);
+        let stream: TcpStream = HappyEyeballs::new()
+            .connect_authority(authority.to_ref(), &resolver)
+            .unwrap();
+        assert_eq!(stream.peer_addr().unwrap().port(), port);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{ConnectError, HappyEyeballs, SocketAddress};
+use std::io;
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::task::{JoinError, JoinSet};
+
+impl HappyEyeballs {
+    //! Async Connect
+
+    /// Connects to the first of the `addresses` to accept a connection, with `tokio` tasks.
+    ///
+    /// The attempts race as in [`Self::connect`], but attempts still in flight when one succeeds are cancelled.
+    /// Returns every failed attempt when no attempt succeeds. Must be called within a `tokio` runtime.
+    pub async fn connect_async(&self, addresses: &[SocketAddress]) -> Result<TcpStream, ConnectError> {
+        let mut attempts: JoinSet<(SocketAddress, io::Result<TcpStream>)> = JoinSet::new();
+        let mut order = self.interleave(addresses).into_iter();
+        let mut failures: Vec<(SocketAddress, io::Error)> = Vec::new();
+        loop {
+            if let Some(socket) = order.next() {
+                let connect_timeout: Option<Duration> = self.connect_timeout();
+                attempts.spawn(async move {
+                    let connect = TcpStream::connect(socket.to_std());
+                    let result: io::Result<TcpStream> = match connect_timeout {
+                        Some(timeout) => tokio::time::timeout(timeout, connect)
+                            .await
+                            .unwrap_or_else(|_| Err(io::ErrorKind::TimedOut.into())),
+                        None => connect.await,
+                    };
+                    (socket, result)
+                });
+            }
+
+            let attempt: Option<Result<(SocketAddress, io::Result<TcpStream>), JoinError>> = if order.len() == 0 {
+                attempts.join_next().await
+            } else {
+                match tokio::time::timeout(self.attempt_delay(), attempts.join_next()).await {
+                    Ok(attempt) => attempt,
+                    Err(_) => continue,
+                }
+            };
+            match attempt {
+                None => return Err(ConnectError::new(failures)),
+                Some(Ok((_, Ok(stream)))) => return Ok(stream),
+                Some(Ok((socket, Err(error)))) => failures.push((socket, error)),
+                Some(Err(error)) => std::panic::resume_unwind(error.into_panic()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ConnectError, HappyEyeballs, SocketAddress};
+    use std::net::TcpListener;
+    use tokio::net::TcpStream;
+
+    #[cfg(target_os = "linux")]
+    use crate::connect::test_util::full_listener;
+    #[cfg(target_os = "linux")]
+    use std::time::{Duration, Instant};
+
+    /// Gets a loopback address that refuses connections.
+    fn closed_port() -> SocketAddress {
+        let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+        SocketAddress::from(listener.local_addr().unwrap())
+    }
+
+    #[tokio::test]
+    async fn connect_async() {
+        let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let open: SocketAddress = listener.local_addr().unwrap().into();
+
+        let stream: TcpStream = HappyEyeballs::new()
+            .connect_async(&[closed_port(), open])
+            .await
+            .unwrap();
+        assert_eq!(SocketAddress::from(stream.peer_addr().unwrap()), open);
+
+        let error: ConnectError = HappyEyeballs::new()
+            .connect_async(&[closed_port(), closed_port()])
+            .await
+            .unwrap_err();
+        assert_eq!(error.failures().len(), 2);
+
+        let error: ConnectError = HappyEyeballs::new().connect_async(&[]).await.unwrap_err();
+        assert!(error.failures().is_empty());
+    }
+
+    /// The first attempt hangs, so the second starts after the attempt delay & wins before the first times out.
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn connect_async_attempt_delay() {
+        let (full, _held): (TcpListener, Vec<std::net::TcpStream>) = full_listener();
+        let hanging: SocketAddress = full.local_addr().unwrap().into();
+        let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let open: SocketAddress = listener.local_addr().unwrap().into();
+        let happy_eyeballs: HappyEyeballs = HappyEyeballs::new()
+            .with_attempt_delay(HappyEyeballs::MIN_ATTEMPT_DELAY)
+            .with_connect_timeout(Duration::from_secs(2));
+
+        let start: Instant = Instant::now();
+        let stream: TcpStream = happy_eyeballs.connect_async(&[hanging, open]).await.unwrap();
+        let elapsed: Duration = start.elapsed();
+        assert_eq!(SocketAddress::from(stream.peer_addr().unwrap()), open);
+        assert!(elapsed >= happy_eyeballs.attempt_delay(), "elapsed={:?}", elapsed);
+        assert!(elapsed < Duration::from_secs(2), "elapsed={:?}", elapsed);
+    }
+}
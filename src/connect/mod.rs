@@ -0,0 +1,27 @@
+pub use connect_error::*;
+pub use happy_eyeballs::*;
+
+mod connect_error;
+mod happy_eyeballs;
+#[cfg(feature = "tokio")]
+mod happy_eyeballs_async;
+
+#[cfg(all(test, target_os = "linux"))]
+pub(crate) mod test_util {
+    use crate::SocketAddress;
+    use std::net::{TcpListener, TcpStream};
+    use std::time::Duration;
+
+    /// Creates a loopback listener that never accepts & fills its accept queue, returning the held connections.
+    ///
+    /// Linux drops connection attempts to a full accept queue, so a new attempt hangs until it times out.
+    pub(crate) fn full_listener() -> (TcpListener, Vec<TcpStream>) {
+        let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address: SocketAddress = listener.local_addr().unwrap().into();
+        let mut held: Vec<TcpStream> = Vec::new();
+        while let Ok(stream) = TcpStream::connect_timeout(&address.to_std(), Duration::from_millis(100)) {
+            held.push(stream);
+        }
+        (listener, held)
+    }
+}
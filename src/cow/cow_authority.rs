@@ -0,0 +1,195 @@
+use crate::{Authority, AuthorityRef, HostRef};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// Either a borrowed [AuthorityRef] or an owned [Authority], for APIs that sometimes borrow their input & sometimes build
+/// names dynamically.
+///
+/// Equality, ordering, & hashing match [Authority] & [AuthorityRef], whichever form is held.
+#[must_use]
+#[derive(Clone)]
+pub enum CowAuthority<'a> {
+    /// A borrowed [AuthorityRef].
+    Borrowed(AuthorityRef<'a>),
+
+    /// An owned [Authority].
+    Owned(Authority),
+}
+
+impl<'a> From<AuthorityRef<'a>> for CowAuthority<'a> {
+    fn from(authority: AuthorityRef<'a>) -> Self {
+        Self::Borrowed(authority)
+    }
+}
+
+impl<'a> From<&'a Authority> for CowAuthority<'a> {
+    fn from(authority: &'a Authority) -> Self {
+        Self::Borrowed(authority.to_ref())
+    }
+}
+
+impl From<Authority> for CowAuthority<'_> {
+    fn from(authority: Authority) -> Self {
+        Self::Owned(authority)
+    }
+}
+
+impl<'a> From<CowAuthority<'a>> for Authority {
+    fn from(authority: CowAuthority<'a>) -> Self {
+        authority.into_owned()
+    }
+}
+
+impl<'a> CowAuthority<'a> {
+    //! Properties
+
+    /// Gets the host.
+    pub fn host(&self) -> HostRef<'_> {
+        self.to_ref().host()
+    }
+
+    /// Gets the port.
+    #[must_use]
+    pub fn port(&self) -> u16 {
+        self.to_ref().port()
+    }
+}
+
+impl<'a> CowAuthority<'a> {
+    //! Matching
+
+    /// Checks if the authority is an endpoint. (a domain & a port)
+    #[must_use]
+    pub fn is_endpoint(&self) -> bool {
+        self.to_ref().is_endpoint()
+    }
+
+    /// Checks if the authority is a socket address. (an IP address & a port)
+    #[must_use]
+    pub fn is_socket(&self) -> bool {
+        self.to_ref().is_socket()
+    }
+
+    /// Checks if the authority is borrowed.
+    #[must_use]
+    pub const fn is_borrowed(&self) -> bool {
+        matches!(self, Self::Borrowed(_))
+    }
+
+    /// Checks if the authority is owned.
+    #[must_use]
+    pub const fn is_owned(&self) -> bool {
+        matches!(self, Self::Owned(_))
+    }
+}
+
+impl<'a> CowAuthority<'a> {
+    //! Conversions
+
+    /// Converts the authority to an authority reference.
+    pub fn to_ref(&self) -> AuthorityRef<'_> {
+        match self {
+            Self::Borrowed(authority) => *authority,
+            Self::Owned(authority) => authority.to_ref(),
+        }
+    }
+
+    /// Converts the authority to an owned authority, copying a borrowed name.
+    pub fn to_authority(&self) -> Authority {
+        self.to_ref().to_authority()
+    }
+
+    /// Converts the authority into an owned authority, copying a borrowed name.
+    pub fn into_owned(self) -> Authority {
+        match self {
+            Self::Borrowed(authority) => authority.to_authority(),
+            Self::Owned(authority) => authority,
+        }
+    }
+
+    /// Gets the owned authority, first copying a borrowed name.
+    pub fn to_mut(&mut self) -> &mut Authority {
+        if let Self::Borrowed(authority) = self {
+            *self = Self::Owned(authority.to_authority());
+        }
+        match self {
+            Self::Borrowed(_) => unreachable!(),
+            Self::Owned(authority) => authority,
+        }
+    }
+}
+
+impl<'a> PartialEq for CowAuthority<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_ref() == other.to_ref()
+    }
+}
+
+impl<'a> Eq for CowAuthority<'a> {}
+
+impl<'a> PartialOrd for CowAuthority<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for CowAuthority<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_ref().cmp(&other.to_ref())
+    }
+}
+
+impl<'a> Hash for CowAuthority<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_ref().hash(state);
+    }
+}
+
+impl<'a, 'b> PartialEq<AuthorityRef<'b>> for CowAuthority<'a> {
+    fn eq(&self, other: &AuthorityRef<'b>) -> bool {
+        self.to_ref() == *other
+    }
+}
+
+impl<'a, 'b> PartialEq<CowAuthority<'b>> for AuthorityRef<'a> {
+    fn eq(&self, other: &CowAuthority<'b>) -> bool {
+        *self == other.to_ref()
+    }
+}
+
+impl<'a> PartialEq<Authority> for CowAuthority<'a> {
+    fn eq(&self, other: &Authority) -> bool {
+        self.to_ref() == other.to_ref()
+    }
+}
+
+impl<'a> PartialEq<CowAuthority<'a>> for Authority {
+    fn eq(&self, other: &CowAuthority<'a>) -> bool {
+        self.to_ref() == other.to_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Authority, AuthorityRef, CowAuthority, Domain, DomainRef, HostRef, IPv6Address};
+
+    #[test]
+    fn conversions() {
+        let borrowed: CowAuthority = AuthorityRef::new(HostRef::Name(DomainRef::LOCALHOST), 80).into();
+        assert!(borrowed.is_borrowed());
+        assert!(borrowed.is_endpoint());
+        assert_eq!(borrowed.host(), HostRef::Name(DomainRef::LOCALHOST));
+        assert_eq!(borrowed.port(), 80);
+
+        let authority: Authority = Domain::localhost().to_host().to_authority(80);
+        let owned: CowAuthority = authority.clone().into();
+        assert!(owned.is_owned());
+        assert_eq!(owned, borrowed);
+        assert_eq!(authority, borrowed);
+        assert_eq!(Authority::from(borrowed), authority);
+
+        let socket: CowAuthority = IPv6Address::LOCALHOST.to_socket(443).to_authority_ref().into();
+        assert!(socket.is_socket());
+        assert_ne!(socket, owned);
+    }
+}
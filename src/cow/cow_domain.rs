@@ -0,0 +1,222 @@
+use crate::{Domain, DomainRef};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// Either a borrowed [DomainRef] or an owned [Domain], for APIs that sometimes borrow their input & sometimes build
+/// names dynamically.
+///
+/// Equality, ordering, & hashing compare the names, exactly as for [Domain] & [DomainRef], whichever form is held.
+#[must_use]
+#[derive(Clone)]
+pub enum CowDomain<'a> {
+    /// A borrowed [DomainRef].
+    Borrowed(DomainRef<'a>),
+
+    /// An owned [Domain].
+    Owned(Domain),
+}
+
+impl<'a> From<DomainRef<'a>> for CowDomain<'a> {
+    fn from(domain: DomainRef<'a>) -> Self {
+        Self::Borrowed(domain)
+    }
+}
+
+impl<'a> From<&'a Domain> for CowDomain<'a> {
+    fn from(domain: &'a Domain) -> Self {
+        Self::Borrowed(domain.to_ref())
+    }
+}
+
+impl From<Domain> for CowDomain<'_> {
+    fn from(domain: Domain) -> Self {
+        Self::Owned(domain)
+    }
+}
+
+impl<'a> From<CowDomain<'a>> for Domain {
+    fn from(domain: CowDomain<'a>) -> Self {
+        domain.into_owned()
+    }
+}
+
+impl<'a> CowDomain<'a> {
+    //! Properties
+
+    /// Gets the name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Borrowed(domain) => domain.name(),
+            Self::Owned(domain) => domain.name(),
+        }
+    }
+}
+
+impl<'a> CowDomain<'a> {
+    //! Matching
+
+    /// Checks if the domain is borrowed.
+    #[must_use]
+    pub const fn is_borrowed(&self) -> bool {
+        matches!(self, Self::Borrowed(_))
+    }
+
+    /// Checks if the domain is owned.
+    #[must_use]
+    pub const fn is_owned(&self) -> bool {
+        matches!(self, Self::Owned(_))
+    }
+}
+
+impl<'a> CowDomain<'a> {
+    //! Conversions
+
+    /// Converts the domain to a domain reference.
+    pub fn to_ref(&self) -> DomainRef<'_> {
+        match self {
+            Self::Borrowed(domain) => *domain,
+            Self::Owned(domain) => domain.to_ref(),
+        }
+    }
+
+    /// Converts the domain to an owned domain, copying a borrowed name.
+    pub fn to_domain(&self) -> Domain {
+        self.to_ref().to_domain()
+    }
+
+    /// Converts the domain into an owned domain, copying a borrowed name.
+    pub fn into_owned(self) -> Domain {
+        match self {
+            Self::Borrowed(domain) => domain.to_domain(),
+            Self::Owned(domain) => domain,
+        }
+    }
+
+    /// Gets the owned domain, first copying a borrowed name.
+    pub fn to_mut(&mut self) -> &mut Domain {
+        if let Self::Borrowed(domain) = self {
+            *self = Self::Owned(domain.to_domain());
+        }
+        match self {
+            Self::Borrowed(_) => unreachable!(),
+            Self::Owned(domain) => domain,
+        }
+    }
+}
+
+impl<'a> PartialEq for CowDomain<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_ref() == other.to_ref()
+    }
+}
+
+impl<'a> Eq for CowDomain<'a> {}
+
+impl<'a> PartialOrd for CowDomain<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for CowDomain<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_ref().cmp(&other.to_ref())
+    }
+}
+
+impl<'a> Hash for CowDomain<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_ref().hash(state);
+    }
+}
+
+impl<'a, 'b> PartialEq<DomainRef<'b>> for CowDomain<'a> {
+    fn eq(&self, other: &DomainRef<'b>) -> bool {
+        self.to_ref() == *other
+    }
+}
+
+impl<'a, 'b> PartialEq<CowDomain<'b>> for DomainRef<'a> {
+    fn eq(&self, other: &CowDomain<'b>) -> bool {
+        *self == other.to_ref()
+    }
+}
+
+impl<'a> PartialEq<Domain> for CowDomain<'a> {
+    fn eq(&self, other: &Domain) -> bool {
+        self.to_ref() == other.to_ref()
+    }
+}
+
+impl<'a> PartialEq<CowDomain<'a>> for Domain {
+    fn eq(&self, other: &CowDomain<'a>) -> bool {
+        self.to_ref() == other.to_ref()
+    }
+}
+
+impl<'a> PartialEq<&str> for CowDomain<'a> {
+    /// Compares the name exactly; domain names are lowercase, so mixed-case strings are never equal.
+    fn eq(&self, other: &&str) -> bool {
+        self.name() == *other
+    }
+}
+
+impl<'a> PartialEq<CowDomain<'a>> for &str {
+    /// Compares the name exactly; domain names are lowercase, so mixed-case strings are never equal.
+    fn eq(&self, other: &CowDomain<'a>) -> bool {
+        *self == other.name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::hash;
+    use crate::{CowDomain, Domain, DomainRef};
+
+    #[test]
+    fn conversions() {
+        let borrowed: CowDomain = DomainRef::EXAMPLE.into();
+        assert!(borrowed.is_borrowed());
+        assert_eq!(borrowed.name(), "example.com");
+        assert_eq!(borrowed.to_ref(), DomainRef::EXAMPLE);
+
+        let owned: CowDomain = Domain::example().into();
+        assert!(owned.is_owned());
+        assert_eq!(owned, borrowed);
+        assert_eq!(owned, Domain::example());
+        assert_eq!(Domain::example(), borrowed);
+        assert_eq!(DomainRef::EXAMPLE, owned);
+        assert_eq!(borrowed, "example.com");
+
+        assert_eq!(Domain::from(borrowed.clone()), Domain::example());
+        assert_eq!(borrowed.into_owned(), owned.into_owned());
+    }
+
+    #[test]
+    fn to_mut() {
+        let mut domain: CowDomain = DomainRef::LOCALHOST.into();
+        *domain.to_mut() = Domain::example();
+        assert!(domain.is_owned());
+        assert_eq!(domain, DomainRef::EXAMPLE);
+    }
+
+    /// Equality, ordering, & hashing match [Domain] whichever form is held.
+    #[test]
+    fn comparison() {
+        let names: &[&str] = &["a", "a.b", "a-b", "b", "example.com"];
+
+        for a in names {
+            for b in names {
+                let (domain_a, domain_b): (Domain, Domain) = (a.parse().unwrap(), b.parse().unwrap());
+                let cow_a: CowDomain = CowDomain::Owned(domain_a.clone());
+                let cow_b: CowDomain = CowDomain::Borrowed(domain_b.to_ref());
+                assert_eq!(cow_a.cmp(&cow_b), domain_a.cmp(&domain_b), "a={} b={}", a, b);
+                assert_eq!(cow_a == cow_b, domain_a == domain_b, "a={} b={}", a, b);
+            }
+            let domain: Domain = a.parse().unwrap();
+            assert_eq!(hash(CowDomain::from(&domain)), hash(&domain), "a={}", a);
+            assert_eq!(hash(CowDomain::from(domain.clone())), hash(&domain), "a={}", a);
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{DomainRef, Endpoint, EndpointRef};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// Either a borrowed [EndpointRef] or an owned [Endpoint], for APIs that sometimes borrow their input & sometimes build
+/// names dynamically.
+///
+/// Equality, ordering, & hashing match [Endpoint] & [EndpointRef], whichever form is held.
+#[must_use]
+#[derive(Clone)]
+pub enum CowEndpoint<'a> {
+    /// A borrowed [EndpointRef].
+    Borrowed(EndpointRef<'a>),
+
+    /// An owned [Endpoint].
+    Owned(Endpoint),
+}
+
+impl<'a> From<EndpointRef<'a>> for CowEndpoint<'a> {
+    fn from(endpoint: EndpointRef<'a>) -> Self {
+        Self::Borrowed(endpoint)
+    }
+}
+
+impl<'a> From<&'a Endpoint> for CowEndpoint<'a> {
+    fn from(endpoint: &'a Endpoint) -> Self {
+        Self::Borrowed(endpoint.to_ref())
+    }
+}
+
+impl From<Endpoint> for CowEndpoint<'_> {
+    fn from(endpoint: Endpoint) -> Self {
+        Self::Owned(endpoint)
+    }
+}
+
+impl<'a> From<CowEndpoint<'a>> for Endpoint {
+    fn from(endpoint: CowEndpoint<'a>) -> Self {
+        endpoint.into_owned()
+    }
+}
+
+impl<'a> CowEndpoint<'a> {
+    //! Properties
+
+    /// Gets the domain.
+    pub fn domain(&self) -> DomainRef<'_> {
+        self.to_ref().domain()
+    }
+
+    /// Gets the port.
+    #[must_use]
+    pub fn port(&self) -> u16 {
+        self.to_ref().port()
+    }
+}
+
+impl<'a> CowEndpoint<'a> {
+    //! Matching
+
+    /// Checks if the endpoint is borrowed.
+    #[must_use]
+    pub const fn is_borrowed(&self) -> bool {
+        matches!(self, Self::Borrowed(_))
+    }
+
+    /// Checks if the endpoint is owned.
+    #[must_use]
+    pub const fn is_owned(&self) -> bool {
+        matches!(self, Self::Owned(_))
+    }
+}
+
+impl<'a> CowEndpoint<'a> {
+    //! Conversions
+
+    /// Converts the endpoint to an endpoint reference.
+    pub fn to_ref(&self) -> EndpointRef<'_> {
+        match self {
+            Self::Borrowed(endpoint) => *endpoint,
+            Self::Owned(endpoint) => endpoint.to_ref(),
+        }
+    }
+
+    /// Converts the endpoint to an owned endpoint, copying a borrowed name.
+    pub fn to_endpoint(&self) -> Endpoint {
+        self.to_ref().to_endpoint()
+    }
+
+    /// Converts the endpoint into an owned endpoint, copying a borrowed name.
+    pub fn into_owned(self) -> Endpoint {
+        match self {
+            Self::Borrowed(endpoint) => endpoint.to_endpoint(),
+            Self::Owned(endpoint) => endpoint,
+        }
+    }
+
+    /// Gets the owned endpoint, first copying a borrowed name.
+    pub fn to_mut(&mut self) -> &mut Endpoint {
+        if let Self::Borrowed(endpoint) = self {
+            *self = Self::Owned(endpoint.to_endpoint());
+        }
+        match self {
+            Self::Borrowed(_) => unreachable!(),
+            Self::Owned(endpoint) => endpoint,
+        }
+    }
+}
+
+impl<'a> PartialEq for CowEndpoint<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_ref() == other.to_ref()
+    }
+}
+
+impl<'a> Eq for CowEndpoint<'a> {}
+
+impl<'a> PartialOrd for CowEndpoint<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for CowEndpoint<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_ref().cmp(&other.to_ref())
+    }
+}
+
+impl<'a> Hash for CowEndpoint<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_ref().hash(state);
+    }
+}
+
+impl<'a, 'b> PartialEq<EndpointRef<'b>> for CowEndpoint<'a> {
+    fn eq(&self, other: &EndpointRef<'b>) -> bool {
+        self.to_ref() == *other
+    }
+}
+
+impl<'a, 'b> PartialEq<CowEndpoint<'b>> for EndpointRef<'a> {
+    fn eq(&self, other: &CowEndpoint<'b>) -> bool {
+        *self == other.to_ref()
+    }
+}
+
+impl<'a> PartialEq<Endpoint> for CowEndpoint<'a> {
+    fn eq(&self, other: &Endpoint) -> bool {
+        self.to_ref() == other.to_ref()
+    }
+}
+
+impl<'a> PartialEq<CowEndpoint<'a>> for Endpoint {
+    fn eq(&self, other: &CowEndpoint<'a>) -> bool {
+        self.to_ref() == other.to_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CowEndpoint, Domain, DomainRef, Endpoint, EndpointRef};
+
+    #[test]
+    fn conversions() {
+        let borrowed: CowEndpoint = EndpointRef::new(DomainRef::LOCALHOST, 80).into();
+        assert!(borrowed.is_borrowed());
+        assert_eq!(borrowed.domain(), DomainRef::LOCALHOST);
+        assert_eq!(borrowed.port(), 80);
+
+        let owned: CowEndpoint = Endpoint::new(Domain::localhost(), 80).into();
+        assert!(owned.is_owned());
+        assert_eq!(owned, borrowed);
+        assert_eq!(owned, EndpointRef::new(DomainRef::LOCALHOST, 80));
+        assert_eq!(Endpoint::new(Domain::localhost(), 80), borrowed);
+
+        let mut endpoint: CowEndpoint = borrowed.clone();
+        *endpoint.to_mut() = Endpoint::new(Domain::example(), 443);
+        assert!(endpoint.is_owned());
+        assert_eq!(endpoint.to_endpoint(), Endpoint::new(Domain::example(), 443));
+        assert!(endpoint < borrowed);
+    }
+}
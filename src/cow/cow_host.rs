@@ -0,0 +1,197 @@
+use crate::{CowDomain, Host, HostRef};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// Either a borrowed [HostRef] or an owned [Host], for APIs that sometimes borrow their input & sometimes build
+/// names dynamically.
+///
+/// Equality, ordering, & hashing match [Host] & [HostRef], whichever form is held.
+#[must_use]
+#[derive(Clone)]
+pub enum CowHost<'a> {
+    /// A borrowed [HostRef].
+    Borrowed(HostRef<'a>),
+
+    /// An owned [Host].
+    Owned(Host),
+}
+
+impl<'a> From<HostRef<'a>> for CowHost<'a> {
+    fn from(host: HostRef<'a>) -> Self {
+        Self::Borrowed(host)
+    }
+}
+
+impl<'a> From<&'a Host> for CowHost<'a> {
+    fn from(host: &'a Host) -> Self {
+        Self::Borrowed(host.to_ref())
+    }
+}
+
+impl From<Host> for CowHost<'_> {
+    fn from(host: Host) -> Self {
+        Self::Owned(host)
+    }
+}
+
+impl<'a> From<CowHost<'a>> for Host {
+    fn from(host: CowHost<'a>) -> Self {
+        host.into_owned()
+    }
+}
+
+impl<'a> From<CowDomain<'a>> for CowHost<'a> {
+    fn from(domain: CowDomain<'a>) -> Self {
+        match domain {
+            CowDomain::Borrowed(domain) => Self::Borrowed(domain.into()),
+            CowDomain::Owned(domain) => Self::Owned(domain.into()),
+        }
+    }
+}
+
+impl<'a> CowHost<'a> {
+    //! Matching
+
+    /// Checks if the host is a domain.
+    #[must_use]
+    pub fn is_domain(&self) -> bool {
+        self.to_ref().is_domain()
+    }
+
+    /// Checks if the host is an IP address.
+    #[must_use]
+    pub fn is_ip(&self) -> bool {
+        self.to_ref().is_ip()
+    }
+
+    /// Checks if the host is borrowed.
+    #[must_use]
+    pub const fn is_borrowed(&self) -> bool {
+        matches!(self, Self::Borrowed(_))
+    }
+
+    /// Checks if the host is owned.
+    #[must_use]
+    pub const fn is_owned(&self) -> bool {
+        matches!(self, Self::Owned(_))
+    }
+}
+
+impl<'a> CowHost<'a> {
+    //! Conversions
+
+    /// Converts the host to a host reference.
+    pub fn to_ref(&self) -> HostRef<'_> {
+        match self {
+            Self::Borrowed(host) => *host,
+            Self::Owned(host) => host.to_ref(),
+        }
+    }
+
+    /// Converts the host to an owned host, copying a borrowed name.
+    pub fn to_host(&self) -> Host {
+        self.to_ref().to_host()
+    }
+
+    /// Converts the host into an owned host, copying a borrowed name.
+    pub fn into_owned(self) -> Host {
+        match self {
+            Self::Borrowed(host) => host.to_host(),
+            Self::Owned(host) => host,
+        }
+    }
+
+    /// Gets the owned host, first copying a borrowed name.
+    pub fn to_mut(&mut self) -> &mut Host {
+        if let Self::Borrowed(host) = self {
+            *self = Self::Owned(host.to_host());
+        }
+        match self {
+            Self::Borrowed(_) => unreachable!(),
+            Self::Owned(host) => host,
+        }
+    }
+}
+
+impl<'a> PartialEq for CowHost<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_ref() == other.to_ref()
+    }
+}
+
+impl<'a> Eq for CowHost<'a> {}
+
+impl<'a> PartialOrd for CowHost<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for CowHost<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_ref().cmp(&other.to_ref())
+    }
+}
+
+impl<'a> Hash for CowHost<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_ref().hash(state);
+    }
+}
+
+impl<'a, 'b> PartialEq<HostRef<'b>> for CowHost<'a> {
+    fn eq(&self, other: &HostRef<'b>) -> bool {
+        self.to_ref() == *other
+    }
+}
+
+impl<'a, 'b> PartialEq<CowHost<'b>> for HostRef<'a> {
+    fn eq(&self, other: &CowHost<'b>) -> bool {
+        *self == other.to_ref()
+    }
+}
+
+impl<'a> PartialEq<Host> for CowHost<'a> {
+    fn eq(&self, other: &Host) -> bool {
+        self.to_ref() == other.to_ref()
+    }
+}
+
+impl<'a> PartialEq<CowHost<'a>> for Host {
+    fn eq(&self, other: &CowHost<'a>) -> bool {
+        self.to_ref() == other.to_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::hash;
+    use crate::{CowDomain, CowHost, Domain, DomainRef, Host, HostRef, IPv4Address};
+
+    #[test]
+    fn conversions() {
+        let borrowed: CowHost = CowDomain::from(DomainRef::LOCALHOST).into();
+        assert!(borrowed.is_borrowed());
+        assert!(borrowed.is_domain());
+        assert_eq!(borrowed, HostRef::Name(DomainRef::LOCALHOST));
+
+        let owned: CowHost = CowDomain::from(Domain::localhost()).into();
+        assert!(owned.is_owned());
+        assert_eq!(owned, borrowed);
+        assert_eq!(Host::from(Domain::localhost()), owned);
+        assert_eq!(borrowed.into_owned(), Host::from(Domain::localhost()));
+
+        let ip: CowHost = IPv4Address::LOCALHOST.to_host_ref().into();
+        assert!(ip.is_ip());
+        assert_ne!(ip, owned);
+    }
+
+    #[test]
+    fn hashing() {
+        let hosts: &[Host] = &[Domain::localhost().into(), IPv4Address::LOCALHOST.to_host()];
+        for host in hosts {
+            assert_eq!(hash(CowHost::from(host)), hash(host), "host={}", host);
+            assert_eq!(hash(CowHost::from(host.clone())), hash(host), "host={}", host);
+        }
+    }
+}
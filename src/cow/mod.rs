@@ -0,0 +1,9 @@
+pub use cow_authority::*;
+pub use cow_domain::*;
+pub use cow_endpoint::*;
+pub use cow_host::*;
+
+mod cow_authority;
+mod cow_domain;
+mod cow_endpoint;
+mod cow_host;
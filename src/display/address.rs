@@ -0,0 +1,87 @@
+#[cfg(feature = "alloc")]
+use crate::Address;
+use crate::AddressRef;
+use crate::display::{TextWriter, impl_write_to};
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg(feature = "alloc")]
+impl Address {
+    /// Writes the text.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        self.to_ref().write_text(writer);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl_write_to!(Address, AddressText);
+
+impl<'a> AddressRef<'a> {
+    /// Writes the text.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        match self {
+            Self::IPAddress(ip) => ip.write_text(writer),
+            Self::SocketAddress(socket) => socket.write_text(writer),
+            Self::Domain(domain) => domain.write_text(writer),
+            Self::Endpoint(endpoint) => endpoint.write_text(writer),
+        }
+    }
+}
+
+impl_write_to!(AddressRef<'a>, AddressText);
+
+#[cfg(feature = "alloc")]
+impl Debug for Address {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Display for Address {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.to_ref(), f)
+    }
+}
+
+impl<'a> Debug for AddressRef<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<'a> Display for AddressRef<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::IPAddress(ip) => Display::fmt(ip, f),
+            Self::SocketAddress(socket) => Display::fmt(socket, f),
+            Self::Domain(domain) => Display::fmt(domain, f),
+            Self::Endpoint(endpoint) => Display::fmt(endpoint, f),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::{Address, Domain};
+    use crate::{IPv4Address, IPv6Address};
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::string::ToString;
+
+    #[test]
+    fn address_display() {
+        let test_cases: &[(Address, &str)] = &[
+            (IPv6Address::LOCALHOST.into(), "::1"),
+            (IPv6Address::LOCALHOST.to_socket(80).to_socket().into(), "[::1]:80"),
+            (Domain::localhost().into(), "localhost"),
+            (Domain::localhost().to_endpoint(80).into(), "localhost:80"),
+        ];
+
+        for (address, expected) in test_cases {
+            let result: String = address.to_string();
+            assert_eq!(result, *expected, "address={:?}", address);
+            assert_eq!(address.to_text().as_str(), *expected, "address={:?}", address);
+        }
+        assert_eq!(format!("{:>11}", Address::from(IPv4Address::LOCALHOST)), "  127.0.0.1");
+    }
+}
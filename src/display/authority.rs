@@ -1,26 +1,54 @@
-use crate::{Authority, AuthorityRef, EndpointRef, HostRef};
-use std::fmt::{Debug, Display, Formatter};
+#[cfg(feature = "alloc")]
+use crate::Authority;
+use crate::display::{TextWriter, impl_write_to};
+use crate::{AuthorityRef, EndpointRef, HostRef};
+use core::fmt::{Debug, Display, Formatter};
 
+#[cfg(feature = "alloc")]
+impl Authority {
+    /// Writes the `host:port` text.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        self.to_ref().write_text(writer);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl_write_to!(Authority, AuthorityText);
+
+impl<'a> AuthorityRef<'a> {
+    /// Writes the `host:port` text, bracketing IPv6 addresses.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        match self.host() {
+            HostRef::Name(domain) => EndpointRef::new(domain, self.port()).write_text(writer),
+            HostRef::Address(ip) => ip.to_socket(self.port()).write_text(writer),
+        }
+    }
+}
+
+impl_write_to!(AuthorityRef<'a>, AuthorityText);
+
+#[cfg(feature = "alloc")]
 impl Debug for Authority {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(self, f)
     }
 }
 
+#[cfg(feature = "alloc")]
 impl Display for Authority {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(&self.to_ref(), f)
     }
 }
 
 impl<'a> Debug for AuthorityRef<'a> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(self, f)
     }
 }
 
 impl<'a> Display for AuthorityRef<'a> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self.host() {
             HostRef::Name(domain) => Display::fmt(&EndpointRef::new(domain, self.port()), f),
             HostRef::Address(ip) => Display::fmt(&ip.to_socket(self.port()), f),
@@ -28,9 +56,13 @@ impl<'a> Display for AuthorityRef<'a> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
-    use crate::{Authority, Domain, IPv4Address, IPv6Address};
+    use crate::{Authority, Domain};
+    use crate::{IPv4Address, IPv6Address};
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::string::ToString;
 
     #[test]
     fn authority_display() {
@@ -43,6 +75,7 @@ mod tests {
         for (authority, expected) in test_cases {
             let result: String = authority.to_string();
             assert_eq!(result, *expected, "authority={:?}", authority);
+            assert_eq!(authority.to_text().as_str(), *expected, "authority={:?}", authority);
         }
     }
 
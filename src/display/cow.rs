@@ -0,0 +1,124 @@
+use crate::display::{TextWriter, impl_write_to};
+use crate::{CowAuthority, CowDomain, CowEndpoint, CowHost};
+use core::borrow::Borrow;
+use core::fmt::{Debug, Display, Formatter};
+
+impl<'a> CowDomain<'a> {
+    /// Writes the name.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        self.to_ref().write_text(writer);
+    }
+}
+
+impl_write_to!(CowDomain<'a>, DomainText);
+
+impl<'a> CowHost<'a> {
+    /// Writes the text.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        self.to_ref().write_text(writer);
+    }
+}
+
+impl_write_to!(CowHost<'a>, HostText);
+
+impl<'a> CowEndpoint<'a> {
+    /// Writes the `domain:port` text.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        self.to_ref().write_text(writer);
+    }
+}
+
+impl_write_to!(CowEndpoint<'a>, EndpointText);
+
+impl<'a> CowAuthority<'a> {
+    /// Writes the `host:port` text.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        self.to_ref().write_text(writer);
+    }
+}
+
+impl_write_to!(CowAuthority<'a>, AuthorityText);
+
+impl<'a> Debug for CowDomain<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<'a> Display for CowDomain<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.to_ref(), f)
+    }
+}
+
+impl<'a> AsRef<str> for CowDomain<'a> {
+    fn as_ref(&self) -> &str {
+        self.name()
+    }
+}
+
+impl<'a> Borrow<str> for CowDomain<'a> {
+    fn borrow(&self) -> &str {
+        self.name()
+    }
+}
+
+impl<'a> Debug for CowHost<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<'a> Display for CowHost<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.to_ref(), f)
+    }
+}
+
+impl<'a> Debug for CowEndpoint<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<'a> Display for CowEndpoint<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.to_ref(), f)
+    }
+}
+
+impl<'a> Debug for CowAuthority<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<'a> Display for CowAuthority<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.to_ref(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CowAuthority, CowDomain, CowEndpoint, CowHost, Domain, DomainRef, IPv6Address};
+    use alloc::format;
+    use alloc::string::ToString;
+
+    #[test]
+    fn display() {
+        let domain: CowDomain = Domain::localhost().into();
+        assert_eq!(domain.to_string(), "localhost");
+        assert_eq!(format!("{:>10}", domain), " localhost");
+        assert_eq!(CowHost::from(IPv6Address::LOCALHOST.to_host_ref()).to_string(), "::1");
+        assert_eq!(
+            CowEndpoint::from(DomainRef::EXAMPLE.to_endpoint_ref(80)).to_string(),
+            "example.com:80"
+        );
+
+        let authority: CowAuthority = IPv6Address::LOCALHOST.to_socket(443).to_authority_ref().into();
+        assert_eq!(authority.to_string(), "[::1]:443");
+        assert_eq!(format!("{:?}", authority), "[::1]:443");
+        assert_eq!(authority.to_text().as_str(), "[::1]:443");
+    }
+}
@@ -1,25 +1,52 @@
-use crate::{Domain, DomainRef};
-use std::borrow::Borrow;
-use std::fmt::{Debug, Display, Formatter};
+#[cfg(feature = "alloc")]
+use crate::Domain;
+use crate::DomainRef;
+use crate::display::{TextWriter, impl_write_to};
+use core::borrow::Borrow;
+use core::fmt::{Debug, Display, Formatter};
 
+#[cfg(feature = "alloc")]
+impl Domain {
+    /// Writes the name.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        self.to_ref().write_text(writer);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl_write_to!(Domain, DomainText);
+
+impl<'a> DomainRef<'a> {
+    /// Writes the name.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        writer.write_bytes(self.name().as_bytes());
+    }
+}
+
+impl_write_to!(DomainRef<'a>, DomainText);
+
+#[cfg(feature = "alloc")]
 impl Debug for Domain {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(self, f)
     }
 }
 
+#[cfg(feature = "alloc")]
 impl Display for Domain {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(&self.to_ref(), f)
     }
 }
 
+#[cfg(feature = "alloc")]
 impl AsRef<str> for Domain {
     fn as_ref(&self) -> &str {
         self.name()
     }
 }
 
+#[cfg(feature = "alloc")]
 impl Borrow<str> for Domain {
     fn borrow(&self) -> &str {
         self.name()
@@ -27,13 +54,13 @@ impl Borrow<str> for Domain {
 }
 
 impl<'a> Debug for DomainRef<'a> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(self, f)
     }
 }
 
 impl<'a> Display for DomainRef<'a> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         f.pad(self.name())
     }
 }
@@ -50,9 +77,13 @@ impl<'a> Borrow<str> for DomainRef<'a> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
-    use crate::{Domain, DomainRef};
+    use crate::Domain;
+    use crate::DomainRef;
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::string::ToString;
 
     #[test]
     fn domain_display() {
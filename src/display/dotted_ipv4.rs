@@ -0,0 +1,15 @@
+/// The IPv6 addresses whose last 32 bits are written as a dotted-decimal IPv4 address.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+pub enum DottedIPv4 {
+    /// No addresses. (`::ffff:c000:201`)
+    Never,
+
+    /// IPv4 mapped addresses, as recommended by RFC 5952. (`::ffff:192.0.2.1`)
+    #[default]
+    Mapped,
+
+    /// IPv4 mapped, IPv4 compatible, & NAT64 well-known prefix addresses. (`::192.0.2.1` & `64:ff9b::192.0.2.1`)
+    ///
+    /// Compatible addresses need a non-zero 7th segment, so `::` & `::1` stay hex.
+    Embedded,
+}
@@ -1,38 +1,73 @@
-use crate::{Endpoint, EndpointRef};
-use std::fmt::{Debug, Display, Formatter};
+#[cfg(feature = "alloc")]
+use crate::Endpoint;
+use crate::EndpointRef;
+use crate::display::{TextWriter, impl_write_to};
+use core::fmt::{Debug, Display, Formatter};
 
+#[cfg(feature = "alloc")]
+impl Endpoint {
+    /// Writes the `domain:port` text.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        self.to_ref().write_text(writer);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl_write_to!(Endpoint, EndpointText);
+
+impl<'a> EndpointRef<'a> {
+    /// Writes the `domain:port` text.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        self.domain().write_text(writer);
+        writer.write_byte(b':');
+        writer.write_decimal(self.port());
+    }
+}
+
+impl_write_to!(EndpointRef<'a>, EndpointText);
+
+#[cfg(feature = "alloc")]
 impl Debug for Endpoint {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(self, f)
     }
 }
 
+#[cfg(feature = "alloc")]
 impl Display for Endpoint {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(&self.to_ref(), f)
     }
 }
 
 impl<'a> Debug for EndpointRef<'a> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(self, f)
     }
 }
 
 impl<'a> Display for EndpointRef<'a> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         if f.width().is_none() && f.precision().is_none() {
             write!(f, "{}:{}", self.domain(), self.port())
         } else {
-            f.pad(&format!("{}:{}", self.domain(), self.port()))
+            f.pad(self.to_text().as_str())
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Domain, DomainRef, Endpoint, EndpointRef};
+    use crate::{DomainRef, EndpointRef, EndpointText};
+    use alloc::format;
+    use alloc::string::String;
+
+    #[cfg(feature = "alloc")]
+    use crate::{Domain, Endpoint};
+    #[cfg(feature = "alloc")]
+    use alloc::string::ToString;
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn endpoint_display() {
         let endpoint: Endpoint = Domain::localhost().to_endpoint(80);
@@ -41,6 +76,15 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn to_text() {
+        let name: String = ["a".repeat(63), "b".repeat(63), "c".repeat(63), "d".repeat(61)].join(".");
+        let endpoint: EndpointRef = EndpointRef::new(DomainRef::try_from(name.as_str()).unwrap(), 65535);
+        assert_eq!(endpoint.to_text().as_str(), format!("{}:65535", name));
+        assert_eq!(endpoint.to_text().len(), EndpointText::CAPACITY);
+    }
+
+    #[cfg(feature = "alloc")]
     #[test]
     fn display_spec() {
         let endpoint: Endpoint = Domain::localhost().to_endpoint(80);
@@ -1,26 +1,54 @@
-use crate::{Host, HostRef};
-use std::fmt::{Debug, Display, Formatter};
+#[cfg(feature = "alloc")]
+use crate::Host;
+use crate::HostRef;
+use crate::display::{TextWriter, impl_write_to};
+use core::fmt::{Debug, Display, Formatter};
 
+#[cfg(feature = "alloc")]
+impl Host {
+    /// Writes the text.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        self.to_ref().write_text(writer);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl_write_to!(Host, HostText);
+
+impl<'a> HostRef<'a> {
+    /// Writes the text.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        match self {
+            Self::Name(domain) => domain.write_text(writer),
+            Self::Address(ip) => ip.write_text(writer),
+        }
+    }
+}
+
+impl_write_to!(HostRef<'a>, HostText);
+
+#[cfg(feature = "alloc")]
 impl Debug for Host {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(self, f)
     }
 }
 
+#[cfg(feature = "alloc")]
 impl Display for Host {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(&self.to_ref(), f)
     }
 }
 
 impl<'a> Debug for HostRef<'a> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(self, f)
     }
 }
 
 impl<'a> Display for HostRef<'a> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::Name(domain) => Display::fmt(domain, f),
             Self::Address(ip) => Display::fmt(ip, f),
@@ -28,9 +56,13 @@ impl<'a> Display for HostRef<'a> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
-    use crate::{Domain, Host, IPv4Address, IPv6Address};
+    use crate::{Domain, Host};
+    use crate::{IPv4Address, IPv6Address};
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::string::ToString;
 
     #[test]
     fn host_display() {
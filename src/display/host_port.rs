@@ -0,0 +1,103 @@
+#[cfg(feature = "alloc")]
+use crate::HostPort;
+use crate::display::{TextWriter, impl_write_to};
+use crate::{HostPortRef, HostRef};
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg(feature = "alloc")]
+impl HostPort {
+    /// Writes the text.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        self.to_ref().write_text(writer);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl_write_to!(HostPort, HostPortText);
+
+impl<'a> HostPortRef<'a> {
+    /// Writes the text, bracketing IPv6 addresses.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        match (self.host(), self.port()) {
+            (host, Some(port)) => host.to_authority_ref(port).write_text(writer),
+            (HostRef::Address(ip), None) if ip.is_v6() => {
+                writer.write_byte(b'[');
+                ip.write_text(writer);
+                writer.write_byte(b']');
+            }
+            (host, None) => host.write_text(writer),
+        }
+    }
+}
+
+impl_write_to!(HostPortRef<'a>, HostPortText);
+
+#[cfg(feature = "alloc")]
+impl Debug for HostPort {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Display for HostPort {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.to_ref(), f)
+    }
+}
+
+impl<'a> Debug for HostPortRef<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<'a> Display for HostPortRef<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match (self.host(), self.port()) {
+            (host, Some(port)) => Display::fmt(&host.to_authority_ref(port), f),
+            (HostRef::Address(ip), None) if ip.is_v6() => f.pad(self.to_text().as_str()),
+            (host, None) => Display::fmt(&host, f),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::{Domain, HostPort};
+    use crate::{IPv4Address, IPv6Address};
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::string::ToString;
+
+    #[test]
+    fn host_port_display() {
+        let test_cases: &[(HostPort, &str)] = &[
+            (HostPort::new(Domain::localhost().to_host(), None), "localhost"),
+            (HostPort::new(Domain::localhost().to_host(), Some(80)), "localhost:80"),
+            (HostPort::new(IPv4Address::LOCALHOST.to_host(), None), "127.0.0.1"),
+            (HostPort::new(IPv6Address::LOCALHOST.to_host(), None), "[::1]"),
+            (HostPort::new(IPv6Address::LOCALHOST.to_host(), Some(80)), "[::1]:80"),
+        ];
+
+        for (host_port, expected) in test_cases {
+            let result: String = host_port.to_string();
+            assert_eq!(result, *expected, "host_port={:?}", host_port);
+            assert_eq!(host_port.to_text().as_str(), *expected, "host_port={:?}", host_port);
+        }
+    }
+
+    #[test]
+    fn default_port_display() {
+        let host_port: HostPort = HostPort::new(Domain::localhost().to_host(), Some(443));
+        assert_eq!(host_port.clone().without_default_port(443).to_string(), "localhost");
+        assert_eq!(host_port.without_default_port(80).to_string(), "localhost:443");
+    }
+
+    #[test]
+    fn display_spec() {
+        let host_port: HostPort = HostPort::new(IPv6Address::LOCALHOST.to_host(), None);
+        assert_eq!(format!("{:>7}", host_port), "  [::1]");
+        assert_eq!(format!("{:<7}|", host_port), "[::1]  |");
+    }
+}
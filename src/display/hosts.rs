@@ -0,0 +1,81 @@
+use crate::{HostsEntry, HostsFile};
+use core::fmt::{Display, Formatter};
+
+impl Display for HostsEntry {
+    /// Formats the entry as a hosts file line: `127.0.0.1<TAB>localhost alias # comment`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}\t", self.ip())?;
+        for (i, name) in self.names().iter().enumerate() {
+            if i != 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}", name)?;
+        }
+        if let Some(comment) = self.comment() {
+            write!(f, " # {}", comment)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for HostsFile {
+    /// Formats the file with the original text of parsed lines & the canonical form of added entries.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let lines = self.lines();
+        for (i, line) in lines.iter().enumerate() {
+            match (&line.text, &line.entry) {
+                (Some(text), _) => f.write_str(text)?,
+                (None, Some(entry)) => write!(f, "{}", entry)?,
+                (None, None) => {}
+            }
+            if i + 1 < lines.len() || self.trailing_newline() {
+                f.write_str("\n")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Domain, HostsEntry, HostsFile, IPv4Address, IPv6Address};
+    use alloc::string::ToString;
+
+    #[test]
+    fn hosts_entry_display() {
+        let entry: HostsEntry = HostsEntry::new(IPv4Address::LOCALHOST.to_ip(), Domain::localhost());
+        assert_eq!(entry.to_string(), "127.0.0.1\tlocalhost");
+
+        let entry: HostsEntry = HostsEntry::new(IPv6Address::LOCALHOST.to_ip(), Domain::localhost())
+            .with_alias(Domain::example())
+            .with_comment("loopback");
+        assert_eq!(entry.to_string(), "::1\tlocalhost example.com # loopback");
+    }
+
+    /// A comment cannot break out of its line & inject another entry.
+    #[test]
+    fn hosts_file_display_comment_line_breaks() {
+        let mut file: HostsFile = HostsFile::default();
+        file.push(
+            HostsEntry::new(IPv4Address::LOCALHOST.to_ip(), Domain::localhost())
+                .with_comment("x\n10.0.0.1 evil.example\r\n::2 evil.example"),
+        );
+        assert_eq!(
+            file.to_string(),
+            "127.0.0.1\tlocalhost # x 10.0.0.1 evil.example  ::2 evil.example\n"
+        );
+
+        let reparsed: HostsFile = HostsFile::parse_hosts(file.to_string().as_bytes()).unwrap();
+        assert_eq!(reparsed.entries().count(), 1);
+    }
+
+    #[test]
+    fn hosts_file_display() {
+        let mut file: HostsFile = HostsFile::parse_hosts(b"# hosts\n127.0.0.1   LocalHost").unwrap();
+        file.push(HostsEntry::new(IPv6Address::LOCALHOST.to_ip(), Domain::localhost()));
+        assert_eq!(file.to_string(), "# hosts\n127.0.0.1   LocalHost\n::1\tlocalhost\n");
+
+        let reparsed: HostsFile = HostsFile::parse_hosts(file.to_string().as_bytes()).unwrap();
+        assert_eq!(reparsed.entries().count(), 2);
+    }
+}
@@ -0,0 +1,27 @@
+/// Implements `write_to` & `to_text` for a type, delegating to its crate-internal `write_text`.
+macro_rules! impl_write_to {
+    ($ty:ident $(<$lt:lifetime>)?, $text:ident) => {
+        impl$(<$lt>)? crate::$ty$(<$lt>)? {
+            //! Text
+
+            #[doc = concat!(
+                "Writes the text to the front of the `buffer` & returns its length, without allocating.\n\n",
+                "# Panics\n",
+                "Panics if the `buffer` is too small. A buffer of `", stringify!($text), "::CAPACITY` bytes always fits."
+            )]
+            pub fn write_to(&self, buffer: &mut [u8]) -> usize {
+                let mut writer: crate::display::TextWriter = crate::display::TextWriter::new(buffer);
+                self.write_text(&mut writer);
+                writer.len()
+            }
+
+            #[doc = concat!("Formats the text into a stack-allocated [", stringify!($text), "](crate::", stringify!($text), ").")]
+            #[must_use]
+            pub fn to_text(&self) -> crate::$text {
+                crate::$text::write_with(|writer| self.write_text(writer))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_write_to;
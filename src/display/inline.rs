@@ -0,0 +1,123 @@
+use crate::display::{TextWriter, impl_write_to};
+use crate::{InlineAuthority, InlineDomain, InlineEndpoint, InlineHost};
+use core::borrow::Borrow;
+use core::fmt::{Debug, Display, Formatter};
+
+impl InlineDomain {
+    /// Writes the name.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        self.to_ref().write_text(writer);
+    }
+}
+
+impl_write_to!(InlineDomain, DomainText);
+
+impl InlineHost {
+    /// Writes the text.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        self.to_ref().write_text(writer);
+    }
+}
+
+impl_write_to!(InlineHost, HostText);
+
+impl InlineEndpoint {
+    /// Writes the `domain:port` text.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        self.to_ref().write_text(writer);
+    }
+}
+
+impl_write_to!(InlineEndpoint, EndpointText);
+
+impl InlineAuthority {
+    /// Writes the `host:port` text.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        self.to_ref().write_text(writer);
+    }
+}
+
+impl_write_to!(InlineAuthority, AuthorityText);
+
+impl Debug for InlineDomain {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for InlineDomain {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.to_ref(), f)
+    }
+}
+
+impl AsRef<str> for InlineDomain {
+    fn as_ref(&self) -> &str {
+        self.name()
+    }
+}
+
+impl Borrow<str> for InlineDomain {
+    fn borrow(&self) -> &str {
+        self.name()
+    }
+}
+
+impl Debug for InlineHost {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for InlineHost {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.to_ref(), f)
+    }
+}
+
+impl Debug for InlineEndpoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for InlineEndpoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.to_ref(), f)
+    }
+}
+
+impl Debug for InlineAuthority {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for InlineAuthority {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.to_ref(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IPv6Address, InlineAuthority, InlineDomain, InlineEndpoint, InlineHost};
+    use alloc::format;
+    use alloc::string::ToString;
+
+    #[test]
+    fn display() {
+        assert_eq!(InlineDomain::LOCALHOST.to_string(), "localhost");
+        assert_eq!(format!("{:>10}", InlineDomain::LOCALHOST), " localhost");
+        assert_eq!(InlineHost::from(IPv6Address::LOCALHOST).to_string(), "::1");
+        assert_eq!(
+            InlineEndpoint::new(InlineDomain::EXAMPLE, 80).to_string(),
+            "example.com:80"
+        );
+
+        let authority: InlineAuthority = (IPv6Address::LOCALHOST, 443).into();
+        assert_eq!(authority.to_string(), "[::1]:443");
+        assert_eq!(format!("{:?}", authority), "[::1]:443");
+        assert_eq!(authority.to_text().as_str(), "[::1]:443");
+    }
+}
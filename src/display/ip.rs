@@ -1,38 +1,181 @@
-use crate::{IPAddress, IPv4Address, IPv6Address};
-use std::fmt::{Debug, Display, Formatter};
+use crate::display::{TextWriter, impl_write_to};
+use crate::{DottedIPv4, IPAddress, IPv4Address, IPv6Address, IPv6Display, IPv6DisplayOptions};
+use core::fmt::{Binary, Debug, Display, Formatter, LowerHex, Octal, UpperHex};
+
+/// Implements the radix formatting traits for an IP address type, formatting the address as its integer value.
+macro_rules! impl_radix {
+    ($ty:ident, $int:ident) => {
+        impl_radix!($ty, $int, Binary, LowerHex, UpperHex, Octal);
+    };
+    ($ty:ident, $int:ident, $($fmt:ident),*) => {
+        $(
+            impl $fmt for $ty {
+                fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                    $fmt::fmt(&$int::from(*self), f)
+                }
+            }
+        )*
+    };
+}
+
+impl_radix!(IPv4Address, u32);
+impl_radix!(IPv6Address, u128);
+
+impl IPv4Address {
+    /// Writes the dotted-decimal text.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        let [a, b, c, d] = self.address();
+        writer.write_decimal(a as u16);
+        for octet in [b, c, d] {
+            writer.write_byte(b'.');
+            writer.write_decimal(octet as u16);
+        }
+    }
+}
+
+impl_write_to!(IPv4Address, IPv4AddressText);
+
+impl IPv6Address {
+    //! Display
+
+    /// Creates a value that displays the address with the `options`, without allocating.
+    ///
+    /// ```
+    /// use address::{DottedIPv4, IPv6Address, IPv6DisplayOptions};
+    ///
+    /// let ip: IPv6Address = "64:ff9b::c000:201".parse().unwrap();
+    /// let options: IPv6DisplayOptions = IPv6DisplayOptions::new().with_dotted_ipv4(DottedIPv4::Embedded);
+    /// assert_eq!(format!("{}", ip.display_with(options)), "64:ff9b::192.0.2.1");
+    ///
+    /// let options: IPv6DisplayOptions = IPv6DisplayOptions::expanded().with_uppercase(true);
+    /// assert_eq!(ip.display_with(options).to_string(), "0064:FF9B:0000:0000:0000:0000:C000:0201");
+    /// ```
+    #[must_use]
+    pub const fn display_with(self, options: IPv6DisplayOptions) -> IPv6Display {
+        IPv6Display::new(self, options)
+    }
+}
+
+impl IPv6Address {
+    /// The NAT64 well-known prefix of RFC 6052. (`64:ff9b::/96`)
+    const NAT64_PREFIX: [u16; 6] = [0x64, 0xFF9B, 0, 0, 0, 0];
+
+    /// Writes the RFC 5952 text, matching `Ipv6Addr`'s `Display`.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        self.write_text_with(writer, IPv6DisplayOptions::new());
+    }
+
+    /// Writes the text with the `options`.
+    pub(crate) fn write_text_with(&self, writer: &mut TextWriter, options: IPv6DisplayOptions) {
+        let segments: [u16; 8] = self.segments();
+        if self.has_dotted_ipv4(options.dotted_ipv4()) {
+            if !Self::write_segments(writer, &segments[..6], options) {
+                writer.write_byte(b':');
+            }
+            let [.., a, b, c, d] = self.address();
+            IPv4Address::new([a, b, c, d]).write_text(writer);
+        } else {
+            Self::write_segments(writer, &segments, options);
+        }
+    }
+
+    /// Checks if the last 32 bits are written as dotted-decimal with the `dotted_ipv4` option.
+    fn has_dotted_ipv4(&self, dotted_ipv4: DottedIPv4) -> bool {
+        let segments: [u16; 8] = self.segments();
+        let mapped: bool = self.to_v4_mapped().is_some();
+        match dotted_ipv4 {
+            DottedIPv4::Never => false,
+            DottedIPv4::Mapped => mapped,
+            DottedIPv4::Embedded => {
+                let compatible: bool = segments[..6] == [0; 6] && segments[6] != 0;
+                mapped || compatible || segments[..6] == Self::NAT64_PREFIX
+            }
+        }
+    }
+
+    /// Writes the colon-separated hex `segments`, compressing the longest run of two or more zero segments to `::` if
+    /// the `options` allow. Returns whether the text ends with `::`.
+    fn write_segments(writer: &mut TextWriter, segments: &[u16], options: IPv6DisplayOptions) -> bool {
+        let (mut zeros_start, mut zeros_len) = (0, 0);
+        if options.compression() {
+            let mut run_start: usize = 0;
+            for (i, segment) in segments.iter().enumerate() {
+                if *segment != 0 {
+                    run_start = i + 1;
+                } else if i + 1 - run_start > zeros_len {
+                    (zeros_start, zeros_len) = (run_start, i + 1 - run_start);
+                }
+            }
+        }
+
+        if zeros_len > 1 {
+            Self::write_hex_segments(writer, &segments[..zeros_start], options);
+            writer.write_bytes(b"::");
+            Self::write_hex_segments(writer, &segments[zeros_start + zeros_len..], options);
+            zeros_start + zeros_len == segments.len()
+        } else {
+            Self::write_hex_segments(writer, segments, options);
+            false
+        }
+    }
+
+    /// Writes the colon-separated hex `segments` without compression.
+    fn write_hex_segments(writer: &mut TextWriter, segments: &[u16], options: IPv6DisplayOptions) {
+        for (i, segment) in segments.iter().enumerate() {
+            if i > 0 {
+                writer.write_byte(b':');
+            }
+            writer.write_hex(*segment, options.uppercase(), options.leading_zeros());
+        }
+    }
+}
+
+impl_write_to!(IPv6Address, IPv6AddressText);
+
+impl IPAddress {
+    /// Writes the text.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        match self {
+            Self::V4(ip) => ip.write_text(writer),
+            Self::V6(ip) => ip.write_text(writer),
+        }
+    }
+}
+
+impl_write_to!(IPAddress, IPAddressText);
 
 impl Debug for IPv4Address {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(self, f)
     }
 }
 
 impl Display for IPv4Address {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(&self.to_std(), f)
     }
 }
 
 impl Debug for IPv6Address {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(self, f)
     }
 }
 
 impl Display for IPv6Address {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(&self.to_std(), f)
     }
 }
 
 impl Debug for IPAddress {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(self, f)
     }
 }
 
 impl Display for IPAddress {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::V4(ip) => Display::fmt(ip, f),
             Self::V6(ip) => Display::fmt(ip, f),
@@ -42,7 +185,11 @@ impl Display for IPAddress {
 
 #[cfg(test)]
 mod tests {
-    use crate::{IPAddress, IPv4Address, IPv6Address};
+    use crate::test_util::Generator;
+    use crate::{DottedIPv4, IPAddress, IPv4Address, IPv6Address, IPv6Display, IPv6DisplayOptions};
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::string::ToString;
 
     #[test]
     fn v4_display() {
@@ -93,4 +240,139 @@ mod tests {
         assert_eq!(format!("{:>6}", IPv6Address::LOCALHOST), "   ::1");
         assert_eq!(format!("{:>12}", IPv4Address::LOCALHOST.to_ip()), "   127.0.0.1");
     }
+
+    #[test]
+    fn to_text() {
+        let test_cases: &[(IPAddress, &str)] = &[
+            (IPv4Address::UNSPECIFIED.to_ip(), "0.0.0.0"),
+            (IPv4Address::BROADCAST.to_ip(), "255.255.255.255"),
+            (IPv6Address::UNSPECIFIED.to_ip(), "::"),
+            (IPv6Address::LOCALHOST.to_ip(), "::1"),
+            (
+                IPv6Address::from_segments([1, 0, 0, 2, 0, 0, 0, 3]).to_ip(),
+                "1:0:0:2::3",
+            ),
+            (
+                IPv6Address::from_segments([1, 0, 2, 3, 4, 5, 6, 7]).to_ip(),
+                "1:0:2:3:4:5:6:7",
+            ),
+            (
+                IPv6Address::from_segments([0xFFFF; 8]).to_ip(),
+                "ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff",
+            ),
+            (IPv4Address::BROADCAST.to_v6_mapped().to_ip(), "::ffff:255.255.255.255"),
+        ];
+
+        for (ip, expected) in test_cases {
+            assert_eq!(ip.to_text().as_str(), *expected, "ip={:?}", ip);
+
+            let mut buffer: [u8; 64] = [0; 64];
+            let len: usize = ip.write_to(&mut buffer);
+            assert_eq!(&buffer[..len], expected.as_bytes(), "ip={:?}", ip);
+        }
+    }
+
+    #[test]
+    fn to_text_matches_std() {
+        let mut generator: Generator = Generator::new(0x1D7E);
+        for _ in 0..100_000 {
+            let mut segments: [u16; 8] = [0; 8];
+            for segment in segments.iter_mut() {
+                *segment = match generator.below(4) {
+                    0 | 1 => 0,
+                    2 => 0xFFFF,
+                    _ => generator.next() as u16,
+                };
+            }
+            let ip: IPv6Address = IPv6Address::from_segments(segments);
+            assert_eq!(
+                ip.to_text().as_str(),
+                ip.to_std().to_string(),
+                "segments={:?}",
+                segments
+            );
+
+            let ip: IPv4Address = IPv4Address::new((generator.next() as u32).to_be_bytes());
+            assert_eq!(ip.to_text().as_str(), ip.to_std().to_string(), "ip={:?}", ip);
+        }
+    }
+
+    #[test]
+    fn display_with() {
+        let default: IPv6DisplayOptions = IPv6DisplayOptions::new();
+        let embedded: IPv6DisplayOptions = default.with_dotted_ipv4(DottedIPv4::Embedded);
+        let expanded: IPv6DisplayOptions = IPv6DisplayOptions::expanded();
+        let test_cases: &[(&str, IPv6DisplayOptions, &str)] = &[
+            ("::1", default, "::1"),
+            ("::1", expanded, "0000:0000:0000:0000:0000:0000:0000:0001"),
+            ("::1", default.with_compression(false), "0:0:0:0:0:0:0:1"),
+            ("::1", embedded, "::1"),
+            ("::", embedded, "::"),
+            ("fe80::ab:cdef", default.with_uppercase(true), "FE80::AB:CDEF"),
+            ("fe80::ab:cdef", default.with_leading_zeros(true), "fe80::00ab:cdef"),
+            ("::ffff:192.0.2.1", default, "::ffff:192.0.2.1"),
+            (
+                "::ffff:192.0.2.1",
+                default.with_dotted_ipv4(DottedIPv4::Never),
+                "::ffff:c000:201",
+            ),
+            (
+                "::ffff:192.0.2.1",
+                default.with_compression(false),
+                "0:0:0:0:0:ffff:192.0.2.1",
+            ),
+            (
+                "::ffff:192.0.2.1",
+                expanded.with_dotted_ipv4(DottedIPv4::Mapped),
+                "0000:0000:0000:0000:0000:ffff:192.0.2.1",
+            ),
+            ("::192.0.2.1", default, "::c000:201"),
+            ("::192.0.2.1", embedded, "::192.0.2.1"),
+            ("64:ff9b::192.0.2.1", default, "64:ff9b::c000:201"),
+            ("64:ff9b::192.0.2.1", embedded, "64:ff9b::192.0.2.1"),
+            (
+                "64:ff9b::192.0.2.1",
+                embedded.with_compression(false),
+                "64:ff9b:0:0:0:0:192.0.2.1",
+            ),
+            ("1:0:0:2::3", expanded, "0001:0000:0000:0002:0000:0000:0000:0003"),
+        ];
+
+        for (ip, options, expected) in test_cases {
+            let ip: IPv6Address = ip.parse().unwrap();
+            let result: String = ip.display_with(*options).to_string();
+            assert_eq!(result, *expected, "ip={} options={:?}", ip, options);
+        }
+
+        let ip: IPv6Display = IPv6Address::LOCALHOST.display_with(default.with_compression(false));
+        assert_eq!(format!("{:>17}", ip), "  0:0:0:0:0:0:0:1");
+        assert_eq!(format!("{:?}", ip), "0:0:0:0:0:0:0:1");
+    }
+
+    #[test]
+    fn radix() {
+        let ip: IPv4Address = IPv4Address::new([192, 0, 2, 1]);
+        assert_eq!(format!("{:x}", ip), "c0000201");
+        assert_eq!(format!("{:#X}", ip), "0xC0000201");
+        assert_eq!(format!("{:o}", ip), "30000001001");
+        assert_eq!(format!("{:b}", ip), "11000000000000000000001000000001");
+        assert_eq!(format!("{:#010x}", IPv4Address::LOCALHOST), "0x7f000001");
+
+        let ip: IPv6Address = IPv6Address::LOCALHOST;
+        assert_eq!(format!("{:x}", ip), "1");
+        assert_eq!(format!("{:032x}", ip), "00000000000000000000000000000001");
+        assert_eq!(
+            format!("{:X}", IPv6Address::from_segments([0xFE80, 0, 0, 0, 0, 0, 0, 0xAB])),
+            "FE8000000000000000000000000000AB"
+        );
+        assert_eq!(format!("{:#b}", ip), "0b1");
+        assert_eq!(format!("{:o}", ip), "1");
+    }
+
+    #[test]
+    #[should_panic(expected = "the buffer is too small")]
+    fn write_to_small_buffer() {
+        let mut buffer: [u8; 8] = [0; 8];
+        let _ = IPv4Address::BROADCAST.write_to(&mut buffer);
+    }
 }
@@ -0,0 +1,54 @@
+use crate::display::TextBuffer;
+use crate::{IPv6Address, IPv6DisplayOptions};
+use core::fmt::{Debug, Display, Formatter};
+
+/// Displays an [IPv6Address] with [IPv6DisplayOptions], without allocating.
+///
+/// Created by [`IPv6Address::display_with`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct IPv6Display {
+    ip: IPv6Address,
+    options: IPv6DisplayOptions,
+}
+
+impl IPv6Display {
+    //! Construction
+
+    /// The maximum length of the text: six 4-digit segments & a dotted-decimal tail.
+    /// (`0000:0000:0000:0000:0000:ffff:255.255.255.255`)
+    const MAX_STR_LEN: usize = 45;
+
+    /// Creates a new [IPv6Display].
+    pub(crate) const fn new(ip: IPv6Address, options: IPv6DisplayOptions) -> Self {
+        Self { ip, options }
+    }
+}
+
+impl IPv6Display {
+    //! Properties
+
+    /// Gets the IPv6 address.
+    pub const fn ip(&self) -> IPv6Address {
+        self.ip
+    }
+
+    /// Gets the options.
+    #[must_use]
+    pub const fn options(&self) -> IPv6DisplayOptions {
+        self.options
+    }
+}
+
+impl Debug for IPv6Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for IPv6Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let buffer: TextBuffer<{ Self::MAX_STR_LEN }> =
+            TextBuffer::write_with(|writer| self.ip.write_text_with(writer, self.options));
+        f.pad(buffer.as_str())
+    }
+}
@@ -0,0 +1,103 @@
+use crate::DottedIPv4;
+
+/// The options for the text of an [IPv6Address](crate::IPv6Address), for
+/// [`IPv6Address::display_with`](crate::IPv6Address::display_with).
+///
+/// The default is the RFC 5952 form of the `Display` impl: lowercase hex without leading zeros, the longest run of two
+/// or more zero segments compressed to `::`, & a dotted-decimal tail for IPv4 mapped addresses.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct IPv6DisplayOptions {
+    uppercase: bool,
+    leading_zeros: bool,
+    compress: bool,
+    dotted_ipv4: DottedIPv4,
+}
+
+impl IPv6DisplayOptions {
+    //! Construction
+
+    /// Creates the RFC 5952 [IPv6DisplayOptions].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            uppercase: false,
+            leading_zeros: false,
+            compress: true,
+            dotted_ipv4: DottedIPv4::Mapped,
+        }
+    }
+
+    /// Creates the fully expanded [IPv6DisplayOptions]: eight 4-digit hex segments without `::` or a dotted tail.
+    /// (`0000:0000:0000:0000:0000:0000:0000:0001`)
+    #[must_use]
+    pub const fn expanded() -> Self {
+        Self {
+            uppercase: false,
+            leading_zeros: true,
+            compress: false,
+            dotted_ipv4: DottedIPv4::Never,
+        }
+    }
+
+    /// Sets whether the hex digits are `uppercase`.
+    #[must_use]
+    pub const fn with_uppercase(mut self, uppercase: bool) -> Self {
+        self.uppercase = uppercase;
+        self
+    }
+
+    /// Sets whether the segments are padded with `leading_zeros` to 4 digits.
+    #[must_use]
+    pub const fn with_leading_zeros(mut self, leading_zeros: bool) -> Self {
+        self.leading_zeros = leading_zeros;
+        self
+    }
+
+    /// Sets whether the longest run of two or more zero segments is compressed to `::`.
+    #[must_use]
+    pub const fn with_compression(mut self, compress: bool) -> Self {
+        self.compress = compress;
+        self
+    }
+
+    /// Sets the addresses written with a dotted-decimal IPv4 tail.
+    #[must_use]
+    pub const fn with_dotted_ipv4(mut self, dotted_ipv4: DottedIPv4) -> Self {
+        self.dotted_ipv4 = dotted_ipv4;
+        self
+    }
+}
+
+impl Default for IPv6DisplayOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IPv6DisplayOptions {
+    //! Properties
+
+    /// Checks if the hex digits are uppercase.
+    #[must_use]
+    pub const fn uppercase(&self) -> bool {
+        self.uppercase
+    }
+
+    /// Checks if the segments are padded with leading zeros.
+    #[must_use]
+    pub const fn leading_zeros(&self) -> bool {
+        self.leading_zeros
+    }
+
+    /// Checks if zero segments are compressed to `::`.
+    #[must_use]
+    pub const fn compression(&self) -> bool {
+        self.compress
+    }
+
+    /// Gets the addresses written with a dotted-decimal IPv4 tail.
+    #[must_use]
+    pub const fn dotted_ipv4(&self) -> DottedIPv4 {
+        self.dotted_ipv4
+    }
+}
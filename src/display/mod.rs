@@ -1,6 +1,42 @@
+pub use dotted_ipv4::*;
+pub use ipv6_display::*;
+pub use ipv6_display_options::*;
+pub use text_buffer::*;
+
+pub(crate) use impl_write_to::*;
+pub(crate) use text_writer::*;
+
+mod dotted_ipv4;
+mod impl_write_to;
+mod ipv6_display;
+mod ipv6_display_options;
+mod text_buffer;
+mod text_writer;
+
+mod address;
 mod authority;
+#[cfg(feature = "alloc")]
+mod cow;
 mod domain;
 mod endpoint;
 mod host;
+mod host_port;
+#[cfg(feature = "alloc")]
+mod hosts;
+mod inline;
 mod ip;
+#[cfg(feature = "alloc")]
+mod origin;
+mod port;
+#[cfg(feature = "alloc")]
+mod record;
+#[cfg(feature = "alloc")]
+mod resolv_conf;
+mod service;
+#[cfg(feature = "alloc")]
+mod shared;
 mod socket;
+#[cfg(feature = "alloc")]
+mod uri;
+#[cfg(feature = "idna")]
+mod whatwg;
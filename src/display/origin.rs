@@ -0,0 +1,80 @@
+use crate::{HostPortRef, Origin, Scheme};
+use alloc::format;
+use core::fmt::{Debug, Display, Formatter};
+
+impl Debug for Scheme {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for Scheme {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+impl Debug for Origin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for Origin {
+    /// Serializes the origin per RFC 6454, omitting the port when it is the scheme's default port.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let host_port: HostPortRef = HostPortRef::from(self.authority());
+        let host_port: HostPortRef = match self.scheme().default_port() {
+            Some(default_port) => host_port.without_default_port(default_port),
+            None => host_port,
+        };
+        if f.width().is_none() && f.precision().is_none() {
+            write!(f, "{}://{}", self.scheme(), host_port)
+        } else {
+            f.pad(&format!("{}://{}", self.scheme(), host_port))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Domain, IPv6Address, Origin, Scheme};
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::string::ToString;
+
+    #[test]
+    fn origin_display() {
+        let test_cases: &[(Origin, &str)] = &[
+            (
+                Origin::new(Scheme::https(), Domain::example().to_host().to_authority(443)),
+                "https://example.com",
+            ),
+            (
+                Origin::new(Scheme::https(), Domain::example().to_host().to_authority(80)),
+                "https://example.com:80",
+            ),
+            (
+                Origin::new(Scheme::http(), IPv6Address::LOCALHOST.to_host().to_authority(80)),
+                "http://[::1]",
+            ),
+            (
+                Origin::new(Scheme::http(), IPv6Address::LOCALHOST.to_host().to_authority(8080)),
+                "http://[::1]:8080",
+            ),
+        ];
+
+        for (origin, expected) in test_cases {
+            let result: String = origin.to_string();
+            assert_eq!(result, *expected, "origin={:?}", origin);
+        }
+    }
+
+    #[test]
+    fn display_spec() {
+        let origin: Origin = Origin::new(Scheme::http(), Domain::localhost().to_host().to_authority(80));
+        assert_eq!(format!("{:>18}", origin), "  http://localhost");
+        assert_eq!(format!("{:.4}", origin), "http");
+        assert_eq!(format!("{:>6}", Scheme::http()), "  http");
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{Port, PortRange};
+use core::fmt::{Debug, Display, Formatter};
+
+impl Debug for Port {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for Port {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.value(), f)
+    }
+}
+
+impl Debug for PortRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for PortRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.is_single() {
+            Display::fmt(&self.start(), f)
+        } else {
+            write!(f, "{}-{}", self.start(), self.end())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Port, PortRange};
+    use alloc::format;
+    use alloc::string::ToString;
+
+    #[test]
+    fn port_display() {
+        assert_eq!(Port::new(80).to_string(), "80");
+        assert_eq!(Port::new(65535).to_string(), "65535");
+    }
+
+    #[test]
+    fn display_spec() {
+        assert_eq!(format!("{:>5}", Port::new(80)), "   80");
+        assert_eq!(format!("{:<5}|", Port::new(80)), "80   |");
+    }
+
+    #[test]
+    fn port_range_display() {
+        let range: PortRange = PortRange::new(Port::new(8000), Port::new(8010)).unwrap();
+        assert_eq!(range.to_string(), "8000-8010");
+        assert_eq!(format!("{:?}", range), "8000-8010");
+        assert_eq!(PortRange::single(Port::new(80)).to_string(), "80");
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{MxRecord, SrvRecord};
+use alloc::format;
+use alloc::string::String;
+use core::fmt::{Debug, Display, Formatter};
+
+impl Debug for SrvRecord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for SrvRecord {
+    /// Formats the record as zone file record data with a fully-qualified target: `10 60 5060 sip.example.com.`
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let text: String = match self.endpoint() {
+            Some(endpoint) => format!(
+                "{} {} {} {}.",
+                self.priority(),
+                self.weight(),
+                endpoint.port(),
+                endpoint.domain()
+            ),
+            None => format!("{} {} 0 .", self.priority(), self.weight()),
+        };
+        f.pad(&text)
+    }
+}
+
+impl Debug for MxRecord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for MxRecord {
+    /// Formats the record as zone file record data with a fully-qualified exchange: `10 mail.example.com.`
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let text: String = match self.exchange() {
+            Some(exchange) => format!("{} {}.", self.preference(), exchange),
+            None => format!("{} .", self.preference()),
+        };
+        f.pad(&text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Domain, MxRecord, SrvRecord};
+    use alloc::format;
+    use alloc::string::ToString;
+
+    #[test]
+    fn srv_record_display() {
+        let record: SrvRecord = SrvRecord::new(10, 60, Domain::example().to_endpoint(443));
+        assert_eq!(record.to_string(), "10 60 443 example.com.");
+        assert_eq!(format!("{:?}", SrvRecord::unavailable(0, 0)), "0 0 0 .");
+    }
+
+    #[test]
+    fn mx_record_display() {
+        let record: MxRecord = MxRecord::new(10, Domain::example());
+        assert_eq!(record.to_string(), "10 example.com.");
+        assert_eq!(format!("{:>6}", MxRecord::null(0)), "   0 .");
+    }
+}
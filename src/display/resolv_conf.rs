@@ -0,0 +1,54 @@
+use crate::Nameserver;
+use alloc::format;
+use alloc::string::String;
+use core::fmt::{Debug, Display, Formatter};
+
+impl Debug for Nameserver {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for Nameserver {
+    /// Formats the name server as its IP address & zone, followed by the port when it is not the DNS port:
+    /// `fe80::1%eth0` or `[::1]:5353`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let zone: String = self.zone().map_or(String::new(), |zone| format!("%{}", zone));
+        if self.port() == Self::DNS_PORT {
+            f.pad(&format!("{}{}", self.ip(), zone))
+        } else if self.ip().is_v6() {
+            f.pad(&format!("[{}{}]:{}", self.ip(), zone, self.port()))
+        } else {
+            f.pad(&format!("{}:{}", self.ip(), self.port()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IPv4Address, IPv6Address, Nameserver};
+    use alloc::string::ToString;
+
+    #[test]
+    fn nameserver_display() {
+        let test_cases: &[(Nameserver, &str)] = &[
+            (Nameserver::new(IPv4Address::LOCALHOST.to_ip()), "127.0.0.1"),
+            (
+                Nameserver::new(IPv4Address::LOCALHOST.to_ip()).with_port(5353),
+                "127.0.0.1:5353",
+            ),
+            (
+                Nameserver::new(IPv6Address::LOCALHOST.to_ip()).with_zone("lo"),
+                "::1%lo",
+            ),
+            (
+                Nameserver::new(IPv6Address::LOCALHOST.to_ip()).with_port(5353),
+                "[::1]:5353",
+            ),
+        ];
+
+        for (nameserver, expected) in test_cases {
+            assert_eq!(nameserver.to_string(), *expected);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+#[cfg(feature = "alloc")]
+use crate::Service;
+use crate::TransportProtocol;
+use core::fmt::{Display, Formatter};
+
+impl Display for TransportProtocol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Display for Service {
+    /// Formats the service as an `/etc/services` line: `http 80/tcp www`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} {}/{}", self.name(), self.port(), self.protocol())?;
+        for alias in self.aliases() {
+            write!(f, " {}", alias)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TransportProtocol;
+    use alloc::format;
+    use alloc::string::ToString;
+
+    #[cfg(feature = "alloc")]
+    use crate::{Port, Service};
+
+    #[test]
+    fn protocol_display() {
+        assert_eq!(TransportProtocol::Tcp.to_string(), "tcp");
+        assert_eq!(format!("{:>5}", TransportProtocol::Udp), "  udp");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn service_display() {
+        let service: Service = Service::new("http", Port::new(80), TransportProtocol::Tcp);
+        assert_eq!(service.to_string(), "http 80/tcp");
+
+        let service: Service = service.with_alias("www").with_alias("www-http");
+        assert_eq!(service.to_string(), "http 80/tcp www www-http");
+    }
+}
@@ -0,0 +1,123 @@
+use crate::display::{TextWriter, impl_write_to};
+use crate::{SharedAuthority, SharedDomain, SharedEndpoint, SharedHost};
+use core::borrow::Borrow;
+use core::fmt::{Debug, Display, Formatter};
+
+impl SharedDomain {
+    /// Writes the name.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        self.to_ref().write_text(writer);
+    }
+}
+
+impl_write_to!(SharedDomain, DomainText);
+
+impl SharedHost {
+    /// Writes the text.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        self.to_ref().write_text(writer);
+    }
+}
+
+impl_write_to!(SharedHost, HostText);
+
+impl SharedEndpoint {
+    /// Writes the `domain:port` text.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        self.to_ref().write_text(writer);
+    }
+}
+
+impl_write_to!(SharedEndpoint, EndpointText);
+
+impl SharedAuthority {
+    /// Writes the `host:port` text.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        self.to_ref().write_text(writer);
+    }
+}
+
+impl_write_to!(SharedAuthority, AuthorityText);
+
+impl Debug for SharedDomain {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for SharedDomain {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.to_ref(), f)
+    }
+}
+
+impl AsRef<str> for SharedDomain {
+    fn as_ref(&self) -> &str {
+        self.name()
+    }
+}
+
+impl Borrow<str> for SharedDomain {
+    fn borrow(&self) -> &str {
+        self.name()
+    }
+}
+
+impl Debug for SharedHost {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for SharedHost {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.to_ref(), f)
+    }
+}
+
+impl Debug for SharedEndpoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for SharedEndpoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.to_ref(), f)
+    }
+}
+
+impl Debug for SharedAuthority {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for SharedAuthority {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.to_ref(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Domain, IPv6Address, SharedAuthority, SharedDomain, SharedEndpoint, SharedHost};
+    use alloc::format;
+    use alloc::string::ToString;
+
+    #[test]
+    fn display() {
+        let domain: SharedDomain = Domain::localhost().into();
+        assert_eq!(domain.to_string(), "localhost");
+        assert_eq!(format!("{:>10}", domain), " localhost");
+        assert_eq!(SharedHost::from(IPv6Address::LOCALHOST).to_string(), "::1");
+        assert_eq!(
+            SharedEndpoint::from((Domain::example(), 80)).to_string(),
+            "example.com:80"
+        );
+
+        let authority: SharedAuthority = (IPv6Address::LOCALHOST, 443).into();
+        assert_eq!(authority.to_string(), "[::1]:443");
+        assert_eq!(format!("{:?}", authority), "[::1]:443");
+    }
+}
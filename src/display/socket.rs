@@ -1,45 +1,87 @@
-use crate::{SocketAddress, SocketAddressV4, SocketAddressV6};
-use std::fmt::{Debug, Display, Formatter};
+use crate::display::{TextWriter, impl_write_to};
+use crate::{IPAddress, SocketAddress, SocketAddressV4, SocketAddressV6};
+use core::fmt::{Debug, Display, Formatter};
+
+impl SocketAddressV4 {
+    /// Writes the `ip:port` text.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        self.ip().write_text(writer);
+        writer.write_byte(b':');
+        writer.write_decimal(self.port());
+    }
+}
+
+impl_write_to!(SocketAddressV4, SocketAddressV4Text);
+
+impl SocketAddressV6 {
+    /// Writes the `[ip]:port` text.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        writer.write_byte(b'[');
+        self.ip().write_text(writer);
+        writer.write_bytes(b"]:");
+        writer.write_decimal(self.port());
+    }
+}
+
+impl_write_to!(SocketAddressV6, SocketAddressV6Text);
+
+impl SocketAddress {
+    /// Writes the text.
+    pub(crate) fn write_text(&self, writer: &mut TextWriter) {
+        match self.ip() {
+            IPAddress::V4(ip) => ip.to_socket(self.port()).write_text(writer),
+            IPAddress::V6(ip) => ip.to_socket(self.port()).write_text(writer),
+        }
+    }
+}
+
+impl_write_to!(SocketAddress, SocketAddressText);
 
 impl Debug for SocketAddressV4 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(self, f)
     }
 }
 
 impl Display for SocketAddressV4 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(&self.to_std(), f)
     }
 }
 
 impl Debug for SocketAddressV6 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(self, f)
     }
 }
 
 impl Display for SocketAddressV6 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(&self.to_std(), f)
     }
 }
 
 impl Debug for SocketAddress {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(self, f)
     }
 }
 
 impl Display for SocketAddress {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(&self.to_std(), f)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{IPv4Address, IPv6Address, SocketAddress, SocketAddressV4, SocketAddressV6};
+    use crate::{
+        IPv4Address, IPv6Address, SocketAddress, SocketAddressText, SocketAddressV4, SocketAddressV4Text,
+        SocketAddressV6, SocketAddressV6Text,
+    };
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::string::ToString;
 
     #[test]
     fn v4_display() {
@@ -70,6 +112,22 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn to_text() {
+        let socket: SocketAddressV4 = IPv4Address::BROADCAST.to_socket(65535);
+        assert_eq!(socket.to_text().as_str(), "255.255.255.255:65535");
+        assert_eq!(socket.to_text().len(), SocketAddressV4Text::CAPACITY);
+
+        let socket: SocketAddressV6 = IPv6Address::from_segments([0xFFFF; 8]).to_socket(65535);
+        assert_eq!(socket.to_text().as_str(), socket.to_string());
+        assert_eq!(socket.to_text().len(), SocketAddressV6Text::CAPACITY);
+
+        let socket: SocketAddress = IPv6Address::LOCALHOST.to_socket(0).to_socket();
+        let mut buffer: [u8; SocketAddressText::CAPACITY] = [0; SocketAddressText::CAPACITY];
+        let len: usize = socket.write_to(&mut buffer);
+        assert_eq!(&buffer[..len], b"[::1]:0");
+    }
+
     #[test]
     fn display_spec() {
         assert_eq!(
@@ -0,0 +1,167 @@
+use crate::NameClass;
+use crate::display::TextWriter;
+use core::fmt::{Debug, Display, Formatter, Write};
+
+/// A fixed-capacity stack buffer holding the text of an address.
+///
+/// Returned by the `to_text` methods so hot paths can format addresses without allocating. Each address type has an
+/// alias sized to its longest text, such as [IPv4AddressText] or [AuthorityText].
+#[derive(Copy, Clone)]
+pub struct TextBuffer<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+/// The text of an [IPv4Address](crate::IPv4Address). (`255.255.255.255`)
+pub type IPv4AddressText = TextBuffer<15>;
+
+/// The text of an [IPv6Address](crate::IPv6Address). (`ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff`)
+pub type IPv6AddressText = TextBuffer<39>;
+
+/// The text of an [IPAddress](crate::IPAddress).
+pub type IPAddressText = IPv6AddressText;
+
+/// The text of a [SocketAddressV4](crate::SocketAddressV4). (`255.255.255.255:65535`)
+pub type SocketAddressV4Text = TextBuffer<21>;
+
+/// The text of a [SocketAddressV6](crate::SocketAddressV6). (`[ffff:...:ffff]:65535`)
+pub type SocketAddressV6Text = TextBuffer<47>;
+
+/// The text of a [SocketAddress](crate::SocketAddress).
+pub type SocketAddressText = SocketAddressV6Text;
+
+/// The text of a [Domain](crate::Domain) or [DomainRef](crate::DomainRef).
+pub type DomainText = TextBuffer<{ NameClass::MAX_NAME_LEN }>;
+
+/// The text of a [Host](crate::Host) or [HostRef](crate::HostRef).
+pub type HostText = DomainText;
+
+/// The text of an [Endpoint](crate::Endpoint) or [EndpointRef](crate::EndpointRef): a domain, a colon, & a port.
+pub type EndpointText = TextBuffer<{ NameClass::MAX_NAME_LEN + 6 }>;
+
+/// The text of an [Authority](crate::Authority) or [AuthorityRef](crate::AuthorityRef).
+pub type AuthorityText = EndpointText;
+
+/// The text of a [HostPort](crate::HostPort) or [HostPortRef](crate::HostPortRef).
+pub type HostPortText = EndpointText;
+
+/// The text of an [Address](crate::Address) or [AddressRef](crate::AddressRef).
+pub type AddressText = EndpointText;
+
+impl<const N: usize> TextBuffer<N> {
+    //! Construction
+
+    /// The capacity in bytes.
+    pub const CAPACITY: usize = N;
+
+    /// Creates a new empty [TextBuffer].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { bytes: [0; N], len: 0 }
+    }
+
+    /// Creates a [TextBuffer] holding the text written by the `write` function.
+    pub(crate) fn write_with<F>(write: F) -> Self
+    where
+        F: FnOnce(&mut TextWriter),
+    {
+        let mut buffer: Self = Self::new();
+        let mut writer: TextWriter = TextWriter::new(&mut buffer.bytes);
+        write(&mut writer);
+        buffer.len = writer.len();
+        buffer
+    }
+}
+
+impl<const N: usize> Default for TextBuffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> TextBuffer<N> {
+    //! Properties
+
+    /// Gets the text.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        unsafe { core::str::from_utf8_unchecked(self.as_bytes()) }
+    }
+
+    /// Gets the text bytes.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// Gets the length of the text in bytes.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if the text is empty.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<const N: usize> AsRef<str> for TextBuffer<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> Debug for TextBuffer<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<const N: usize> Display for TextBuffer<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl<const N: usize> Write for TextBuffer<N> {
+    /// Appends the `text`, or fails without writing if the capacity would be exceeded.
+    fn write_str(&mut self, text: &str) -> core::fmt::Result {
+        let end: usize = self.len + text.len();
+        if end > N {
+            return Err(core::fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(text.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::DomainRef;
+    use crate::display::TextBuffer;
+    use alloc::format;
+    use core::fmt::Write;
+
+    #[test]
+    fn write() {
+        let mut buffer: TextBuffer<8> = TextBuffer::new();
+        write!(buffer, "{}:{}", DomainRef::try_from("ab").unwrap(), 80).unwrap();
+        assert_eq!(buffer.as_str(), "ab:80");
+
+        assert!(buffer.write_str("long").is_err());
+        assert_eq!(buffer.as_str(), "ab:80");
+    }
+
+    #[test]
+    fn display() {
+        let mut buffer: TextBuffer<8> = TextBuffer::new();
+        buffer.write_str("ab").unwrap();
+        assert_eq!(format!("{:>4}", buffer), "  ab");
+        assert_eq!(buffer.len(), 2);
+        assert!(!buffer.is_empty());
+        assert!(TextBuffer::<8>::default().is_empty());
+    }
+}
@@ -0,0 +1,126 @@
+/// A cursor writing text to the front of a caller's byte buffer, backing the `write_to` & `to_text` methods.
+///
+/// The writes bypass `core::fmt`, so formatting an address is a handful of byte copies.
+pub(crate) struct TextWriter<'a> {
+    buffer: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> TextWriter<'a> {
+    //! Construction
+
+    /// Creates a new [TextWriter] at the front of the `buffer`.
+    pub(crate) fn new(buffer: &'a mut [u8]) -> Self {
+        Self { buffer, len: 0 }
+    }
+}
+
+impl<'a> TextWriter<'a> {
+    //! Properties
+
+    /// Gets the length of the written text.
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<'a> TextWriter<'a> {
+    //! Write
+
+    /// Writes the `bytes`.
+    ///
+    /// # Panics
+    /// Panics if the buffer is too small.
+    pub(crate) fn write_bytes(&mut self, bytes: &[u8]) {
+        let end: usize = self.len + bytes.len();
+        assert!(end <= self.buffer.len(), "the buffer is too small for the text");
+        self.buffer[self.len..end].copy_from_slice(bytes);
+        self.len = end;
+    }
+
+    /// Writes the `byte`.
+    pub(crate) fn write_byte(&mut self, byte: u8) {
+        self.write_bytes(&[byte]);
+    }
+
+    /// Writes the `value` in decimal.
+    pub(crate) fn write_decimal(&mut self, mut value: u16) {
+        let mut digits: [u8; 5] = [0; 5];
+        let mut start: usize = digits.len();
+        loop {
+            start -= 1;
+            digits[start] = b'0' + (value % 10) as u8;
+            value /= 10;
+            if value == 0 {
+                break;
+            }
+        }
+        self.write_bytes(&digits[start..]);
+    }
+
+    /// Writes the `value` in hex, in `uppercase` or lowercase, & `padded` to 4 digits or without leading zeros.
+    pub(crate) fn write_hex(&mut self, value: u16, uppercase: bool, padded: bool) {
+        let hex: &[u8; 16] = if uppercase {
+            b"0123456789ABCDEF"
+        } else {
+            b"0123456789abcdef"
+        };
+        let digits: [u8; 4] = [
+            hex[(value >> 12) as usize],
+            hex[((value >> 8) & 0xF) as usize],
+            hex[((value >> 4) & 0xF) as usize],
+            hex[(value & 0xF) as usize],
+        ];
+        let start: usize = if padded {
+            0
+        } else {
+            (value.leading_zeros() as usize / 4).min(3)
+        };
+        self.write_bytes(&digits[start..]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::display::TextWriter;
+    use alloc::format;
+    use alloc::string::String;
+
+    #[test]
+    fn write_numbers() {
+        let test_cases: &[(u16, &str, &str)] = &[
+            (0, "0", "0"),
+            (9, "9", "9"),
+            (10, "10", "a"),
+            (255, "255", "ff"),
+            (4096, "4096", "1000"),
+            (65535, "65535", "ffff"),
+        ];
+
+        for (value, decimal, hex) in test_cases {
+            let mut buffer: [u8; 5] = [0; 5];
+            let mut writer: TextWriter = TextWriter::new(&mut buffer);
+            writer.write_decimal(*value);
+            let len: usize = writer.len();
+            assert_eq!(&buffer[..len], decimal.as_bytes(), "value={}", value);
+
+            let mut writer: TextWriter = TextWriter::new(&mut buffer);
+            writer.write_hex(*value, false, false);
+            let len: usize = writer.len();
+            assert_eq!(&buffer[..len], hex.as_bytes(), "value={}", value);
+
+            let mut writer: TextWriter = TextWriter::new(&mut buffer);
+            writer.write_hex(*value, true, true);
+            let len: usize = writer.len();
+            let padded: String = format!("{:0>4}", hex.to_uppercase());
+            assert_eq!(&buffer[..len], padded.as_bytes(), "value={}", value);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the buffer is too small")]
+    fn write_overflow() {
+        let mut buffer: [u8; 2] = [0; 2];
+        TextWriter::new(&mut buffer).write_decimal(100);
+    }
+}
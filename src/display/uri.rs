@@ -0,0 +1,112 @@
+use crate::{IPAddress, IPvFuture, RegName, UriAuthority, UriHost};
+use alloc::format;
+use alloc::string::String;
+use core::fmt::{Debug, Display, Formatter};
+
+impl Debug for RegName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for RegName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+impl Debug for IPvFuture {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for IPvFuture {
+    /// Formats the literal without its brackets.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.pad(self.literal())
+    }
+}
+
+impl Debug for UriHost {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for UriHost {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Name(name) => Display::fmt(name, f),
+            Self::Address(IPAddress::V4(ip)) => Display::fmt(ip, f),
+            Self::Address(IPAddress::V6(ip)) => f.pad(&format!("[{}]", ip)),
+            Self::Future(literal) => f.pad(&format!("[{}]", literal)),
+        }
+    }
+}
+
+impl Debug for UriAuthority {
+    /// Formats the authority with the user-info redacted, so credentials do not leak into logs.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let userinfo: &str = if self.has_userinfo() { "***@" } else { "" };
+        match self.port() {
+            Some(port) => f.pad(&format!("{}{}:{}", userinfo, self.host(), port)),
+            None => f.pad(&format!("{}{}", userinfo, self.host())),
+        }
+    }
+}
+
+impl Display for UriAuthority {
+    /// Formats the authority as URI syntax, including the percent-encoded user-info.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let userinfo: String = self
+            .encoded_userinfo()
+            .map_or(String::new(), |userinfo| format!("{}@", userinfo));
+        match self.port() {
+            Some(port) => f.pad(&format!("{}{}:{}", userinfo, self.host(), port)),
+            None => f.pad(&format!("{}{}", userinfo, self.host())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UriAuthority;
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use core::str::FromStr;
+
+    #[test]
+    fn uri_authority_display() {
+        let test_cases: &[(&str, &str, &str)] = &[
+            ("example.com", "example.com", "example.com"),
+            ("user:secret@[::1]:5432", "user:secret@[::1]:5432", "***@[::1]:5432"),
+            ("git@github.com", "git@github.com", "***@github.com"),
+            ("[v1.x]", "[v1.x]", "[v1.x]"),
+        ];
+
+        for (input, display, debug) in test_cases {
+            let authority: UriAuthority = UriAuthority::from_str(input).unwrap();
+            assert_eq!(authority.to_string(), *display, "input={}", input);
+            assert_eq!(format!("{:?}", authority), *debug, "input={}", input);
+        }
+    }
+
+    /// The user-info must not appear in `Debug` output, however the authority is nested.
+    #[test]
+    fn debug_redacts_userinfo() {
+        let authority: UriAuthority = UriAuthority::from_str("admin:hunter2@db.example.com:5432").unwrap();
+        let debug: String = format!("{:?}", Some(vec![authority]));
+        assert!(!debug.contains("hunter2"), "debug={}", debug);
+        assert!(!debug.contains("admin"), "debug={}", debug);
+    }
+
+    #[test]
+    fn display_spec() {
+        let authority: UriAuthority = UriAuthority::from_str("[::1]").unwrap();
+        assert_eq!(format!("{:>7}", authority), "  [::1]");
+        assert_eq!(format!("{:>7}", authority.host()), "  [::1]");
+    }
+}
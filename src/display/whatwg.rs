@@ -0,0 +1,38 @@
+use crate::WhatwgHost;
+use core::fmt::{Debug, Display, Formatter};
+
+impl Debug for WhatwgHost {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for WhatwgHost {
+    /// Formats the host with the WHATWG host serializer.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Host(host) => f.pad(&host.to_whatwg_string()),
+            Self::Opaque(opaque) => f.pad(opaque),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IPv6Address, WhatwgHost};
+    use alloc::format;
+    use alloc::string::ToString;
+
+    #[test]
+    fn whatwg_host_display() {
+        let host: WhatwgHost = IPv6Address::from_segments([0, 0, 0, 0, 0, 0xFFFF, 0x7F00, 1])
+            .to_host()
+            .into();
+        assert_eq!(host.to_string(), "[::ffff:7f00:1]");
+        assert_eq!(format!("{:?}", host), "[::ffff:7f00:1]");
+
+        let host: WhatwgHost = WhatwgHost::Opaque("fa%C3%9F".to_string());
+        assert_eq!(host.to_string(), "fa%C3%9F");
+        assert_eq!(format!("{:>10}", host), "  fa%C3%9F");
+    }
+}
@@ -0,0 +1,194 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::mem::ManuallyDrop;
+use core::ptr::NonNull;
+
+/// The storage of a domain name: inline when it fits in [Self::INLINE_CAPACITY] bytes, otherwise a boxed `str`.
+///
+/// The whole name is the size of a `String` (24 bytes on 64-bit targets), so most host names need no allocation. The
+/// boxed form is kept as raw parts rather than a `Box<str>` because only raw parts can be read in a `const fn`.
+pub(crate) enum CompactName {
+    /// A name of at most [Self::INLINE_CAPACITY] bytes, in the first `len` of the `bytes`.
+    Inline {
+        len: u8,
+        bytes: [u8; Self::INLINE_CAPACITY],
+    },
+
+    /// A longer name, owned as the raw parts of a `Box<str>`.
+    Boxed { ptr: NonNull<u8>, len: usize },
+}
+
+/// The boxed name is uniquely owned & never mutated, so it is as thread-safe as a `Box<str>`.
+unsafe impl Send for CompactName {}
+
+/// The boxed name is uniquely owned & never mutated, so it is as thread-safe as a `Box<str>`.
+unsafe impl Sync for CompactName {}
+
+impl CompactName {
+    //! Construction
+
+    /// The maximum length of an inline name: the size of a `String`, less the `len` & the enum tag.
+    pub(crate) const INLINE_CAPACITY: usize = size_of::<String>() - 2;
+
+    /// Creates a new [CompactName] from a copy of the `name`.
+    pub(crate) fn new(name: &str) -> Self {
+        Self::inline(name).unwrap_or_else(|| Self::boxed(Box::from(name)))
+    }
+
+    /// Creates a new [CompactName] from the `name`, reusing its allocation when the name does not fit inline.
+    pub(crate) fn from_string(name: String) -> Self {
+        Self::inline(name.as_str()).unwrap_or_else(|| Self::boxed(name.into_boxed_str()))
+    }
+
+    /// Creates an inline name from a copy of the `name`.
+    ///
+    /// Returns `None` if the `name` does not fit inline.
+    fn inline(name: &str) -> Option<Self> {
+        if name.len() > Self::INLINE_CAPACITY {
+            None
+        } else {
+            let mut bytes: [u8; Self::INLINE_CAPACITY] = [0; Self::INLINE_CAPACITY];
+            bytes[..name.len()].copy_from_slice(name.as_bytes());
+            Some(Self::Inline {
+                len: name.len() as u8,
+                bytes,
+            })
+        }
+    }
+
+    /// Creates a boxed name that owns the `name`.
+    fn boxed(name: Box<str>) -> Self {
+        let len: usize = name.len();
+        let ptr: NonNull<u8> = unsafe { NonNull::new_unchecked(Box::into_raw(name).cast::<u8>()) };
+        Self::Boxed { ptr, len }
+    }
+}
+
+impl CompactName {
+    //! Properties
+
+    /// Gets the name.
+    pub(crate) const fn as_str(&self) -> &str {
+        let name: &[u8] = match self {
+            Self::Inline { len, bytes } => bytes.split_at(*len as usize).0,
+            Self::Boxed { ptr, len } => unsafe { core::slice::from_raw_parts(ptr.as_ptr(), *len) },
+        };
+        unsafe { core::str::from_utf8_unchecked(name) }
+    }
+}
+
+impl CompactName {
+    //! Conversions
+
+    /// Converts the name to a string, reusing the allocation of a boxed name.
+    pub(crate) fn into_string(self) -> String {
+        let name: ManuallyDrop<Self> = ManuallyDrop::new(self);
+        match *name {
+            Self::Inline { .. } => String::from(name.as_str()),
+            Self::Boxed { ptr, len } => unsafe { Self::into_box(ptr, len) }.into_string(),
+        }
+    }
+
+    /// Reassembles the boxed name from its raw parts.
+    ///
+    /// # Safety
+    /// The raw parts must come from a [Self::Boxed] name that is never used again.
+    unsafe fn into_box(ptr: NonNull<u8>, len: usize) -> Box<str> {
+        let name: *mut [u8] = core::ptr::slice_from_raw_parts_mut(ptr.as_ptr(), len);
+        unsafe { Box::from_raw(name as *mut str) }
+    }
+}
+
+impl Drop for CompactName {
+    fn drop(&mut self) {
+        if let Self::Boxed { ptr, len } = *self {
+            drop(unsafe { Self::into_box(ptr, len) });
+        }
+    }
+}
+
+impl Clone for CompactName {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Inline { len, bytes } => Self::Inline {
+                len: *len,
+                bytes: *bytes,
+            },
+            Self::Boxed { .. } => Self::boxed(Box::from(self.as_str())),
+        }
+    }
+}
+
+impl PartialEq for CompactName {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for CompactName {}
+
+impl PartialOrd for CompactName {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CompactName {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for CompactName {
+    /// Hashes as the `str`, so the [Domain](crate::Domain) `Borrow<str>` impl upholds its hashing contract.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::CompactName;
+    use crate::test_util::hash;
+    use alloc::string::String;
+    use alloc::string::ToString;
+
+    #[test]
+    fn storage() {
+        let test_cases: &[&str] = &[
+            "",
+            "localhost",
+            "a12345.example.com",
+            "a123456789.example.com",
+            "a1234567890.example.com",
+            "service.internal.us-east-1.example.com",
+        ];
+
+        for input in test_cases {
+            let inline: bool = input.len() <= CompactName::INLINE_CAPACITY;
+            for name in [CompactName::new(input), CompactName::from_string(input.to_string())] {
+                assert_eq!(name.as_str(), *input, "input={}", input);
+                assert_eq!(matches!(name, CompactName::Inline { .. }), inline, "input={}", input);
+
+                let clone: CompactName = name.clone();
+                assert_eq!(clone.as_str(), *input, "input={}", input);
+                assert!(clone == name, "input={}", input);
+                assert_eq!(hash(&clone), hash(input), "input={}", input);
+                assert_eq!(name.into_string(), *input, "input={}", input);
+            }
+        }
+    }
+
+    #[test]
+    fn size() {
+        assert_eq!(size_of::<CompactName>(), size_of::<String>());
+        assert_eq!(size_of::<Option<CompactName>>(), size_of::<String>());
+
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(CompactName::INLINE_CAPACITY, 22);
+        #[cfg(target_pointer_width = "32")]
+        assert_eq!(CompactName::INLINE_CAPACITY, 10);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Domain, DomainRef, Endpoint, Host};
+use crate::{Domain, DomainRef, Endpoint, Endpoints, Host, PortRange};
 
 impl Domain {
     //! Conversions
@@ -13,6 +13,11 @@ impl Domain {
         Endpoint::new(self, port)
     }
 
+    /// Converts the domain to an iterator over the endpoints with each of the `ports`.
+    pub const fn to_endpoints(self, ports: PortRange) -> Endpoints {
+        Endpoints::new(self, ports)
+    }
+
     /// Converts the domain to a host.
     pub const fn to_host(self) -> Host {
         Host::Name(self)
@@ -1,14 +1,18 @@
-use crate::{Domain, DomainRef, Endpoint, EndpointRef, Host, HostRef};
+#[cfg(feature = "alloc")]
+use crate::{Domain, Endpoint, Host};
+use crate::{DomainRef, EndpointRef, HostRef};
 
 impl<'a> DomainRef<'a> {
     //! Conversions
 
     /// Converts the domain reference to a domain.
+    #[cfg(feature = "alloc")]
     pub fn to_domain(self) -> Domain {
-        unsafe { Domain::new_unchecked(self.name()) }
+        unsafe { Domain::from_name_unchecked(self.name()) }
     }
 
     /// Converts the domain reference to an endpoint with the `port`.
+    #[cfg(feature = "alloc")]
     pub fn to_endpoint(self, port: u16) -> Endpoint {
         Endpoint::new(self.to_domain(), port)
     }
@@ -19,6 +23,7 @@ impl<'a> DomainRef<'a> {
     }
 
     /// Converts the domain reference to a host.
+    #[cfg(feature = "alloc")]
     pub fn to_host(self) -> Host {
         Host::Name(self.to_domain())
     }
@@ -29,15 +34,17 @@ impl<'a> DomainRef<'a> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'a> From<&'a Domain> for DomainRef<'a> {
     fn from(domain: &'a Domain) -> Self {
         domain.to_ref()
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
-    use crate::{Domain, DomainRef, Endpoint, EndpointRef, Host, HostRef};
+    use crate::{Domain, Endpoint, Host};
+    use crate::{DomainRef, EndpointRef, HostRef};
 
     #[test]
     fn ref_to_domain() {
@@ -1,10 +1,15 @@
 use crate::DomainRef;
+use crate::domain::CompactName;
+use alloc::string::String;
 
 /// A domain name.
+///
+/// Names of up to 22 bytes (10 on 32-bit targets) are stored inline, so most domains need no allocation; longer names
+/// are boxed.
 #[must_use]
 #[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct Domain {
-    name: String,
+    name: CompactName,
 }
 
 impl Domain {
@@ -36,13 +41,27 @@ impl Domain {
 
         debug_assert!(Self::is_valid_name_str(name.as_str()));
 
-        Self { name }
+        Self {
+            name: CompactName::from_string(name),
+        }
+    }
+
+    /// Creates a new [Domain] from a copy of the `name`, which needs no allocation for inline names.
+    ///
+    /// # Safety
+    /// The `name` must be valid and lowercase.
+    pub(crate) unsafe fn from_name_unchecked(name: &str) -> Self {
+        debug_assert!(Self::is_valid_name_str(name));
+
+        Self {
+            name: CompactName::new(name),
+        }
     }
 }
 
 impl From<Domain> for String {
     fn from(domain: Domain) -> Self {
-        domain.name
+        domain.name.into_string()
     }
 }
 
@@ -55,28 +74,28 @@ impl<'a> PartialEq<DomainRef<'a>> for Domain {
 impl PartialEq<&str> for Domain {
     /// Compares the name exactly; domain names are lowercase, so mixed-case strings are never equal.
     fn eq(&self, other: &&str) -> bool {
-        self.name == *other
+        self.name() == *other
     }
 }
 
 impl PartialEq<Domain> for &str {
     /// Compares the name exactly; domain names are lowercase, so mixed-case strings are never equal.
     fn eq(&self, other: &Domain) -> bool {
-        *self == other.name
+        *self == other.name()
     }
 }
 
 impl PartialEq<String> for Domain {
     /// Compares the name exactly; domain names are lowercase, so mixed-case strings are never equal.
     fn eq(&self, other: &String) -> bool {
-        self.name == *other
+        self.name() == *other
     }
 }
 
 impl PartialEq<Domain> for String {
     /// Compares the name exactly; domain names are lowercase, so mixed-case strings are never equal.
     fn eq(&self, other: &Domain) -> bool {
-        *self == other.name
+        *self == other.name()
     }
 }
 
@@ -93,11 +112,12 @@ impl Domain {
 #[cfg(test)]
 mod tests {
     use crate::{Domain, DomainRef};
+    use alloc::string::String;
 
     #[test]
     fn specials() {
-        assert_eq!(Domain::localhost().name, "localhost");
-        assert_eq!(Domain::example().name, "example.com");
+        assert_eq!(Domain::localhost().name(), "localhost");
+        assert_eq!(Domain::example().name(), "example.com");
     }
 
     #[test]
@@ -1,4 +1,8 @@
+#[cfg(feature = "alloc")]
 use crate::Domain;
+use crate::NameClass;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 
 /// A [Domain] reference.
 #[must_use]
@@ -24,13 +28,14 @@ impl<'a> DomainRef<'a> {
     ///
     /// # Safety
     /// The `name` must be valid and lowercase.
-    pub unsafe fn new_unchecked(name: &'a str) -> Self {
-        debug_assert!(Domain::is_valid_name_str(name));
+    pub const unsafe fn new_unchecked(name: &'a str) -> Self {
+        debug_assert!(NameClass::of_name(name.as_bytes()).is_valid(false));
 
         Self { name }
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'a> PartialEq<Domain> for DomainRef<'a> {
     fn eq(&self, other: &Domain) -> bool {
         *self == other.to_ref()
@@ -51,6 +56,7 @@ impl<'a> PartialEq<DomainRef<'a>> for &str {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'a> PartialEq<String> for DomainRef<'a> {
     /// Compares the name exactly; domain names are lowercase, so mixed-case strings are never equal.
     fn eq(&self, other: &String) -> bool {
@@ -58,6 +64,7 @@ impl<'a> PartialEq<String> for DomainRef<'a> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'a> PartialEq<DomainRef<'a>> for String {
     /// Compares the name exactly; domain names are lowercase, so mixed-case strings are never equal.
     fn eq(&self, other: &DomainRef<'a>) -> bool {
@@ -77,7 +84,12 @@ impl<'a> DomainRef<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Domain, DomainRef};
+    use crate::DomainRef;
+
+    #[cfg(feature = "alloc")]
+    use crate::Domain;
+    #[cfg(feature = "alloc")]
+    use alloc::string::String;
 
     #[test]
     fn specials() {
@@ -85,6 +97,7 @@ mod tests {
         assert_eq!(DomainRef::EXAMPLE.name, "example.com");
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn equality() {
         let owned: Domain = Domain::localhost();
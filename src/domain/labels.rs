@@ -1,6 +1,9 @@
-use crate::{Domain, DomainRef};
-use std::iter::FusedIterator;
+#[cfg(feature = "alloc")]
+use crate::Domain;
+use crate::DomainRef;
+use core::iter::FusedIterator;
 
+#[cfg(feature = "alloc")]
 impl Domain {
     //! Labels
 
@@ -74,8 +77,14 @@ impl<'a> FusedIterator for Labels<'a> {}
 
 #[cfg(test)]
 mod tests {
-    use crate::{Domain, DomainRef, Labels};
+    use crate::{DomainRef, Labels};
 
+    #[cfg(feature = "alloc")]
+    use crate::Domain;
+    #[cfg(feature = "alloc")]
+    use alloc::vec::Vec;
+
+    #[cfg(feature = "alloc")]
     #[test]
     fn labels() {
         let test_cases: &[(&str, &[&str])] = &[
@@ -94,6 +103,7 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn labels_rev() {
         let test_cases: &[(&str, &[&str])] = &[
@@ -112,6 +122,7 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn labels_mixed() {
         let domain: Domain = "a.b.c.d.e".parse().unwrap();
@@ -126,6 +137,7 @@ mod tests {
         assert_eq!(labels.next_back(), None);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn labels_size_hint() {
         let test_cases: &[&str] = &["x", "a.b", "a.b.c", "www.example.com", "a.bb.ccc.dddd"];
@@ -1,10 +1,18 @@
+#[cfg(feature = "alloc")]
 pub use domain::*;
 pub use domain_ref::*;
 pub use labels::*;
 
+#[cfg(feature = "alloc")]
+pub(crate) use compact_name::*;
+
+#[cfg(feature = "alloc")]
+mod compact_name;
+#[cfg(feature = "alloc")]
 mod domain;
 mod domain_ref;
 mod labels;
 
+#[cfg(feature = "alloc")]
 mod conversions;
 mod conversions_ref;
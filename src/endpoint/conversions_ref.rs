@@ -1,14 +1,18 @@
-use crate::{Authority, AuthorityRef, Endpoint, EndpointRef};
+#[cfg(feature = "alloc")]
+use crate::{Authority, Endpoint};
+use crate::{AuthorityRef, EndpointRef};
 
 impl<'a> EndpointRef<'a> {
     //! Conversions
 
     /// Converts the endpoint reference to an endpoint.
+    #[cfg(feature = "alloc")]
     pub fn to_endpoint(self) -> Endpoint {
         Endpoint::new(self.domain().to_domain(), self.port())
     }
 
     /// Converts the endpoint reference to an authority.
+    #[cfg(feature = "alloc")]
     pub fn to_authority(self) -> Authority {
         Authority::new(self.domain().to_host(), self.port())
     }
@@ -19,15 +23,17 @@ impl<'a> EndpointRef<'a> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'a> From<&'a Endpoint> for EndpointRef<'a> {
     fn from(endpoint: &'a Endpoint) -> Self {
         endpoint.to_ref()
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
-    use crate::{Authority, AuthorityRef, Domain, DomainRef, Endpoint, EndpointRef, Host, HostRef};
+    use crate::{Authority, Domain, Endpoint, Host};
+    use crate::{AuthorityRef, DomainRef, EndpointRef, HostRef};
 
     #[test]
     fn ref_to_endpoint() {
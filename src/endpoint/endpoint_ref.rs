@@ -1,4 +1,6 @@
-use crate::{DomainRef, Endpoint};
+use crate::DomainRef;
+#[cfg(feature = "alloc")]
+use crate::Endpoint;
 
 /// An [Endpoint] reference.
 #[must_use]
@@ -29,6 +31,7 @@ impl<'a> From<EndpointRef<'a>> for (DomainRef<'a>, u16) {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'a> PartialEq<Endpoint> for EndpointRef<'a> {
     fn eq(&self, other: &Endpoint) -> bool {
         *self == other.to_ref()
@@ -52,7 +55,10 @@ impl<'a> EndpointRef<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Domain, DomainRef, Endpoint, EndpointRef};
+    use crate::{DomainRef, EndpointRef};
+
+    #[cfg(feature = "alloc")]
+    use crate::{Domain, Endpoint};
 
     #[test]
     fn construction() {
@@ -73,6 +79,7 @@ mod tests {
         assert_eq!(port, 80);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn equality() {
         let owned: Endpoint = Endpoint::new(Domain::localhost(), 80);
@@ -1,8 +1,11 @@
+#[cfg(feature = "alloc")]
 pub use endpoint::*;
 pub use endpoint_ref::*;
 
+#[cfg(feature = "alloc")]
 mod endpoint;
 mod endpoint_ref;
 
+#[cfg(feature = "alloc")]
 mod conversions;
 mod conversions_ref;
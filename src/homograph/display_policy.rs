@@ -0,0 +1,104 @@
+use crate::{LabelSafety, RestrictionLevel};
+
+/// A policy deciding which labels are safe to display in Unicode.
+///
+/// Labels that fail the policy are displayed in their ASCII A-label form, the way browsers display suspicious
+/// internationalized domain names.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct DisplayPolicy {
+    restriction_level: RestrictionLevel,
+    allow_whole_script_confusables: bool,
+}
+
+impl DisplayPolicy {
+    //! Special Policies
+
+    /// The browser policy: highly restrictive, rejecting whole-script confusables.
+    pub const BROWSER: Self = Self::new(RestrictionLevel::HighlyRestrictive, false);
+
+    /// The moderately restrictive policy, rejecting whole-script confusables.
+    pub const MODERATE: Self = Self::new(RestrictionLevel::ModeratelyRestrictive, false);
+
+    /// The ASCII-only policy: every internationalized label is displayed as its A-label.
+    pub const ASCII_ONLY: Self = Self::new(RestrictionLevel::AsciiOnly, false);
+}
+
+impl DisplayPolicy {
+    //! Construction
+
+    /// Creates a new [DisplayPolicy].
+    ///
+    /// Labels must satisfy the `restriction_level`; whole-script confusables are also rejected unless
+    /// `allow_whole_script_confusables` is set.
+    #[must_use]
+    pub const fn new(restriction_level: RestrictionLevel, allow_whole_script_confusables: bool) -> Self {
+        Self {
+            restriction_level,
+            allow_whole_script_confusables,
+        }
+    }
+}
+
+impl Default for DisplayPolicy {
+    fn default() -> Self {
+        Self::BROWSER
+    }
+}
+
+impl DisplayPolicy {
+    //! Properties
+
+    /// Gets the least restrictive level a label may have.
+    #[must_use]
+    pub const fn restriction_level(&self) -> RestrictionLevel {
+        self.restriction_level
+    }
+
+    /// Checks if whole-script confusable labels are allowed.
+    #[must_use]
+    pub const fn allows_whole_script_confusables(&self) -> bool {
+        self.allow_whole_script_confusables
+    }
+}
+
+impl DisplayPolicy {
+    //! Evaluation
+
+    /// Checks if the `label` may be displayed in Unicode.
+    #[must_use]
+    pub fn allows(&self, label: &LabelSafety) -> bool {
+        label.restriction_level() <= self.restriction_level
+            && (self.allow_whole_script_confusables || !label.is_whole_script_confusable())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DisplayPolicy, LabelSafety, RestrictionLevel};
+
+    #[test]
+    fn default() {
+        assert_eq!(DisplayPolicy::default(), DisplayPolicy::BROWSER);
+    }
+
+    #[test]
+    fn allows() {
+        let permissive: DisplayPolicy = DisplayPolicy::new(RestrictionLevel::Unrestricted, true);
+        let test_cases: &[(&str, DisplayPolicy, bool)] = &[
+            ("example", DisplayPolicy::ASCII_ONLY, true),
+            ("bücher", DisplayPolicy::ASCII_ONLY, false),
+            ("bücher", DisplayPolicy::BROWSER, true),
+            ("аpple", DisplayPolicy::BROWSER, false),
+            ("аpple", permissive, true),
+            ("аррӏе", DisplayPolicy::BROWSER, false),
+            ("аррӏе", DisplayPolicy::MODERATE, false),
+            ("аррӏе", permissive, true),
+            ("пример", DisplayPolicy::BROWSER, true),
+        ];
+
+        for (label, policy, expected) in test_cases {
+            let result: bool = policy.allows(&LabelSafety::new(label));
+            assert_eq!(result, *expected, "label={} policy={:?}", label, policy);
+        }
+    }
+}
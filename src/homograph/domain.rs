@@ -0,0 +1,148 @@
+use crate::ParseError::InvalidDomain;
+use crate::{DisplayPolicy, Domain, DomainRef, LabelSafety, ParseError};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+impl Domain {
+    //! Homograph Safety
+
+    /// Analyzes the homograph safety of each label, in order.
+    ///
+    /// Returns an error if a label contains invalid punycode. (example: `xn--a.example`)
+    pub fn label_safety(&self) -> Result<Vec<LabelSafety>, ParseError> {
+        self.to_ref().label_safety()
+    }
+
+    /// Gets the confusable skeleton of the Unicode name: the label skeletons joined with dots.
+    ///
+    /// Names that look alike have equal skeletons. (example: `xn--pple-43d.com`, the Cyrillic-led `аpple.com`, has the
+    /// same skeleton as `apple.com`)
+    ///
+    /// Returns an error if a label contains invalid punycode. (example: `xn--a.example`)
+    pub fn skeleton(&self) -> Result<String, ParseError> {
+        self.to_ref().skeleton()
+    }
+
+    /// Converts the domain name to Unicode for display, keeping the A-label of each label that fails the `policy`.
+    ///
+    /// Labels with invalid punycode are kept as A-labels.
+    #[must_use]
+    pub fn to_display_unicode(&self, policy: DisplayPolicy) -> String {
+        self.to_ref().to_display_unicode(policy)
+    }
+}
+
+impl<'a> DomainRef<'a> {
+    //! Homograph Safety
+
+    /// Analyzes the homograph safety of each label, in order.
+    ///
+    /// Returns an error if a label contains invalid punycode. (example: `xn--a.example`)
+    pub fn label_safety(self) -> Result<Vec<LabelSafety>, ParseError> {
+        self.labels()
+            .map(|label| Self::label_to_unicode(label).map(|label| LabelSafety::new(label.as_str())))
+            .collect()
+    }
+
+    /// Gets the confusable skeleton of the Unicode name: the label skeletons joined with dots.
+    ///
+    /// Names that look alike have equal skeletons. (example: `xn--pple-43d.com`, the Cyrillic-led `аpple.com`, has the
+    /// same skeleton as `apple.com`)
+    ///
+    /// Returns an error if a label contains invalid punycode. (example: `xn--a.example`)
+    pub fn skeleton(self) -> Result<String, ParseError> {
+        let labels: Vec<LabelSafety> = self.label_safety()?;
+        let skeletons: Vec<&str> = labels.iter().map(LabelSafety::skeleton).collect();
+        Ok(skeletons.join("."))
+    }
+
+    /// Converts the domain name to Unicode for display, keeping the A-label of each label that fails the `policy`.
+    ///
+    /// Labels with invalid punycode are kept as A-labels.
+    #[must_use]
+    pub fn to_display_unicode(self, policy: DisplayPolicy) -> String {
+        let mut result: String = String::with_capacity(self.name().len());
+        for (i, label) in self.labels().enumerate() {
+            if i != 0 {
+                result.push('.');
+            }
+            match Self::label_to_unicode(label) {
+                Ok(unicode) if policy.allows(&LabelSafety::new(unicode.as_str())) => result.push_str(unicode.as_str()),
+                _ => result.push_str(label),
+            }
+        }
+        result
+    }
+
+    /// Converts the ASCII `label` to its Unicode form.
+    fn label_to_unicode(label: &str) -> Result<String, ParseError> {
+        if label.starts_with("xn--") {
+            let (unicode, result) = idna::domain_to_unicode(label);
+            result.map_err(|_| InvalidDomain)?;
+            Ok(unicode)
+        } else {
+            Ok(label.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::InvalidDomain;
+    use crate::{DisplayPolicy, Domain, DomainRef, LabelSafety, ParseError};
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn label_safety() {
+        let domain: Domain = Domain::parse_unicode("аpple.bücher.com").unwrap();
+        let labels: Vec<LabelSafety> = domain.label_safety().unwrap();
+        let unicode: Vec<&str> = labels.iter().map(LabelSafety::unicode).collect();
+        assert_eq!(unicode, ["аpple", "bücher", "com"]);
+        assert!(labels[0].is_mixed_script());
+        assert!(!labels[1].is_mixed_script());
+
+        let domain: Domain = Domain::try_from("xn--a.example").unwrap();
+        assert_eq!(domain.label_safety(), Err(InvalidDomain));
+    }
+
+    #[test]
+    fn skeleton() {
+        let test_cases: &[(&str, Result<&str, ParseError>)] = &[
+            ("apple.com", Ok("apple.corn")),
+            ("xn--pple-43d.com", Ok("apple.corn")),
+            ("xn--80ak6aa92e.com", Ok("appie.corn")),
+            ("xn--a.example", Err(InvalidDomain)),
+        ];
+
+        for (name, expected) in test_cases {
+            let domain: DomainRef = DomainRef::try_from(*name).unwrap();
+            let result: Result<String, ParseError> = domain.skeleton();
+            assert_eq!(result, (*expected).map(String::from), "name={}", name);
+        }
+    }
+
+    #[test]
+    fn to_display_unicode() {
+        let test_cases: &[(&str, DisplayPolicy, &str)] = &[
+            ("example.com", DisplayPolicy::BROWSER, "example.com"),
+            ("xn--bcher-kva.example", DisplayPolicy::BROWSER, "bücher.example"),
+            (
+                "xn--bcher-kva.example",
+                DisplayPolicy::ASCII_ONLY,
+                "xn--bcher-kva.example",
+            ),
+            ("xn--pple-43d.com", DisplayPolicy::BROWSER, "xn--pple-43d.com"),
+            ("xn--80ak6aa92e.com", DisplayPolicy::BROWSER, "xn--80ak6aa92e.com"),
+            ("xn--e1afmkfd.xn--p1ai", DisplayPolicy::BROWSER, "пример.рф"),
+            ("xn--a.xn--bcher-kva", DisplayPolicy::BROWSER, "xn--a.bücher"),
+        ];
+
+        for (name, policy, expected) in test_cases {
+            let domain: Domain = Domain::try_from(*name).unwrap();
+            let result: String = domain.to_display_unicode(*policy);
+            assert_eq!(result, *expected, "name={} policy={:?}", name, policy);
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use crate::RestrictionLevel;
+use alloc::string::String;
+use alloc::string::ToString;
+use unicode_script::Script;
+use unicode_security::MixedScript;
+
+/// The homograph safety of a single domain label, per [UTS 39](https://www.unicode.org/reports/tr39/).
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct LabelSafety {
+    unicode: String,
+    skeleton: String,
+    restriction_level: RestrictionLevel,
+    mixed_script: bool,
+    whole_script_confusable: bool,
+}
+
+impl LabelSafety {
+    //! Construction
+
+    /// Analyzes the Unicode `label`.
+    ///
+    /// A label is mixed-script when no single script covers all of its characters. It is whole-script confusable
+    /// when it is written in a single non-Latin script & its confusable skeleton is entirely ASCII, so it can be
+    /// mistaken for a Latin label. (example: Cyrillic `аррӏе` for `apple`)
+    pub(crate) fn new(label: &str) -> Self {
+        let skeleton: String = unicode_security::skeleton(label).collect();
+        let mixed_script: bool = !label.is_single_script();
+        let whole_script_confusable: bool = !label.is_ascii()
+            && !mixed_script
+            && !label.resolve_script_set().base.contains_script(Script::Latin)
+            && skeleton.is_ascii();
+        Self {
+            unicode: label.to_string(),
+            skeleton,
+            restriction_level: RestrictionLevel::detect(label),
+            mixed_script,
+            whole_script_confusable,
+        }
+    }
+}
+
+impl LabelSafety {
+    //! Properties
+
+    /// Gets the Unicode form of the label.
+    #[must_use]
+    pub fn unicode(&self) -> &str {
+        self.unicode.as_str()
+    }
+
+    /// Gets the confusable skeleton of the label.
+    ///
+    /// Labels that look alike have equal skeletons. (example: `аpple` & `apple` are both `apple`)
+    ///
+    /// A skeleton is a comparison key rather than display text: it maps characters to prototypes, which can turn
+    /// one letter into several. (example: `m` becomes `rn`)
+    #[must_use]
+    pub fn skeleton(&self) -> &str {
+        self.skeleton.as_str()
+    }
+
+    /// Gets the restriction level of the label.
+    #[must_use]
+    pub const fn restriction_level(&self) -> RestrictionLevel {
+        self.restriction_level
+    }
+
+    /// Checks if the label mixes scripts that no single script covers.
+    #[must_use]
+    pub const fn is_mixed_script(&self) -> bool {
+        self.mixed_script
+    }
+
+    /// Checks if the label is written in a single non-Latin script that can be mistaken for a Latin label.
+    #[must_use]
+    pub const fn is_whole_script_confusable(&self) -> bool {
+        self.whole_script_confusable
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LabelSafety, RestrictionLevel};
+
+    #[test]
+    fn analysis() {
+        let test_cases: &[(&str, &str, RestrictionLevel, bool, bool)] = &[
+            ("example", "exarnple", RestrictionLevel::AsciiOnly, false, false),
+            ("bücher", "bu\u{308}cher", RestrictionLevel::SingleScript, false, false),
+            ("аpple", "apple", RestrictionLevel::MinimallyRestrictive, true, false),
+            ("аррӏе", "appie", RestrictionLevel::SingleScript, false, true),
+            ("пример", "πpᴎʍep", RestrictionLevel::SingleScript, false, false),
+        ];
+
+        for (label, skeleton, level, mixed_script, whole_script_confusable) in test_cases {
+            let safety: LabelSafety = LabelSafety::new(label);
+            assert_eq!(safety.unicode(), *label, "label={}", label);
+            assert_eq!(safety.skeleton(), *skeleton, "label={}", label);
+            assert_eq!(safety.restriction_level(), *level, "label={}", label);
+            assert_eq!(safety.is_mixed_script(), *mixed_script, "label={}", label);
+            assert_eq!(
+                safety.is_whole_script_confusable(),
+                *whole_script_confusable,
+                "label={}",
+                label
+            );
+        }
+    }
+}
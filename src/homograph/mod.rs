@@ -0,0 +1,9 @@
+pub use display_policy::*;
+pub use label_safety::*;
+pub use restriction_level::*;
+
+mod display_policy;
+mod label_safety;
+mod restriction_level;
+
+mod domain;
@@ -0,0 +1,72 @@
+/// A [UTS 39](https://www.unicode.org/reports/tr39/#Restriction_Level_Detection) restriction level.
+///
+/// The levels are ordered from most to least restrictive, so a label satisfies a level when its own level is less
+/// than or equal to it.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum RestrictionLevel {
+    /// Only ASCII characters.
+    AsciiOnly,
+
+    /// Characters from a single script, such as Latin or Cyrillic.
+    SingleScript,
+
+    /// A single script plus Latin, limited to the Han-based combinations: Han with Hiragana & Katakana, Han with
+    /// Bopomofo, or Han with Hangul.
+    HighlyRestrictive,
+
+    /// A single script plus Latin, where the other script is any recommended script except Cyrillic & Greek.
+    ModeratelyRestrictive,
+
+    /// Any mix of scripts made of characters allowed in identifiers.
+    MinimallyRestrictive,
+
+    /// Anything, including characters not allowed in identifiers.
+    Unrestricted,
+}
+
+impl RestrictionLevel {
+    //! Detection
+
+    /// Detects the restriction level of the `text`.
+    pub(crate) fn detect(text: &str) -> Self {
+        use unicode_security::RestrictionLevelDetection;
+
+        match text.detect_restriction_level() {
+            unicode_security::RestrictionLevel::ASCIIOnly => Self::AsciiOnly,
+            unicode_security::RestrictionLevel::SingleScript => Self::SingleScript,
+            unicode_security::RestrictionLevel::HighlyRestrictive => Self::HighlyRestrictive,
+            unicode_security::RestrictionLevel::ModeratelyRestrictive => Self::ModeratelyRestrictive,
+            unicode_security::RestrictionLevel::MinimallyRestrictive => Self::MinimallyRestrictive,
+            unicode_security::RestrictionLevel::Unrestricted => Self::Unrestricted,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RestrictionLevel;
+
+    #[test]
+    fn detect() {
+        let test_cases: &[(&str, RestrictionLevel)] = &[
+            ("example", RestrictionLevel::AsciiOnly),
+            ("bücher", RestrictionLevel::SingleScript),
+            ("пример", RestrictionLevel::SingleScript),
+            ("abc日本語", RestrictionLevel::HighlyRestrictive),
+            ("abcनमस्ते", RestrictionLevel::ModeratelyRestrictive),
+            ("аpple", RestrictionLevel::MinimallyRestrictive),
+        ];
+
+        for (text, expected) in test_cases {
+            let result: RestrictionLevel = RestrictionLevel::detect(text);
+            assert_eq!(result, *expected, "text={}", text);
+        }
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(RestrictionLevel::AsciiOnly < RestrictionLevel::SingleScript);
+        assert!(RestrictionLevel::HighlyRestrictive < RestrictionLevel::ModeratelyRestrictive);
+        assert!(RestrictionLevel::MinimallyRestrictive < RestrictionLevel::Unrestricted);
+    }
+}
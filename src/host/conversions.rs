@@ -1,4 +1,4 @@
-use crate::{Authority, Domain, DomainRef, Host, HostRef, IPAddress};
+use crate::{Authorities, Authority, Domain, DomainRef, Host, HostRef, IPAddress, PortRange};
 
 impl Host {
     //! Conversions
@@ -16,6 +16,11 @@ impl Host {
         Authority::new(self, port)
     }
 
+    /// Converts the host to an iterator over the authorities with each of the `ports`.
+    pub const fn to_authorities(self, ports: PortRange) -> Authorities {
+        Authorities::new(self, ports)
+    }
+
     /// Converts the host to an optional domain.
     #[must_use]
     pub fn to_domain(self) -> Option<Domain> {
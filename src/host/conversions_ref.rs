@@ -1,9 +1,12 @@
-use crate::{Authority, AuthorityRef, Domain, DomainRef, Host, HostRef, IPAddress};
+#[cfg(feature = "alloc")]
+use crate::{Authority, Domain, Host};
+use crate::{AuthorityRef, DomainRef, HostRef, IPAddress};
 
 impl<'a> HostRef<'a> {
     //! Conversions
 
     /// Converts the host reference to a host.
+    #[cfg(feature = "alloc")]
     pub fn to_host(self) -> Host {
         match self {
             Self::Name(domain) => Host::Name(domain.to_domain()),
@@ -12,6 +15,7 @@ impl<'a> HostRef<'a> {
     }
 
     /// Converts the host reference to an authority with the `port`.
+    #[cfg(feature = "alloc")]
     pub fn to_authority(self, port: u16) -> Authority {
         Authority::new(self.to_host(), port)
     }
@@ -22,6 +26,7 @@ impl<'a> HostRef<'a> {
     }
 
     /// Converts the host reference to an optional domain.
+    #[cfg(feature = "alloc")]
     #[must_use]
     pub fn to_domain(self) -> Option<Domain> {
         if let Self::Name(domain) = self {
@@ -48,6 +53,7 @@ impl<'a> HostRef<'a> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'a> From<&'a Host> for HostRef<'a> {
     fn from(host: &'a Host) -> Self {
         host.to_ref()
@@ -68,8 +74,12 @@ impl<'a, A: Into<IPAddress>> From<A> for HostRef<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Authority, AuthorityRef, Domain, DomainRef, Host, HostRef, IPAddress, IPv4Address};
+    use crate::{DomainRef, HostRef, IPAddress, IPv4Address};
 
+    #[cfg(feature = "alloc")]
+    use crate::{Authority, AuthorityRef, Domain, Host};
+
+    #[cfg(feature = "alloc")]
     #[test]
     fn ref_to_host() {
         let host: HostRef = HostRef::Name(DomainRef::LOCALHOST);
@@ -83,6 +93,7 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn ref_to_authority() {
         let host: HostRef = DomainRef::LOCALHOST.to_host_ref();
@@ -104,6 +115,7 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn ref_to_domain() {
         let host: HostRef = DomainRef::LOCALHOST.to_host_ref();
@@ -138,6 +150,7 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn ref_from() {
         let expected: HostRef = HostRef::Name(DomainRef::LOCALHOST);
@@ -1,4 +1,6 @@
-use crate::{DomainRef, Host, IPAddress};
+#[cfg(feature = "alloc")]
+use crate::Host;
+use crate::{DomainRef, IPAddress};
 
 /// Either a [DomainRef] or an [IPAddress].
 #[must_use]
@@ -11,6 +13,7 @@ pub enum HostRef<'a> {
     Address(IPAddress),
 }
 
+#[cfg(feature = "alloc")]
 impl<'a> PartialEq<Host> for HostRef<'a> {
     fn eq(&self, other: &Host) -> bool {
         *self == other.to_ref()
@@ -35,8 +38,12 @@ impl<'a> HostRef<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Domain, DomainRef, Host, HostRef, IPv4Address};
+    use crate::{DomainRef, HostRef, IPv4Address};
 
+    #[cfg(feature = "alloc")]
+    use crate::{Domain, Host};
+
+    #[cfg(feature = "alloc")]
     #[test]
     fn equality() {
         let owned: Host = Domain::localhost().into();
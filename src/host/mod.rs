@@ -1,8 +1,11 @@
+#[cfg(feature = "alloc")]
 pub use host::*;
 pub use host_ref::*;
 
+#[cfg(feature = "alloc")]
 mod host;
 mod host_ref;
 
+#[cfg(feature = "alloc")]
 mod conversions;
 mod conversions_ref;
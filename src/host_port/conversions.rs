@@ -0,0 +1,94 @@
+use crate::{Authority, Host, HostPort, HostPortRef};
+
+impl HostPort {
+    //! Conversions
+
+    /// Converts the host & port to a host & port reference.
+    pub fn to_ref(&self) -> HostPortRef<'_> {
+        HostPortRef::new(self.host(), self.port())
+    }
+
+    /// Converts the host & port to an authority, using the `default_port` when the port is absent.
+    pub fn to_authority(self, default_port: u16) -> Authority {
+        let (host, port): (Host, Option<u16>) = self.into();
+        host.to_authority(port.unwrap_or(default_port))
+    }
+
+    /// Removes the port if it equals the `default_port`, so the display omits it.
+    pub fn without_default_port(self, default_port: u16) -> Self {
+        let (host, port): (Host, Option<u16>) = self.into();
+        Self::new(host, port.filter(|port| *port != default_port))
+    }
+}
+
+impl From<Host> for HostPort {
+    fn from(host: Host) -> Self {
+        Self::new(host, None)
+    }
+}
+
+impl From<Authority> for HostPort {
+    fn from(authority: Authority) -> Self {
+        let (host, port): (Host, u16) = authority.into();
+        Self::new(host, Some(port))
+    }
+}
+
+impl<'a> From<HostPortRef<'a>> for HostPort {
+    fn from(host_port: HostPortRef<'a>) -> Self {
+        host_port.to_host_port()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Authority, Domain, DomainRef, HostPort, HostPortRef, HostRef};
+
+    #[test]
+    fn host_port_to_ref() {
+        let host_port: HostPort = HostPort::new(Domain::localhost().to_host(), Some(80));
+        let result: HostPortRef = host_port.to_ref();
+        let expected: HostPortRef = HostPortRef::new(HostRef::Name(DomainRef::LOCALHOST), Some(80));
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn host_port_to_authority() {
+        let host_port: HostPort = HostPort::new(Domain::localhost().to_host(), Some(8443));
+        let result: Authority = host_port.to_authority(443);
+        assert_eq!(result, Domain::localhost().to_host().to_authority(8443));
+
+        let host_port: HostPort = HostPort::new(Domain::localhost().to_host(), None);
+        let result: Authority = host_port.to_authority(443);
+        assert_eq!(result, Domain::localhost().to_host().to_authority(443));
+    }
+
+    #[test]
+    fn host_port_without_default_port() {
+        let test_cases: &[(Option<u16>, Option<u16>)] = &[(None, None), (Some(443), None), (Some(8443), Some(8443))];
+
+        for (port, expected) in test_cases {
+            let host_port: HostPort = HostPort::new(Domain::localhost().to_host(), *port);
+            let result: Option<u16> = host_port.without_default_port(443).port();
+            assert_eq!(result, *expected, "port={:?}", port);
+        }
+    }
+
+    #[test]
+    fn from_host() {
+        let host_port: HostPort = Domain::localhost().to_host().into();
+        assert_eq!(host_port, HostPort::new(Domain::localhost().to_host(), None));
+    }
+
+    #[test]
+    fn from_authority() {
+        let host_port: HostPort = Domain::localhost().to_host().to_authority(80).into();
+        assert_eq!(host_port, HostPort::new(Domain::localhost().to_host(), Some(80)));
+    }
+
+    #[test]
+    fn from_ref() {
+        let host_port: HostPort = HostPortRef::new(HostRef::Name(DomainRef::LOCALHOST), None).into();
+        assert_eq!(host_port, HostPort::new(Domain::localhost().to_host(), None));
+    }
+}
@@ -0,0 +1,96 @@
+#[cfg(feature = "alloc")]
+use crate::{Authority, HostPort};
+use crate::{AuthorityRef, HostPortRef, HostRef};
+
+impl<'a> HostPortRef<'a> {
+    //! Conversions
+
+    /// Converts the host & port reference to a host & port.
+    #[cfg(feature = "alloc")]
+    pub fn to_host_port(self) -> HostPort {
+        HostPort::new(self.host().to_host(), self.port())
+    }
+
+    /// Converts the host & port reference to an authority, using the `default_port` when the port is absent.
+    #[cfg(feature = "alloc")]
+    pub fn to_authority(self, default_port: u16) -> Authority {
+        self.to_authority_ref(default_port).to_authority()
+    }
+
+    /// Converts the host & port reference to an authority reference, using the `default_port` when the port is
+    /// absent.
+    pub const fn to_authority_ref(self, default_port: u16) -> AuthorityRef<'a> {
+        AuthorityRef::new(self.host(), self.port_or(default_port))
+    }
+
+    /// Removes the port if it equals the `default_port`, so the display omits it.
+    pub const fn without_default_port(self, default_port: u16) -> Self {
+        match self.port() {
+            Some(port) if port == default_port => Self::new(self.host(), None),
+            port => Self::new(self.host(), port),
+        }
+    }
+}
+
+impl<'a> From<HostRef<'a>> for HostPortRef<'a> {
+    fn from(host: HostRef<'a>) -> Self {
+        Self::new(host, None)
+    }
+}
+
+impl<'a> From<AuthorityRef<'a>> for HostPortRef<'a> {
+    fn from(authority: AuthorityRef<'a>) -> Self {
+        Self::new(authority.host(), Some(authority.port()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DomainRef, HostPortRef, HostRef};
+
+    #[cfg(feature = "alloc")]
+    use crate::{AuthorityRef, Domain, HostPort, IPv6Address};
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn host_port_ref_to_host_port() {
+        let host_port: HostPortRef = HostPortRef::new(HostRef::Name(DomainRef::LOCALHOST), Some(80));
+        let result: HostPort = host_port.to_host_port();
+        assert_eq!(result, HostPort::new(Domain::localhost().to_host(), Some(80)));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn host_port_ref_to_authority() {
+        let host_port: HostPortRef = HostPortRef::new(IPv6Address::LOCALHOST.to_host_ref(), None);
+        let result: AuthorityRef = host_port.to_authority_ref(443);
+        assert_eq!(result, IPv6Address::LOCALHOST.to_host_ref().to_authority_ref(443));
+        assert_eq!(host_port.to_authority(443), result);
+    }
+
+    #[test]
+    fn host_port_ref_without_default_port() {
+        let test_cases: &[(Option<u16>, Option<u16>)] = &[(None, None), (Some(80), None), (Some(8080), Some(8080))];
+
+        for (port, expected) in test_cases {
+            let host_port: HostPortRef = HostPortRef::new(HostRef::Name(DomainRef::LOCALHOST), *port);
+            let result: Option<u16> = host_port.without_default_port(80).port();
+            assert_eq!(result, *expected, "port={:?}", port);
+        }
+    }
+
+    #[test]
+    fn from_host_ref() {
+        let host_port: HostPortRef = HostRef::Name(DomainRef::LOCALHOST).into();
+        assert_eq!(host_port, HostPortRef::new(HostRef::Name(DomainRef::LOCALHOST), None));
+    }
+
+    #[test]
+    fn from_authority_ref() {
+        let host_port: HostPortRef = HostRef::Name(DomainRef::LOCALHOST).to_authority_ref(80).into();
+        assert_eq!(
+            host_port,
+            HostPortRef::new(HostRef::Name(DomainRef::LOCALHOST), Some(80))
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{Host, HostPortRef, HostRef};
+
+/// A [Host] with an optional port. (`example.com` or `example.com:8443`)
+///
+/// This is the shape of an HTTP `Host` header or a `--server` flag, where the port falls back to a default. Use
+/// [`Self::to_authority`] to fill in the default port.
+#[must_use]
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct HostPort {
+    host: Host,
+    port: Option<u16>,
+}
+
+impl HostPort {
+    //! Construction
+
+    /// Creates a new [HostPort].
+    pub const fn new(host: Host, port: Option<u16>) -> Self {
+        Self { host, port }
+    }
+}
+
+impl<H: Into<Host>> From<(H, Option<u16>)> for HostPort {
+    fn from(tuple: (H, Option<u16>)) -> Self {
+        Self::new(tuple.0.into(), tuple.1)
+    }
+}
+
+impl From<HostPort> for (Host, Option<u16>) {
+    fn from(host_port: HostPort) -> Self {
+        (host_port.host, host_port.port)
+    }
+}
+
+impl<'a> PartialEq<HostPortRef<'a>> for HostPort {
+    fn eq(&self, other: &HostPortRef<'a>) -> bool {
+        self.to_ref() == *other
+    }
+}
+
+impl HostPort {
+    //! Properties
+
+    /// Gets the host reference.
+    pub fn host(&self) -> HostRef<'_> {
+        self.host.to_ref()
+    }
+
+    /// Gets the optional port.
+    #[must_use]
+    pub const fn port(&self) -> Option<u16> {
+        self.port
+    }
+
+    /// Gets the port, or the `default_port` when the port is absent.
+    #[must_use]
+    pub fn port_or(&self, default_port: u16) -> u16 {
+        self.port.unwrap_or(default_port)
+    }
+}
+
+impl HostPort {
+    //! Matching
+
+    /// Checks if the port is present.
+    #[must_use]
+    pub const fn has_port(&self) -> bool {
+        self.port.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Domain, Host, HostPort};
+
+    #[test]
+    fn construction() {
+        let host_port: HostPort = HostPort::new(Domain::localhost().to_host(), Some(80));
+        assert_eq!(host_port.host, Domain::localhost().to_host());
+        assert_eq!(host_port.port, Some(80));
+
+        let host_port: HostPort = (Domain::localhost(), None).into();
+        assert_eq!(host_port.host, Domain::localhost().to_host());
+        assert_eq!(host_port.port, None);
+    }
+
+    #[test]
+    fn deconstruction() {
+        let host_port: HostPort = (Domain::localhost(), Some(80)).into();
+        let (host, port): (Host, Option<u16>) = host_port.into();
+        assert_eq!(host, Domain::localhost().to_host());
+        assert_eq!(port, Some(80));
+    }
+
+    #[test]
+    fn properties() {
+        let host_port: HostPort = HostPort::new(Domain::localhost().to_host(), Some(8443));
+        assert_eq!(host_port.host(), Domain::localhost().to_host().to_ref());
+        assert_eq!(host_port.port(), Some(8443));
+        assert_eq!(host_port.port_or(443), 8443);
+        assert!(host_port.has_port());
+
+        let host_port: HostPort = HostPort::new(Domain::localhost().to_host(), None);
+        assert_eq!(host_port.port(), None);
+        assert_eq!(host_port.port_or(443), 443);
+        assert!(!host_port.has_port());
+    }
+}
@@ -0,0 +1,116 @@
+#[cfg(feature = "alloc")]
+use crate::HostPort;
+use crate::HostRef;
+
+/// A [HostPort] reference.
+#[must_use]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct HostPortRef<'a> {
+    host: HostRef<'a>,
+    port: Option<u16>,
+}
+
+impl<'a> HostPortRef<'a> {
+    //! Construction
+
+    /// Creates a new [HostPortRef].
+    pub const fn new(host: HostRef<'a>, port: Option<u16>) -> Self {
+        Self { host, port }
+    }
+}
+
+impl<'a, H: Into<HostRef<'a>>> From<(H, Option<u16>)> for HostPortRef<'a> {
+    fn from(tuple: (H, Option<u16>)) -> Self {
+        Self::new(tuple.0.into(), tuple.1)
+    }
+}
+
+impl<'a> From<HostPortRef<'a>> for (HostRef<'a>, Option<u16>) {
+    fn from(host_port: HostPortRef<'a>) -> Self {
+        (host_port.host, host_port.port)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> PartialEq<HostPort> for HostPortRef<'a> {
+    fn eq(&self, other: &HostPort) -> bool {
+        *self == other.to_ref()
+    }
+}
+
+impl<'a> HostPortRef<'a> {
+    //! Properties
+
+    /// Gets the host reference.
+    pub const fn host(self) -> HostRef<'a> {
+        self.host
+    }
+
+    /// Gets the optional port.
+    #[must_use]
+    pub const fn port(self) -> Option<u16> {
+        self.port
+    }
+
+    /// Gets the port, or the `default_port` when the port is absent.
+    #[must_use]
+    pub const fn port_or(self, default_port: u16) -> u16 {
+        if let Some(port) = self.port { port } else { default_port }
+    }
+}
+
+impl<'a> HostPortRef<'a> {
+    //! Matching
+
+    /// Checks if the port is present.
+    #[must_use]
+    pub const fn has_port(self) -> bool {
+        self.port.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DomainRef, HostPortRef, HostRef};
+
+    #[cfg(feature = "alloc")]
+    use crate::{Domain, HostPort};
+
+    #[test]
+    fn construction() {
+        let host_port: HostPortRef = HostPortRef::new(HostRef::Name(DomainRef::LOCALHOST), Some(80));
+        assert_eq!(host_port.host, HostRef::Name(DomainRef::LOCALHOST));
+        assert_eq!(host_port.port, Some(80));
+
+        let host_port: HostPortRef = (DomainRef::LOCALHOST, None).into();
+        assert_eq!(host_port.host, HostRef::Name(DomainRef::LOCALHOST));
+        assert_eq!(host_port.port, None);
+    }
+
+    #[test]
+    fn deconstruction() {
+        let host_port: HostPortRef = (DomainRef::LOCALHOST, Some(80)).into();
+        let (host, port): (HostRef, Option<u16>) = host_port.into();
+        assert_eq!(host, HostRef::Name(DomainRef::LOCALHOST));
+        assert_eq!(port, Some(80));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn equality() {
+        let eighty: HostPort = HostPort::new(Domain::localhost().to_host(), Some(80));
+        assert_eq!(eighty.to_ref(), eighty);
+
+        let absent: HostPort = HostPort::new(Domain::localhost().to_host(), None);
+        assert_ne!(eighty.to_ref(), absent);
+    }
+
+    #[test]
+    fn properties() {
+        let host_port: HostPortRef = HostPortRef::new(HostRef::Name(DomainRef::LOCALHOST), None);
+        assert_eq!(host_port.host(), HostRef::Name(DomainRef::LOCALHOST));
+        assert_eq!(host_port.port(), None);
+        assert_eq!(host_port.port_or(80), 80);
+        assert!(!host_port.has_port());
+    }
+}
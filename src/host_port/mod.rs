@@ -0,0 +1,11 @@
+#[cfg(feature = "alloc")]
+pub use host_port::*;
+pub use host_port_ref::*;
+
+#[cfg(feature = "alloc")]
+mod host_port;
+mod host_port_ref;
+
+#[cfg(feature = "alloc")]
+mod conversions;
+mod conversions_ref;
@@ -0,0 +1,106 @@
+use crate::{Domain, IPAddress};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// An entry of a hosts file: an IP address, its canonical name & aliases, & an optional comment.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct HostsEntry {
+    ip: IPAddress,
+    names: Vec<Domain>,
+    comment: Option<String>,
+}
+
+impl HostsEntry {
+    //! Construction
+
+    /// Creates a new [HostsEntry] for the `ip` & its canonical `name`.
+    #[must_use]
+    pub fn new(ip: IPAddress, name: Domain) -> Self {
+        Self {
+            ip,
+            names: vec![name],
+            comment: None,
+        }
+    }
+
+    /// Adds the `alias` to the entry.
+    #[must_use]
+    pub fn with_alias(mut self, alias: Domain) -> Self {
+        self.names.push(alias);
+        self
+    }
+
+    /// Sets the `comment`, the trimmed text after the `#` of the line.
+    ///
+    /// Line breaks in the `comment` are replaced with spaces, so the entry is always written as a single line.
+    #[must_use]
+    pub fn with_comment<S>(mut self, comment: S) -> Self
+    where
+        S: Into<String>,
+    {
+        let mut comment: String = comment.into();
+        if comment.contains(['\r', '\n']) {
+            comment = comment.replace(['\r', '\n'], " ");
+        }
+        self.comment = Some(comment);
+        self
+    }
+
+    /// Creates a new [HostsEntry] from its parsed parts. The `names` must not be empty.
+    pub(crate) const fn from_parts(ip: IPAddress, names: Vec<Domain>, comment: Option<String>) -> Self {
+        Self { ip, names, comment }
+    }
+}
+
+impl HostsEntry {
+    //! Properties
+
+    /// Gets the IP address.
+    pub const fn ip(&self) -> IPAddress {
+        self.ip
+    }
+
+    /// Gets the names: the canonical name followed by the aliases.
+    pub fn names(&self) -> &[Domain] {
+        self.names.as_slice()
+    }
+
+    /// Gets the canonical name.
+    pub fn canonical_name(&self) -> &Domain {
+        &self.names[0]
+    }
+
+    /// Gets the optional comment.
+    #[must_use]
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+}
+
+impl From<HostsEntry> for (IPAddress, Vec<Domain>) {
+    fn from(entry: HostsEntry) -> Self {
+        (entry.ip, entry.names)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Domain, HostsEntry, IPAddress, IPv4Address};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn properties() {
+        let entry: HostsEntry = HostsEntry::new(IPv4Address::LOCALHOST.to_ip(), Domain::localhost())
+            .with_alias(Domain::example())
+            .with_comment("loopback");
+        assert_eq!(entry.ip(), IPv4Address::LOCALHOST.to_ip());
+        assert_eq!(entry.names(), [Domain::localhost(), Domain::example()]);
+        assert_eq!(*entry.canonical_name(), Domain::localhost());
+        assert_eq!(entry.comment(), Some("loopback"));
+
+        let (ip, names): (IPAddress, Vec<Domain>) = entry.into();
+        assert_eq!(ip, IPv4Address::LOCALHOST.to_ip());
+        assert_eq!(names, [Domain::localhost(), Domain::example()]);
+    }
+}
@@ -0,0 +1,112 @@
+use crate::HostsEntry;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A hosts file, such as `/etc/hosts`.
+///
+/// Parsed lines keep their original text, so [Display](core::fmt::Display) writes unchanged lines back byte for byte,
+/// including blank lines, comments, & whitespace. Entries added with [`Self::push`] are written in the canonical
+/// `ip<TAB>names # comment` form.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct HostsFile {
+    lines: Vec<HostsLine>,
+    trailing_newline: bool,
+}
+
+/// A line of a hosts file.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub(crate) struct HostsLine {
+    /// The original text without the newline, or `None` for an added entry.
+    pub(crate) text: Option<String>,
+
+    /// The entry, or `None` for a blank or comment-only line.
+    pub(crate) entry: Option<HostsEntry>,
+}
+
+impl HostsFile {
+    //! Construction
+
+    /// Creates an empty [HostsFile].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            lines: Vec::new(),
+            trailing_newline: true,
+        }
+    }
+
+    /// Creates a [HostsFile] from its parsed parts.
+    pub(crate) const fn from_parts(lines: Vec<HostsLine>, trailing_newline: bool) -> Self {
+        Self {
+            lines,
+            trailing_newline,
+        }
+    }
+
+    /// Adds the `entry` as a new line at the end of the file.
+    pub fn push(&mut self, entry: HostsEntry) {
+        self.lines.push(HostsLine {
+            text: None,
+            entry: Some(entry),
+        });
+        self.trailing_newline = true;
+    }
+}
+
+impl FromIterator<HostsEntry> for HostsFile {
+    fn from_iter<I: IntoIterator<Item = HostsEntry>>(entries: I) -> Self {
+        let mut file: Self = Self::new();
+        for entry in entries {
+            file.push(entry);
+        }
+        file
+    }
+}
+
+impl HostsFile {
+    //! Properties
+
+    /// Gets the entries with their 1-based line numbers.
+    pub fn entries(&self) -> impl Iterator<Item = (usize, &HostsEntry)> {
+        self.lines
+            .iter()
+            .enumerate()
+            .filter_map(|(i, line)| line.entry.as_ref().map(|entry| (i + 1, entry)))
+    }
+
+    /// Gets the number of lines.
+    #[must_use]
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Gets the lines.
+    pub(crate) fn lines(&self) -> &[HostsLine] {
+        self.lines.as_slice()
+    }
+
+    /// Checks if the file ends with a newline.
+    pub(crate) const fn trailing_newline(&self) -> bool {
+        self.trailing_newline
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Domain, HostsEntry, HostsFile, IPv4Address, IPv6Address};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn push() {
+        let file: HostsFile = [
+            HostsEntry::new(IPv4Address::LOCALHOST.to_ip(), Domain::localhost()),
+            HostsEntry::new(IPv6Address::LOCALHOST.to_ip(), Domain::localhost()),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(file.line_count(), 2);
+
+        let lines: Vec<usize> = file.entries().map(|(line, _)| line).collect();
+        assert_eq!(lines, [1, 2]);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{Domain, DomainRef, HostsFile, IPAddress};
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use crate::Resolver;
+#[cfg(feature = "std")]
+use alloc::format;
+#[cfg(feature = "std")]
+use std::io;
+
+/// A resolver backed by a [HostsFile], answering forward & reverse lookups without a network.
+///
+/// Addresses & names are in file order, & a name listed on several lines has the addresses of each. Unknown domain
+/// names are an `io::ErrorKind::NotFound` error when resolving with the `std` feature's `Resolver`.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct HostsResolver {
+    addresses: BTreeMap<String, Vec<IPAddress>>,
+    names: BTreeMap<IPAddress, Vec<Domain>>,
+}
+
+impl From<&HostsFile> for HostsResolver {
+    fn from(file: &HostsFile) -> Self {
+        let mut resolver: Self = Self::default();
+        for (_, entry) in file.entries() {
+            let names: &mut Vec<Domain> = resolver.names.entry(entry.ip()).or_default();
+            for name in entry.names() {
+                if !names.contains(name) {
+                    names.push(name.clone());
+                }
+                let addresses: &mut Vec<IPAddress> = resolver.addresses.entry(name.to_string()).or_default();
+                if !addresses.contains(&entry.ip()) {
+                    addresses.push(entry.ip());
+                }
+            }
+        }
+        resolver
+    }
+}
+
+impl HostsResolver {
+    //! Lookups
+
+    /// Gets the IP addresses of the `domain`.
+    pub fn addresses(&self, domain: DomainRef) -> &[IPAddress] {
+        self.addresses.get(domain.name()).map_or(&[], Vec::as_slice)
+    }
+
+    /// Gets the names of the `ip`: the canonical name of its first entry, then the other names.
+    pub fn names(&self, ip: IPAddress) -> &[Domain] {
+        self.names.get(&ip).map_or(&[], Vec::as_slice)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Resolver for HostsResolver {
+    fn resolve_domain(&self, domain: DomainRef) -> io::Result<Vec<IPAddress>> {
+        let addresses: &[IPAddress] = self.addresses(domain);
+        if addresses.is_empty() {
+            Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("unknown domain: {}", domain),
+            ))
+        } else {
+            Ok(addresses.to_vec())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Domain, DomainRef, HostsFile, HostsResolver, IPAddress, IPv4Address, IPv6Address};
+
+    #[cfg(feature = "std")]
+    use crate::Resolver;
+
+    #[test]
+    fn lookups() {
+        let text: &str = "\
+127.0.0.1 localhost example.com
+::1 localhost
+127.0.0.1 LocalHost
+";
+        let file: HostsFile = HostsFile::parse_hosts(text.as_bytes()).unwrap();
+        let resolver: HostsResolver = HostsResolver::from(&file);
+
+        let expected: &[IPAddress] = &[IPv4Address::LOCALHOST.to_ip(), IPv6Address::LOCALHOST.to_ip()];
+        assert_eq!(resolver.addresses(DomainRef::LOCALHOST), expected);
+        assert_eq!(resolver.addresses(DomainRef::EXAMPLE), [IPv4Address::LOCALHOST.to_ip()]);
+
+        let expected: &[Domain] = &[Domain::localhost(), Domain::example()];
+        assert_eq!(resolver.names(IPv4Address::LOCALHOST.to_ip()), expected);
+        assert!(resolver.names(IPv4Address::UNSPECIFIED.to_ip()).is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn resolve() {
+        let file: HostsFile = HostsFile::parse_hosts(b"127.0.0.1 localhost example.com").unwrap();
+        let resolver: HostsResolver = HostsResolver::from(&file);
+
+        assert_eq!(
+            resolver.resolve_domain(DomainRef::EXAMPLE).unwrap(),
+            [IPv4Address::LOCALHOST.to_ip()]
+        );
+        assert!(
+            resolver
+                .resolve_domain(DomainRef::try_from("unknown.example").unwrap())
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,7 @@
+pub use hosts_entry::*;
+pub use hosts_file::*;
+pub use hosts_resolver::*;
+
+mod hosts_entry;
+mod hosts_file;
+mod hosts_resolver;
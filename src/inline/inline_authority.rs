@@ -0,0 +1,106 @@
+#[cfg(feature = "alloc")]
+use crate::Authority;
+use crate::{AuthorityRef, InlineEndpoint, InlineHost};
+
+/// An [Authority] stored without a heap allocation: an [InlineHost] & a port.
+#[must_use]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct InlineAuthority {
+    host: InlineHost,
+    port: u16,
+}
+
+impl InlineAuthority {
+    //! Construction
+
+    /// Creates a new [InlineAuthority].
+    pub const fn new(host: InlineHost, port: u16) -> Self {
+        Self { host, port }
+    }
+}
+
+impl<H: Into<InlineHost>> From<(H, u16)> for InlineAuthority {
+    fn from(tuple: (H, u16)) -> Self {
+        Self::new(tuple.0.into(), tuple.1)
+    }
+}
+
+impl<'a> From<AuthorityRef<'a>> for InlineAuthority {
+    fn from(authority: AuthorityRef<'a>) -> Self {
+        Self::new(authority.host().into(), authority.port())
+    }
+}
+
+impl From<InlineEndpoint> for InlineAuthority {
+    fn from(endpoint: InlineEndpoint) -> Self {
+        Self::new(InlineHost::Name(*endpoint.domain()), endpoint.port())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&Authority> for InlineAuthority {
+    fn from(authority: &Authority) -> Self {
+        authority.to_ref().into()
+    }
+}
+
+impl<'a> PartialEq<AuthorityRef<'a>> for InlineAuthority {
+    fn eq(&self, other: &AuthorityRef<'a>) -> bool {
+        self.to_ref() == *other
+    }
+}
+
+impl<'a> PartialEq<InlineAuthority> for AuthorityRef<'a> {
+    fn eq(&self, other: &InlineAuthority) -> bool {
+        *self == other.to_ref()
+    }
+}
+
+impl InlineAuthority {
+    //! Properties
+
+    /// Gets the host.
+    pub const fn host(&self) -> &InlineHost {
+        &self.host
+    }
+
+    /// Gets the port.
+    #[must_use]
+    pub const fn port(&self) -> u16 {
+        self.port
+    }
+}
+
+impl InlineAuthority {
+    //! Conversions
+
+    /// Converts the authority to an authority reference.
+    pub const fn to_ref(&self) -> AuthorityRef<'_> {
+        AuthorityRef::new(self.host.to_ref(), self.port)
+    }
+
+    /// Converts the authority to a heap-allocated authority.
+    #[cfg(feature = "alloc")]
+    pub fn to_authority(&self) -> Authority {
+        self.to_ref().to_authority()
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::Authority;
+    use crate::{AuthorityRef, DomainRef, IPv4Address, InlineAuthority, InlineDomain, InlineEndpoint};
+
+    #[test]
+    fn conversions() {
+        let authority: InlineAuthority = InlineEndpoint::new(InlineDomain::LOCALHOST, 80).into();
+        assert_eq!(authority, AuthorityRef::from((DomainRef::LOCALHOST, 80)));
+        assert_eq!(authority.port(), 80);
+        assert!(authority.host().is_domain());
+
+        let authority: InlineAuthority = (IPv4Address::LOCALHOST, 443).into();
+        let owned: Authority = authority.to_authority();
+        assert_eq!(owned.to_ref(), authority.to_ref());
+        assert_eq!(InlineAuthority::from(&owned), authority);
+    }
+}
@@ -0,0 +1,180 @@
+#[cfg(feature = "alloc")]
+use crate::Domain;
+use crate::{DomainRef, NameClass};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// A [Domain] stored inline in a fixed-capacity buffer, owning its name without a heap allocation.
+///
+/// Every valid domain name fits the buffer, so the conversions from the other domain types cannot fail.
+#[must_use]
+#[derive(Copy, Clone)]
+pub struct InlineDomain {
+    bytes: [u8; Self::CAPACITY],
+    len: u8,
+}
+
+impl InlineDomain {
+    //! Special Domains
+
+    /// The `localhost` domain.
+    pub const LOCALHOST: Self = Self::new(DomainRef::LOCALHOST);
+
+    /// The `example.com` domain.
+    pub const EXAMPLE: Self = Self::new(DomainRef::EXAMPLE);
+}
+
+impl InlineDomain {
+    //! Construction
+
+    /// The capacity of the buffer in bytes, the maximum length of a domain name.
+    pub const CAPACITY: usize = NameClass::MAX_NAME_LEN;
+
+    /// Creates a new [InlineDomain] from a copy of the `domain` name.
+    pub const fn new(domain: DomainRef) -> Self {
+        let name: &[u8] = domain.name().as_bytes();
+        let mut bytes: [u8; Self::CAPACITY] = [0; Self::CAPACITY];
+        let mut i: usize = 0;
+        while i < name.len() {
+            bytes[i] = name[i];
+            i += 1;
+        }
+        Self {
+            bytes,
+            len: name.len() as u8,
+        }
+    }
+
+    /// Creates a new [InlineDomain] from the name in the first `len` of the `bytes`.
+    ///
+    /// # Safety
+    /// The name must be valid and lowercase, & the `bytes` after it must be zero.
+    pub(crate) const unsafe fn from_bytes_unchecked(bytes: [u8; Self::CAPACITY], len: usize) -> Self {
+        debug_assert!(NameClass::of_name(bytes.split_at(len).0).is_valid(false));
+
+        Self { bytes, len: len as u8 }
+    }
+}
+
+impl<'a> From<DomainRef<'a>> for InlineDomain {
+    fn from(domain: DomainRef<'a>) -> Self {
+        Self::new(domain)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&Domain> for InlineDomain {
+    fn from(domain: &Domain) -> Self {
+        Self::new(domain.to_ref())
+    }
+}
+
+impl InlineDomain {
+    //! Properties
+
+    /// Gets the name.
+    #[must_use]
+    pub const fn name(&self) -> &str {
+        let (name, _): (&[u8], &[u8]) = self.bytes.split_at(self.len as usize);
+        unsafe { core::str::from_utf8_unchecked(name) }
+    }
+}
+
+impl InlineDomain {
+    //! Conversions
+
+    /// Converts the domain to a domain reference.
+    pub const fn to_ref(&self) -> DomainRef<'_> {
+        unsafe { DomainRef::new_unchecked(self.name()) }
+    }
+
+    /// Converts the domain to a heap-allocated domain.
+    #[cfg(feature = "alloc")]
+    pub fn to_domain(&self) -> Domain {
+        self.to_ref().to_domain()
+    }
+}
+
+impl PartialEq for InlineDomain {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name()
+    }
+}
+
+impl Eq for InlineDomain {}
+
+impl PartialOrd for InlineDomain {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for InlineDomain {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.name().cmp(other.name())
+    }
+}
+
+impl Hash for InlineDomain {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name().hash(state);
+    }
+}
+
+impl<'a> PartialEq<DomainRef<'a>> for InlineDomain {
+    fn eq(&self, other: &DomainRef<'a>) -> bool {
+        self.to_ref() == *other
+    }
+}
+
+impl<'a> PartialEq<InlineDomain> for DomainRef<'a> {
+    fn eq(&self, other: &InlineDomain) -> bool {
+        *self == other.to_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::hash;
+    use crate::{DomainRef, InlineDomain};
+    use alloc::string::String;
+
+    #[cfg(feature = "alloc")]
+    use crate::Domain;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn construction() {
+        let domain: InlineDomain = InlineDomain::new(DomainRef::LOCALHOST);
+        assert_eq!(domain.name(), "localhost");
+        assert_eq!(domain, DomainRef::LOCALHOST);
+        assert_eq!(DomainRef::LOCALHOST, domain);
+        assert_eq!(domain, InlineDomain::LOCALHOST);
+
+        let domain: InlineDomain = (&Domain::example()).into();
+        assert_eq!(domain, InlineDomain::EXAMPLE);
+        assert_eq!(domain.to_domain(), Domain::example());
+    }
+
+    /// The longest valid name fills the buffer.
+    #[test]
+    fn capacity() {
+        let name: String = ["a".repeat(63), "b".repeat(63), "c".repeat(63), "d".repeat(61)].join(".");
+        assert_eq!(name.len(), InlineDomain::CAPACITY);
+
+        let domain: InlineDomain = DomainRef::try_from(name.as_str()).unwrap().into();
+        assert_eq!(domain.name(), name);
+    }
+
+    /// Equality, ordering, & hashing match the names, as for the other domain types.
+    #[test]
+    fn comparison() {
+        let a: InlineDomain = DomainRef::try_from("a").unwrap().into();
+        let ab: InlineDomain = DomainRef::try_from("a.b").unwrap().into();
+        let b: InlineDomain = DomainRef::try_from("b").unwrap().into();
+        assert!(a < ab && ab < b);
+        assert_eq!(a.cmp(&ab), DomainRef::try_from("a").unwrap().cmp(&ab.to_ref()));
+        assert_eq!(hash(a), hash(DomainRef::try_from("a").unwrap()));
+        assert_ne!(a, b);
+    }
+}
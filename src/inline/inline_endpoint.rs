@@ -0,0 +1,100 @@
+#[cfg(feature = "alloc")]
+use crate::Endpoint;
+use crate::{EndpointRef, InlineDomain};
+
+/// An [Endpoint] stored without a heap allocation: an [InlineDomain] & a port.
+#[must_use]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct InlineEndpoint {
+    domain: InlineDomain,
+    port: u16,
+}
+
+impl InlineEndpoint {
+    //! Construction
+
+    /// Creates a new [InlineEndpoint].
+    pub const fn new(domain: InlineDomain, port: u16) -> Self {
+        Self { domain, port }
+    }
+}
+
+impl<D: Into<InlineDomain>> From<(D, u16)> for InlineEndpoint {
+    fn from(tuple: (D, u16)) -> Self {
+        Self::new(tuple.0.into(), tuple.1)
+    }
+}
+
+impl<'a> From<EndpointRef<'a>> for InlineEndpoint {
+    fn from(endpoint: EndpointRef<'a>) -> Self {
+        Self::new(InlineDomain::new(endpoint.domain()), endpoint.port())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&Endpoint> for InlineEndpoint {
+    fn from(endpoint: &Endpoint) -> Self {
+        endpoint.to_ref().into()
+    }
+}
+
+impl<'a> PartialEq<EndpointRef<'a>> for InlineEndpoint {
+    fn eq(&self, other: &EndpointRef<'a>) -> bool {
+        self.to_ref() == *other
+    }
+}
+
+impl<'a> PartialEq<InlineEndpoint> for EndpointRef<'a> {
+    fn eq(&self, other: &InlineEndpoint) -> bool {
+        *self == other.to_ref()
+    }
+}
+
+impl InlineEndpoint {
+    //! Properties
+
+    /// Gets the domain.
+    pub const fn domain(&self) -> &InlineDomain {
+        &self.domain
+    }
+
+    /// Gets the port.
+    #[must_use]
+    pub const fn port(&self) -> u16 {
+        self.port
+    }
+}
+
+impl InlineEndpoint {
+    //! Conversions
+
+    /// Converts the endpoint to an endpoint reference.
+    pub const fn to_ref(&self) -> EndpointRef<'_> {
+        EndpointRef::new(self.domain.to_ref(), self.port)
+    }
+
+    /// Converts the endpoint to a heap-allocated endpoint.
+    #[cfg(feature = "alloc")]
+    pub fn to_endpoint(&self) -> Endpoint {
+        self.to_ref().to_endpoint()
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::Endpoint;
+    use crate::{DomainRef, EndpointRef, InlineDomain, InlineEndpoint};
+
+    #[test]
+    fn conversions() {
+        let endpoint: InlineEndpoint = InlineEndpoint::new(InlineDomain::LOCALHOST, 80);
+        assert_eq!(endpoint.domain(), &InlineDomain::LOCALHOST);
+        assert_eq!(endpoint.port(), 80);
+        assert_eq!(endpoint, EndpointRef::new(DomainRef::LOCALHOST, 80));
+        assert_eq!(endpoint, InlineEndpoint::from((DomainRef::LOCALHOST, 80)));
+
+        let owned: Endpoint = endpoint.to_endpoint();
+        assert_eq!(owned.to_ref(), endpoint.to_ref());
+        assert_eq!(InlineEndpoint::from(&owned), endpoint);
+    }
+}
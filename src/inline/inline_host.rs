@@ -0,0 +1,117 @@
+#[cfg(feature = "alloc")]
+use crate::Host;
+use crate::{DomainRef, HostRef, IPAddress, InlineDomain};
+
+/// A [Host] stored without a heap allocation: either an [InlineDomain] or an [IPAddress].
+///
+/// Every host takes the space of the domain buffer, since boxing the domain would need the heap this type avoids.
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum InlineHost {
+    /// An [InlineDomain].
+    Name(InlineDomain),
+
+    /// An [IPAddress].
+    Address(IPAddress),
+}
+
+impl<'a> From<HostRef<'a>> for InlineHost {
+    fn from(host: HostRef<'a>) -> Self {
+        match host {
+            HostRef::Name(domain) => Self::Name(InlineDomain::new(domain)),
+            HostRef::Address(ip) => Self::Address(ip),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&Host> for InlineHost {
+    fn from(host: &Host) -> Self {
+        host.to_ref().into()
+    }
+}
+
+impl From<InlineDomain> for InlineHost {
+    fn from(domain: InlineDomain) -> Self {
+        Self::Name(domain)
+    }
+}
+
+impl<'a> From<DomainRef<'a>> for InlineHost {
+    fn from(domain: DomainRef<'a>) -> Self {
+        Self::Name(InlineDomain::new(domain))
+    }
+}
+
+impl<A: Into<IPAddress>> From<A> for InlineHost {
+    fn from(ip: A) -> Self {
+        Self::Address(ip.into())
+    }
+}
+
+impl<'a> PartialEq<HostRef<'a>> for InlineHost {
+    fn eq(&self, other: &HostRef<'a>) -> bool {
+        self.to_ref() == *other
+    }
+}
+
+impl<'a> PartialEq<InlineHost> for HostRef<'a> {
+    fn eq(&self, other: &InlineHost) -> bool {
+        *self == other.to_ref()
+    }
+}
+
+impl InlineHost {
+    //! Matching
+
+    /// Checks if the host is a domain.
+    #[must_use]
+    pub const fn is_domain(&self) -> bool {
+        matches!(self, Self::Name(_))
+    }
+
+    /// Checks if the host is an IP address.
+    #[must_use]
+    pub const fn is_ip(&self) -> bool {
+        matches!(self, Self::Address(_))
+    }
+}
+
+impl InlineHost {
+    //! Conversions
+
+    /// Converts the host to a host reference.
+    pub const fn to_ref(&self) -> HostRef<'_> {
+        match self {
+            Self::Name(domain) => HostRef::Name(domain.to_ref()),
+            Self::Address(ip) => HostRef::Address(*ip),
+        }
+    }
+
+    /// Converts the host to a heap-allocated host.
+    #[cfg(feature = "alloc")]
+    pub fn to_host(&self) -> Host {
+        self.to_ref().to_host()
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::{Domain, Host};
+    use crate::{DomainRef, HostRef, IPv4Address, InlineDomain, InlineHost};
+
+    #[test]
+    fn conversions() {
+        let host: InlineHost = HostRef::Name(DomainRef::LOCALHOST).into();
+        assert_eq!(host, InlineHost::Name(InlineDomain::LOCALHOST));
+        assert_eq!(host, HostRef::Name(DomainRef::LOCALHOST));
+        assert_eq!(host.to_host(), Host::from(Domain::localhost()));
+        assert!(host.is_domain());
+
+        let host: InlineHost = IPv4Address::LOCALHOST.into();
+        assert_eq!(host.to_ref(), IPv4Address::LOCALHOST.to_host_ref());
+        assert_eq!(InlineHost::from(&host.to_host()), host);
+        assert!(host.is_ip());
+    }
+}
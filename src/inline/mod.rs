@@ -0,0 +1,9 @@
+pub use inline_authority::*;
+pub use inline_domain::*;
+pub use inline_endpoint::*;
+pub use inline_host::*;
+
+mod inline_authority;
+mod inline_domain;
+mod inline_endpoint;
+mod inline_host;
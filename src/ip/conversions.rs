@@ -1,4 +1,6 @@
-use crate::{Host, HostRef, IPAddress, IPv4Address, IPv6Address, SocketAddress};
+#[cfg(feature = "alloc")]
+use crate::Host;
+use crate::{HostRef, IPAddress, IPv4Address, IPv6Address, PortRange, SocketAddress, SocketAddresses};
 
 impl IPAddress {
     //! Conversions
@@ -20,7 +22,13 @@ impl IPAddress {
         SocketAddress::new(self, port)
     }
 
+    /// Converts the address to an iterator over the socket addresses with each of the `ports`.
+    pub const fn to_sockets(self, ports: PortRange) -> SocketAddresses {
+        SocketAddresses::new(self, ports)
+    }
+
     /// Converts the address to a host.
+    #[cfg(feature = "alloc")]
     pub const fn to_host(self) -> Host {
         Host::Address(self)
     }
@@ -45,7 +53,10 @@ impl From<IPv6Address> for IPAddress {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Host, HostRef, IPAddress, IPv4Address, IPv6Address, SocketAddress};
+    use crate::{IPAddress, IPv4Address, IPv6Address, SocketAddress};
+
+    #[cfg(feature = "alloc")]
+    use crate::{Host, HostRef};
 
     #[test]
     fn ip_to_v4() {
@@ -81,6 +92,7 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn ip_to_host() {
         let ip: IPAddress = IPAddress::V4(IPv4Address::LOCALHOST);
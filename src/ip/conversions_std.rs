@@ -1,5 +1,5 @@
 use crate::{IPAddress, IPv4Address, IPv6Address};
-use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 impl IPAddress {
     //! Standard Library Conversions
@@ -44,7 +44,7 @@ impl From<IPAddress> for IpAddr {
 #[cfg(test)]
 mod tests {
     use crate::{IPAddress, IPv4Address, IPv6Address};
-    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+    use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
     #[test]
     fn ip_to_std() {
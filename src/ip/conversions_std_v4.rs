@@ -1,5 +1,5 @@
 use crate::IPv4Address;
-use std::net::Ipv4Addr;
+use core::net::Ipv4Addr;
 
 impl IPv4Address {
     //! Standard Library Conversions
@@ -27,7 +27,7 @@ impl From<IPv4Address> for Ipv4Addr {
 #[cfg(test)]
 mod tests {
     use crate::IPv4Address;
-    use std::net::Ipv4Addr;
+    use core::net::Ipv4Addr;
 
     #[test]
     fn v4_to_std() {
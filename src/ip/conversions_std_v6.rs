@@ -1,5 +1,5 @@
 use crate::IPv6Address;
-use std::net::Ipv6Addr;
+use core::net::Ipv6Addr;
 
 impl IPv6Address {
     //! Standard Library Conversions
@@ -36,7 +36,7 @@ impl From<IPv6Address> for Ipv6Addr {
 #[cfg(test)]
 mod tests {
     use crate::IPv6Address;
-    use std::net::Ipv6Addr;
+    use core::net::Ipv6Addr;
 
     #[test]
     fn v6_to_std() {
@@ -1,4 +1,6 @@
-use crate::{Host, HostRef, IPAddress, IPv4Address, IPv6Address, SocketAddressV4};
+#[cfg(feature = "alloc")]
+use crate::Host;
+use crate::{HostRef, IPAddress, IPv4Address, IPv6Address, SocketAddressV4};
 
 impl IPv4Address {
     //! Conversions
@@ -28,6 +30,7 @@ impl IPv4Address {
     }
 
     /// Converts the address to a host.
+    #[cfg(feature = "alloc")]
     pub const fn to_host(self) -> Host {
         Host::Address(self.to_ip())
     }
@@ -40,7 +43,10 @@ impl IPv4Address {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Host, HostRef, IPAddress, IPv4Address, IPv6Address, SocketAddressV4};
+    use crate::{IPAddress, IPv4Address, IPv6Address, SocketAddressV4};
+
+    #[cfg(feature = "alloc")]
+    use crate::{Host, HostRef};
 
     #[test]
     fn v4_to_v6() {
@@ -71,6 +77,7 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn v4_to_host() {
         let ip: IPv4Address = IPv4Address::LOCALHOST;
@@ -1,4 +1,6 @@
-use crate::{Host, HostRef, IPAddress, IPv4Address, IPv6Address, SocketAddressV6};
+#[cfg(feature = "alloc")]
+use crate::Host;
+use crate::{HostRef, IPAddress, IPv4Address, IPv6Address, SocketAddressV6};
 
 impl IPv6Address {
     //! Conversions
@@ -38,6 +40,7 @@ impl IPv6Address {
     }
 
     /// Converts the address to a host.
+    #[cfg(feature = "alloc")]
     pub const fn to_host(self) -> Host {
         Host::Address(self.to_ip())
     }
@@ -50,7 +53,10 @@ impl IPv6Address {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Host, HostRef, IPAddress, IPv4Address, IPv6Address, SocketAddressV6};
+    use crate::{IPAddress, IPv4Address, IPv6Address, SocketAddressV6};
+
+    #[cfg(feature = "alloc")]
+    use crate::{Host, HostRef};
 
     #[test]
     fn v6_to_v4() {
@@ -99,6 +105,7 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn v6_to_host() {
         let ip: IPv6Address = IPv6Address::LOCALHOST;
@@ -1,23 +1,99 @@
-#![doc = include_str!("../README.md")]
+// The README examples use the owned types, so its doctests run with `alloc`.
+#![cfg_attr(any(feature = "alloc", not(doctest)), doc = include_str!("../README.md"))]
+#![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![cfg_attr(docsrs, doc(auto_cfg))]
+// The core types link to their owned counterparts, which need `alloc`; the links are checked in the `alloc` builds.
+#![cfg_attr(not(feature = "alloc"), allow(rustdoc::broken_intra_doc_links))]
 
+// The tests use `alloc` in every build; the test harness links `std` regardless.
+#[cfg(any(feature = "alloc", test))]
+extern crate alloc;
+
+pub use address::*;
 pub use authority::*;
+#[cfg(feature = "happy-eyeballs")]
+pub use connect::*;
+#[cfg(feature = "alloc")]
+pub use cow::*;
+pub use display::*;
 pub use domain::*;
 pub use endpoint::*;
+#[cfg(feature = "idna")]
+pub use homograph::*;
 pub use host::*;
+pub use host_port::*;
+#[cfg(feature = "alloc")]
+pub use hosts::*;
+pub use inline::*;
 pub use ip::*;
+#[cfg(feature = "alloc")]
+pub use origin::*;
 pub use parse::*;
+pub use port::*;
+#[cfg(feature = "alloc")]
+pub use record::*;
+#[cfg(feature = "alloc")]
+pub use resolv_conf::*;
+#[cfg(feature = "std")]
+pub use resolve::*;
+#[cfg(feature = "alloc")]
+pub use scan::*;
+#[cfg(feature = "alloc")]
+pub use selection::*;
+pub use service::*;
+#[cfg(feature = "alloc")]
+pub use shared::*;
 pub use socket::*;
+#[cfg(feature = "alloc")]
+pub use uri::*;
+#[cfg(feature = "idna")]
+pub use whatwg::*;
 
+mod address;
 mod authority;
+#[cfg(feature = "happy-eyeballs")]
+mod connect;
+#[cfg(feature = "alloc")]
+mod cow;
 mod display;
 mod domain;
 mod endpoint;
+#[cfg(feature = "idna")]
+mod homograph;
 mod host;
+mod host_port;
+#[cfg(feature = "alloc")]
+mod hosts;
+mod inline;
 mod ip;
+#[cfg(feature = "alloc")]
+mod origin;
 mod parse;
+mod port;
+#[cfg(feature = "alloc")]
+mod record;
+#[cfg(feature = "alloc")]
+mod resolv_conf;
+#[cfg(feature = "std")]
+mod resolve;
+#[cfg(feature = "alloc")]
+mod scan;
+#[cfg(feature = "alloc")]
+mod selection;
+mod service;
+#[cfg(feature = "alloc")]
+mod shared;
 mod socket;
+#[cfg(feature = "alloc")]
+mod uri;
+#[cfg(feature = "idna")]
+mod whatwg;
 
 #[cfg(feature = "serde")]
 mod serde;
+#[cfg(feature = "tokio")]
+mod tokio;
+
+#[cfg(test)]
+mod test_util;
@@ -0,0 +1,86 @@
+/// The default ports of common URI schemes, sorted by scheme for binary search.
+///
+/// Each port is the one a URI of the scheme implies when it omits the port.
+pub(crate) const DEFAULT_PORTS: &[(&str, u16)] = &[
+    ("amqp", 5672),
+    ("amqps", 5671),
+    ("coap", 5683),
+    ("coaps", 5684),
+    ("ftp", 21),
+    ("ftps", 990),
+    ("git", 9418),
+    ("gopher", 70),
+    ("http", 80),
+    ("https", 443),
+    ("imap", 143),
+    ("imaps", 993),
+    ("irc", 6667),
+    ("ircs", 6697),
+    ("kafka", 9092),
+    ("ldap", 389),
+    ("ldaps", 636),
+    ("memcached", 11211),
+    ("mongodb", 27017),
+    ("mqtt", 1883),
+    ("mqtts", 8883),
+    ("mysql", 3306),
+    ("nats", 4222),
+    ("nntp", 119),
+    ("pop3", 110),
+    ("pop3s", 995),
+    ("postgres", 5432),
+    ("postgresql", 5432),
+    ("redis", 6379),
+    ("rediss", 6379),
+    ("rtsp", 554),
+    ("sftp", 22),
+    ("sip", 5060),
+    ("sips", 5061),
+    ("smtp", 25),
+    ("ssh", 22),
+    ("telnet", 23),
+    ("ws", 80),
+    ("wss", 443),
+];
+
+/// Gets the default port of the lowercase `scheme`.
+pub(crate) fn default_port(scheme: &str) -> Option<u16> {
+    DEFAULT_PORTS
+        .binary_search_by(|(name, _)| (*name).cmp(scheme))
+        .ok()
+        .map(|index| DEFAULT_PORTS[index].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::origin::default_ports::{DEFAULT_PORTS, default_port};
+
+    /// The table must stay sorted & lowercase, or the binary search misses entries.
+    #[test]
+    fn well_formed() {
+        for window in DEFAULT_PORTS.windows(2) {
+            assert!(window[0].0 < window[1].0, "scheme={}", window[1].0);
+        }
+        for (scheme, _) in DEFAULT_PORTS {
+            assert_eq!(scheme.to_ascii_lowercase(), *scheme, "scheme={}", scheme);
+        }
+    }
+
+    #[test]
+    fn lookup() {
+        let test_cases: &[(&str, Option<u16>)] = &[
+            ("http", Some(80)),
+            ("https", Some(443)),
+            ("wss", Some(443)),
+            ("postgres", Some(5432)),
+            ("amqp", Some(5672)),
+            ("HTTP", None),
+            ("unknown", None),
+            ("", None),
+        ];
+
+        for (scheme, expected) in test_cases {
+            assert_eq!(default_port(scheme), *expected, "scheme={}", scheme);
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub use origin::*;
+pub use scheme::*;
+
+mod default_ports;
+mod origin;
+mod scheme;
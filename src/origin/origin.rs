@@ -0,0 +1,149 @@
+use crate::{Authority, AuthorityRef, Host, HostRef, Scheme};
+
+/// A web origin: a scheme & an authority. (`https://example.com`)
+///
+/// This is the tuple origin of [RFC 6454](https://www.rfc-editor.org/rfc/rfc6454). The port is always known: a port
+/// omitted from the text is filled in from the scheme's default port, so `https://example.com` &
+/// `https://example.com:443` are the same origin. Opaque origins (`data:` URIs, sandboxed documents) are not
+/// represented.
+#[must_use]
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Origin {
+    scheme: Scheme,
+    authority: Authority,
+}
+
+impl Origin {
+    //! Construction
+
+    /// Creates a new [Origin].
+    pub const fn new(scheme: Scheme, authority: Authority) -> Self {
+        Self { scheme, authority }
+    }
+
+    /// Creates a new [Origin] with the `host` & the default port of the `scheme`.
+    ///
+    /// Returns `None` if the scheme has no known default port.
+    #[must_use]
+    pub fn with_default_port(scheme: Scheme, host: Host) -> Option<Self> {
+        let port: u16 = scheme.default_port()?;
+        Some(Self::new(scheme, host.to_authority(port)))
+    }
+}
+
+impl From<Origin> for (Scheme, Authority) {
+    fn from(origin: Origin) -> Self {
+        (origin.scheme, origin.authority)
+    }
+}
+
+impl Origin {
+    //! Properties
+
+    /// Gets the scheme.
+    pub const fn scheme(&self) -> &Scheme {
+        &self.scheme
+    }
+
+    /// Gets the authority reference.
+    pub fn authority(&self) -> AuthorityRef<'_> {
+        self.authority.to_ref()
+    }
+
+    /// Gets the host reference.
+    pub fn host(&self) -> HostRef<'_> {
+        self.authority.host()
+    }
+
+    /// Gets the port.
+    #[must_use]
+    pub const fn port(&self) -> u16 {
+        self.authority.port()
+    }
+}
+
+impl Origin {
+    //! Matching
+
+    /// Checks if the port is the default port of the scheme, so the serialization omits it.
+    #[must_use]
+    pub fn is_default_port(&self) -> bool {
+        self.scheme.default_port() == Some(self.port())
+    }
+
+    /// Checks if the origins are the same origin: the same scheme, host, & port.
+    ///
+    /// Hosts are compared exactly, so `localhost` & `127.0.0.1` are different origins.
+    #[must_use]
+    pub fn is_same_origin(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl Origin {
+    //! Conversions
+
+    /// Converts the origin to its authority.
+    pub fn to_authority(self) -> Authority {
+        self.authority
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Authority, Domain, IPv4Address, Origin, Scheme};
+
+    #[test]
+    fn construction() {
+        let origin: Origin = Origin::new(Scheme::https(), Domain::example().to_host().to_authority(443));
+        assert_eq!(origin.scheme, Scheme::https());
+        assert_eq!(origin.authority, Domain::example().to_host().to_authority(443));
+
+        let result: Option<Origin> = Origin::with_default_port(Scheme::https(), Domain::example().to_host());
+        assert_eq!(result, Some(origin));
+
+        let scheme: Scheme = unsafe { Scheme::new_unchecked("x-custom") };
+        assert_eq!(Origin::with_default_port(scheme, Domain::example().to_host()), None);
+    }
+
+    #[test]
+    fn deconstruction() {
+        let origin: Origin = Origin::new(Scheme::http(), Domain::localhost().to_host().to_authority(8080));
+        let (scheme, authority): (Scheme, Authority) = origin.into();
+        assert_eq!(scheme, Scheme::http());
+        assert_eq!(authority, Domain::localhost().to_host().to_authority(8080));
+    }
+
+    #[test]
+    fn properties() {
+        let origin: Origin = Origin::new(Scheme::http(), Domain::localhost().to_host().to_authority(8080));
+        assert_eq!(origin.scheme(), &Scheme::http());
+        assert_eq!(origin.authority(), Domain::localhost().to_host().to_authority(8080));
+        assert_eq!(origin.host(), Domain::localhost().to_host().to_ref());
+        assert_eq!(origin.port(), 8080);
+    }
+
+    #[test]
+    fn matching() {
+        let origin: Origin = Origin::new(Scheme::https(), Domain::example().to_host().to_authority(443));
+        assert!(origin.is_default_port());
+
+        let other_port: Origin = Origin::new(Scheme::https(), Domain::example().to_host().to_authority(8443));
+        assert!(!other_port.is_default_port());
+        assert!(!origin.is_same_origin(&other_port));
+
+        let other_scheme: Origin = Origin::new(Scheme::http(), Domain::example().to_host().to_authority(443));
+        assert!(!origin.is_same_origin(&other_scheme));
+
+        let other_host: Origin = Origin::new(Scheme::https(), IPv4Address::LOCALHOST.to_host().to_authority(443));
+        assert!(!origin.is_same_origin(&other_host));
+
+        assert!(origin.is_same_origin(&origin.clone()));
+    }
+
+    #[test]
+    fn to_authority() {
+        let origin: Origin = Origin::new(Scheme::https(), Domain::example().to_host().to_authority(443));
+        assert_eq!(origin.to_authority(), Domain::example().to_host().to_authority(443));
+    }
+}
@@ -0,0 +1,104 @@
+use crate::origin::default_ports::default_port;
+use alloc::string::String;
+
+/// A URI scheme. (`https`)
+///
+/// Schemes are normalized to lowercase, per [RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-3.1).
+#[must_use]
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Scheme {
+    name: String,
+}
+
+impl Scheme {
+    //! Special Schemes
+
+    /// Creates the `http` scheme.
+    pub fn http() -> Self {
+        unsafe { Self::new_unchecked("http") }
+    }
+
+    /// Creates the `https` scheme.
+    pub fn https() -> Self {
+        unsafe { Self::new_unchecked("https") }
+    }
+}
+
+impl Scheme {
+    //! Construction
+
+    /// Creates a new [Scheme].
+    ///
+    /// # Safety
+    /// The `name` must be valid and lowercase.
+    pub unsafe fn new_unchecked<S>(name: S) -> Self
+    where
+        S: Into<String>,
+    {
+        let name: String = name.into();
+
+        debug_assert!(Self::is_valid_name(name.as_bytes()));
+        debug_assert!(!name.bytes().any(|c| c.is_ascii_uppercase()));
+
+        Self { name }
+    }
+}
+
+impl From<Scheme> for String {
+    fn from(scheme: Scheme) -> Self {
+        scheme.name
+    }
+}
+
+impl PartialEq<&str> for Scheme {
+    /// Compares the name exactly; schemes are lowercase, so mixed-case strings are never equal.
+    fn eq(&self, other: &&str) -> bool {
+        self.name == *other
+    }
+}
+
+impl Scheme {
+    //! Properties
+
+    /// Gets the lowercase name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Gets the default port from the built-in table of common schemes.
+    ///
+    /// Returns `None` for schemes without a known default port.
+    #[must_use]
+    pub fn default_port(&self) -> Option<u16> {
+        default_port(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Scheme;
+    use alloc::string::String;
+
+    #[test]
+    fn specials() {
+        assert_eq!(Scheme::http(), "http");
+        assert_eq!(Scheme::https(), "https");
+    }
+
+    #[test]
+    fn properties() {
+        assert_eq!(Scheme::https().name(), "https");
+        assert_eq!(Scheme::https().default_port(), Some(443));
+        assert_eq!(Scheme::http().default_port(), Some(80));
+
+        let scheme: Scheme = unsafe { Scheme::new_unchecked("x-custom") };
+        assert_eq!(scheme.default_port(), None);
+    }
+
+    #[test]
+    fn into_string() {
+        let result: String = Scheme::https().into();
+        assert_eq!(result, "https");
+    }
+}
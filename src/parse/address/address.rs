@@ -0,0 +1,105 @@
+use crate::ParseError::InvalidAddress;
+use crate::{Address, AddressRef, Domain, Endpoint, InvalidAddressError, ParseError, impl_parse, impl_parse_string};
+use alloc::vec::Vec;
+
+impl Address {
+    //! Parse
+
+    /// An IP address, a socket address, a domain, or an endpoint, classified in order of precedence:
+    ///
+    /// 1. Bracketed text is an IPv6 socket address: `[::1]:80`.
+    /// 2. An IPv4 or unbracketed IPv6 address: `127.0.0.1` or `::1`.
+    /// 3. An IPv4 socket address: `127.0.0.1:80`.
+    /// 4. A domain: `localhost` or `a.b`.
+    /// 5. An endpoint: `localhost:80` or `a.b:80`.
+    ///
+    /// Domain labels may be entirely numeric, so malformed IPv4 text such as `999.1.1.1` is a domain. Domain names
+    /// are normalized to lowercase.
+    ///
+    /// Bracketed text reports the socket address error; other text that is no kind of address is an
+    /// [InvalidAddress](ParseError::InvalidAddress).
+    pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+        if let Some(address) = AddressRef::parse_ip_or_socket(text) {
+            return address.map(AddressRef::to_address);
+        }
+        if let Ok(domain) = Domain::parse_text(text) {
+            return Ok(Self::Domain(domain));
+        }
+        Endpoint::parse_text(text)
+            .map(Self::Endpoint)
+            .map_err(|_| InvalidAddress)
+    }
+}
+
+impl_parse!(
+    Address,
+    "An IP address, a socket address, a domain, or an endpoint. (see [`Address::parse_text`])",
+    "Domain names are normalized to lowercase."
+);
+
+impl_parse_string!(
+    Address,
+    "An IP address, a socket address, a domain, or an endpoint. (see [`Address::parse_text`])",
+    "Domain names are normalized to lowercase."
+);
+
+impl TryFrom<Vec<u8>> for Address {
+    type Error = InvalidAddressError<Vec<u8>>;
+
+    /// An IP address, a socket address, a domain, or an endpoint. (see [`Address::parse_text`])
+    /// Domain names are normalized to lowercase.
+    /// The error contains the unmodified `text`, which `TryFrom<String>` soundly recovers as a string.
+    fn try_from(text: Vec<u8>) -> Result<Self, Self::Error> {
+        if let Some(address) = AddressRef::parse_ip_or_socket(text.as_slice()) {
+            return address
+                .map(AddressRef::to_address)
+                .map_err(|error| InvalidAddressError::new(text, error));
+        }
+        match Domain::try_from(text) {
+            Ok(domain) => Ok(Self::Domain(domain)),
+            Err(error) => Endpoint::try_from(error.into_value())
+                .map(Self::Endpoint)
+                .map_err(|error| InvalidAddressError::new(error.into_value(), InvalidAddress)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::{InvalidAddress, InvalidPort};
+    use crate::{Address, Domain, IPv4Address, IPv6Address, InvalidAddressError, ParseError};
+    use alloc::string::String;
+
+    #[test]
+    fn parse_text() {
+        let test_cases: &[(&str, Result<Address, ParseError>)] = &[
+            ("127.0.0.1", Ok(IPv4Address::LOCALHOST.into())),
+            ("::1", Ok(IPv6Address::LOCALHOST.into())),
+            (
+                "127.0.0.1:80",
+                Ok(IPv4Address::LOCALHOST.to_socket(80).to_socket().into()),
+            ),
+            ("[::1]:80", Ok(IPv6Address::LOCALHOST.to_socket(80).to_socket().into())),
+            ("LocalHost", Ok(Domain::localhost().into())),
+            ("LocalHost:80", Ok(Domain::localhost().to_endpoint(80).into())),
+            ("[::1]", Err(InvalidPort)),
+            ("local host", Err(InvalidAddress)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<Address, ParseError> = input.parse();
+            assert_eq!(result, *expected, "input={}", input);
+
+            let result: Result<Address, ParseError> =
+                Address::try_from(String::from(*input)).map_err(|error| error.error());
+            assert_eq!(result, *expected, "input={}", input);
+        }
+    }
+
+    #[test]
+    fn try_from_string_error() {
+        let error: InvalidAddressError<String> = Address::try_from(String::from("Local Host:80")).unwrap_err();
+        assert_eq!(error.error(), InvalidAddress);
+        assert_eq!(error.into_value(), "Local Host:80");
+    }
+}
@@ -0,0 +1,104 @@
+use crate::ParseError::InvalidAddress;
+use crate::{AddressRef, DomainRef, EndpointRef, IPAddress, ParseError, SocketAddress, impl_parse_ref};
+
+impl<'a> AddressRef<'a> {
+    //! Parse
+
+    /// An IP address, a socket address, a domain, or an endpoint, classified in order of precedence:
+    ///
+    /// 1. Bracketed text is an IPv6 socket address: `[::1]:80`.
+    /// 2. An IPv4 or unbracketed IPv6 address: `127.0.0.1` or `::1`.
+    /// 3. An IPv4 socket address: `127.0.0.1:80`.
+    /// 4. A domain: `localhost` or `a.b`.
+    /// 5. An endpoint: `localhost:80` or `a.b:80`.
+    ///
+    /// Domain labels may be entirely numeric, so malformed IPv4 text such as `999.1.1.1` is a domain. Domain names
+    /// must already be in lowercase. Use [`Address`](crate::Address) to parse mixed-case input.
+    ///
+    /// Bracketed text reports the socket address error; other text that is no kind of address is an
+    /// [InvalidAddress](ParseError::InvalidAddress).
+    pub const fn parse_text(text: &'a [u8]) -> Result<Self, ParseError> {
+        if let Some(address) = Self::parse_ip_or_socket(text) {
+            return address;
+        }
+        if let Ok(domain) = DomainRef::parse_text(text) {
+            return Ok(Self::Domain(domain));
+        }
+        match EndpointRef::parse_text(text) {
+            Ok(endpoint) => Ok(Self::Endpoint(endpoint)),
+            Err(_) => Err(InvalidAddress),
+        }
+    }
+
+    /// Parses the IP & socket addresses, which take precedence over names, or returns `None` if the `text` is
+    /// neither.
+    pub(crate) const fn parse_ip_or_socket(text: &[u8]) -> Option<Result<Self, ParseError>> {
+        if let [b'[', ..] = text {
+            return Some(match SocketAddress::parse_text(text) {
+                Ok(socket) => Ok(Self::SocketAddress(socket)),
+                Err(error) => Err(error),
+            });
+        }
+        if let Ok(ip) = IPAddress::parse_text(text) {
+            return Some(Ok(Self::IPAddress(ip)));
+        }
+        if let Ok(socket) = SocketAddress::parse_text(text) {
+            return Some(Ok(Self::SocketAddress(socket)));
+        }
+        None
+    }
+}
+
+impl_parse_ref!(
+    AddressRef,
+    "An IP address, a socket address, a domain, or an endpoint. (see [`AddressRef::parse_text`])",
+    "Domain names must already be in lowercase. Use [`Address`](crate::Address) to parse mixed-case input."
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::{InvalidAddress, InvalidIPv6Address, InvalidPort};
+    use crate::{AddressKind, AddressRef, DomainRef, ParseError};
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse_text() {
+        let test_cases: &[(&str, Result<AddressKind, ParseError>)] = &[
+            ("127.0.0.1", Ok(AddressKind::IPAddress)),
+            ("::1", Ok(AddressKind::IPAddress)),
+            ("::ffff:127.0.0.1", Ok(AddressKind::IPAddress)),
+            ("127.0.0.1:80", Ok(AddressKind::SocketAddress)),
+            ("[::1]:80", Ok(AddressKind::SocketAddress)),
+            ("localhost", Ok(AddressKind::Domain)),
+            ("a.b", Ok(AddressKind::Domain)),
+            ("999.1.1.1", Ok(AddressKind::Domain)),
+            ("localhost:80", Ok(AddressKind::Endpoint)),
+            ("a.b:80", Ok(AddressKind::Endpoint)),
+            ("1:80", Ok(AddressKind::Endpoint)),
+            ("[::1]", Err(InvalidPort)),
+            ("[localhost]:80", Err(InvalidIPv6Address)),
+            ("::1:80:x", Err(InvalidAddress)),
+            ("LocalHost", Err(InvalidAddress)),
+            ("localhost:x", Err(InvalidAddress)),
+            ("", Err(InvalidAddress)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<AddressKind, ParseError> = AddressRef::try_from(*input).map(AddressRef::kind);
+            assert_eq!(result, *expected, "input={}", input);
+            if let Ok(address) = AddressRef::try_from(*input) {
+                assert_eq!(address.to_string(), *input, "input={}", input);
+            }
+        }
+    }
+
+    /// The parser runs in `const` contexts, so literals are validated at compile time.
+    #[test]
+    fn const_parse() {
+        const ADDRESS: AddressRef = match AddressRef::parse_text(b"localhost") {
+            Ok(address) => address,
+            Err(_) => panic!("invalid address"),
+        };
+        assert_eq!(ADDRESS, AddressRef::Domain(DomainRef::LOCALHOST));
+    }
+}
@@ -0,0 +1,3 @@
+#[cfg(feature = "alloc")]
+mod address;
+mod address_ref;
@@ -1,8 +1,9 @@
 use crate::ParseError::{InvalidAuthority, InvalidHost};
-use crate::parse_port;
 use crate::{
-    Authority, Domain, Host, IPAddress, IPv6Address, InvalidAddressError, ParseError, impl_parse, impl_parse_string,
+    Authority, Domain, Host, IPAddress, IPv6Address, InvalidAddressError, ParseError, ServiceRegistry,
+    TransportProtocol, impl_parse, impl_parse_string, parse_port, parse_service_port,
 };
+use alloc::vec::Vec;
 
 impl Authority {
     //! Parse
@@ -12,8 +13,25 @@ impl Authority {
     /// A numeric IPv6 zone is accepted & ignored: `[fe80::1%1]:80` parses as `[fe80::1]:80`.
     pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
         let (host, port): (&[u8], u16) = parse_port(text)?;
+        Self::parse_host(host).map(|host| host.to_authority(port))
+    }
+
+    /// A host & a decimal port or a service name from the `services`: `localhost:80` or `localhost:http`.
+    /// Service names are looked up case-insensitively for the `protocol`; unknown names are an invalid port.
+    /// Otherwise the syntax matches [`Self::parse_text`].
+    pub fn parse_text_with_services(
+        text: &[u8],
+        services: &ServiceRegistry,
+        protocol: TransportProtocol,
+    ) -> Result<Self, ParseError> {
+        let (host, port): (&[u8], u16) = parse_service_port(text, services, protocol)?;
+        Self::parse_host(host).map(|host| host.to_authority(port))
+    }
+
+    /// Parses the `host` text, the authority text before the port.
+    pub(crate) fn parse_host(host: &[u8]) -> Result<Host, ParseError> {
         if let Some(ip) = IPv6Address::parse_bracketed(host) {
-            Ok(ip?.to_host().to_authority(port))
+            Ok(ip?.to_host())
         } else {
             let host: Host = Host::parse_text(host)?;
             if let Host::Address(ip) = &host
@@ -21,7 +39,7 @@ impl Authority {
             {
                 return Err(InvalidAuthority);
             }
-            Ok(host.to_authority(port))
+            Ok(host)
         }
     }
 }
@@ -74,8 +92,14 @@ impl TryFrom<Vec<u8>> for Authority {
 #[cfg(test)]
 mod tests {
     use crate::ParseError::{InvalidAuthority, InvalidHost, InvalidIPv6Address, InvalidPort};
-    use crate::{Authority, Domain, IPv4Address, IPv6Address, InvalidAddressError, ParseError};
-    use std::str::FromStr;
+    use crate::{
+        Authority, Domain, IPv4Address, IPv6Address, InvalidAddressError, ParseError, ServiceRegistry,
+        TransportProtocol,
+    };
+    use alloc::string::String;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+    use core::str::FromStr;
 
     #[test]
     fn from_str() {
@@ -209,6 +233,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_text_with_services() {
+        let services: ServiceRegistry = ServiceRegistry::iana();
+        let test_cases: &[(&str, Result<Authority, ParseError>)] = &[
+            ("example.com:https", Ok(Domain::example().to_host().to_authority(443))),
+            ("Example.com:HTTP", Ok(Domain::example().to_host().to_authority(80))),
+            ("example.com:8443", Ok(Domain::example().to_host().to_authority(8443))),
+            ("127.0.0.1:ssh", Ok(IPv4Address::LOCALHOST.to_host().to_authority(22))),
+            ("[::1]:https", Ok(IPv6Address::LOCALHOST.to_host().to_authority(443))),
+            ("::1:https", Err(InvalidAuthority)),
+            ("example.com:nope", Err(InvalidPort)),
+            ("example.com", Err(InvalidPort)),
+            ("Local_Host:http", Err(InvalidHost)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<Authority, ParseError> =
+                Authority::parse_text_with_services(input.as_bytes(), &services, TransportProtocol::Tcp);
+            assert_eq!(result, *expected, "input={}", input);
+        }
+    }
+
     /// Each canonical string must parse and display back to the exact same string.
     #[test]
     fn round_trip() {
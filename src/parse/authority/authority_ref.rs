@@ -1,6 +1,7 @@
 use crate::ParseError::InvalidAuthority;
-use crate::parse_port;
-use crate::{AuthorityRef, HostRef, IPv6Address, ParseError, impl_parse_ref};
+use crate::{AuthorityRef, HostRef, IPv6Address, ParseError, impl_parse_ref, parse_port};
+#[cfg(feature = "alloc")]
+use crate::{ServiceRegistry, TransportProtocol, parse_service_port};
 
 impl<'a> AuthorityRef<'a> {
     //! Parse
@@ -8,18 +9,39 @@ impl<'a> AuthorityRef<'a> {
     /// A host & a decimal port; an IPv6 host must be bracketed: `localhost:80` or `[::1]:80`.
     /// Domain names must already be in lowercase. Use [`Authority`](crate::Authority) to parse mixed-case input.
     /// A numeric IPv6 zone is accepted & ignored: `[fe80::1%1]:80` parses as `[fe80::1]:80`.
-    pub fn parse_text(text: &'a [u8]) -> Result<Self, ParseError> {
-        let (host, port): (&[u8], u16) = parse_port(text)?;
-        if let Some(ip) = IPv6Address::parse_bracketed(host) {
-            Ok(ip?.to_host_ref().to_authority_ref(port))
-        } else {
-            let host: HostRef = HostRef::parse_text(host)?;
-            if let HostRef::Address(ip) = host
-                && ip.is_v6()
-            {
-                return Err(InvalidAuthority);
-            }
-            Ok(host.to_authority_ref(port))
+    pub const fn parse_text(text: &'a [u8]) -> Result<Self, ParseError> {
+        let (host, port): (&[u8], u16) = match parse_port(text) {
+            Ok(parsed) => parsed,
+            Err(error) => return Err(error),
+        };
+        match Self::parse_host(host) {
+            Ok(host) => Ok(host.to_authority_ref(port)),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// A host & a decimal port or a service name from the `services`: `localhost:80` or `localhost:http`.
+    /// Service names are looked up case-insensitively for the `protocol`; unknown names are an invalid port.
+    /// Otherwise the syntax matches [`Self::parse_text`].
+    #[cfg(feature = "alloc")]
+    pub fn parse_text_with_services(
+        text: &'a [u8],
+        services: &ServiceRegistry,
+        protocol: TransportProtocol,
+    ) -> Result<Self, ParseError> {
+        let (host, port): (&[u8], u16) = parse_service_port(text, services, protocol)?;
+        Self::parse_host(host).map(|host| host.to_authority_ref(port))
+    }
+
+    /// Parses the `host` text, the authority text before the port.
+    pub(crate) const fn parse_host(host: &'a [u8]) -> Result<HostRef<'a>, ParseError> {
+        match IPv6Address::parse_bracketed(host) {
+            Some(Ok(ip)) => Ok(ip.to_host_ref()),
+            Some(Err(error)) => Err(error),
+            None => match HostRef::parse_text(host) {
+                Ok(HostRef::Address(ip)) if ip.is_v6() => Err(InvalidAuthority),
+                result => result,
+            },
         }
     }
 }
@@ -35,6 +57,12 @@ impl_parse_ref!(
 mod tests {
     use crate::ParseError::{InvalidAuthority, InvalidHost, InvalidIPv6Address};
     use crate::{AuthorityRef, DomainRef, HostRef, IPv4Address, IPv6Address, ParseError};
+    use alloc::string::ToString;
+
+    #[cfg(feature = "alloc")]
+    use crate::ParseError::InvalidPort;
+    #[cfg(feature = "alloc")]
+    use crate::{ServiceRegistry, TransportProtocol};
 
     #[test]
     fn try_from_str() {
@@ -81,6 +109,30 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn parse_text_with_services() {
+        let services: ServiceRegistry = ServiceRegistry::iana();
+        let test_cases: &[(&str, Result<AuthorityRef, ParseError>)] = &[
+            (
+                "localhost:http",
+                Ok(AuthorityRef::new(HostRef::Name(DomainRef::LOCALHOST), 80)),
+            ),
+            (
+                "[::1]:https",
+                Ok(AuthorityRef::new(IPv6Address::LOCALHOST.to_host_ref(), 443)),
+            ),
+            ("LocalHost:http", Err(InvalidHost)),
+            ("localhost:nope", Err(InvalidPort)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<AuthorityRef, ParseError> =
+                AuthorityRef::parse_text_with_services(input.as_bytes(), &services, TransportProtocol::Tcp);
+            assert_eq!(result, *expected, "input={}", input);
+        }
+    }
+
     /// Each canonical string must parse and display back to the exact same string.
     #[test]
     fn round_trip() {
@@ -97,4 +149,17 @@ mod tests {
             assert_eq!(value.to_string(), *input, "input={}", input);
         }
     }
+
+    /// The parser runs in `const` contexts, so literals are validated at compile time.
+    #[test]
+    fn const_parse() {
+        const AUTHORITY: Result<AuthorityRef, ParseError> = AuthorityRef::parse_text(b"[::1]:443");
+        assert_eq!(
+            AUTHORITY,
+            Ok(IPv6Address::LOCALHOST.to_host_ref().to_authority_ref(443))
+        );
+
+        const INVALID: Result<AuthorityRef, ParseError> = AuthorityRef::parse_text(b"::1:443");
+        assert_eq!(INVALID, Err(InvalidAuthority));
+    }
 }
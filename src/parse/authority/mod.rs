@@ -1,2 +1,3 @@
+#[cfg(feature = "alloc")]
 mod authority;
 mod authority_ref;
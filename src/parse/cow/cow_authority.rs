@@ -0,0 +1,59 @@
+use crate::{Authority, AuthorityRef, CowAuthority, ParseError, impl_parse_ref};
+
+impl<'a> CowAuthority<'a> {
+    //! Parse
+
+    /// A host & a decimal port; an IPv6 host must be bracketed: `localhost:80` or `[::1]:80`.
+    /// Borrows the text if it is already normalized, & otherwise parses an owned, lowercase copy.
+    pub fn parse_text(text: &'a [u8]) -> Result<Self, ParseError> {
+        match AuthorityRef::parse_text(text) {
+            Ok(authority) => Ok(Self::Borrowed(authority)),
+            Err(_) => Authority::parse_text(text).map(Self::Owned),
+        }
+    }
+}
+
+impl_parse_ref!(
+    CowAuthority,
+    "A host & a decimal port; an IPv6 host must be bracketed: `localhost:80` or `[::1]:80`.",
+    "Borrows the text if it is already normalized, & otherwise parses an owned, lowercase copy."
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::InvalidAuthority;
+    use crate::{AuthorityRef, CowAuthority, Domain, IPv6Address, ParseError};
+
+    #[test]
+    fn parse_text() {
+        let test_cases: &[(&str, Result<CowAuthority, ParseError>)] = &[
+            (
+                "[::1]:80",
+                Ok(CowAuthority::Borrowed(
+                    IPv6Address::LOCALHOST.to_socket(80).to_authority_ref(),
+                )),
+            ),
+            (
+                "LocalHost:80",
+                Ok(CowAuthority::Owned(Domain::localhost().to_host().to_authority(80))),
+            ),
+            (
+                "[fe80::1%1]:80",
+                Ok(CowAuthority::Borrowed(AuthorityRef::try_from("[fe80::1]:80").unwrap())),
+            ),
+            ("::1:80", Err(InvalidAuthority)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<CowAuthority, ParseError> = CowAuthority::try_from(*input);
+            assert_eq!(result, *expected, "input={}", input);
+            let borrowed: bool = matches!(expected, Ok(CowAuthority::Borrowed(_)));
+            assert_eq!(
+                result.is_ok_and(|result| result.is_borrowed()),
+                borrowed,
+                "input={}",
+                input
+            );
+        }
+    }
+}
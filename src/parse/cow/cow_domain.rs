@@ -0,0 +1,47 @@
+use crate::{CowDomain, Domain, DomainRef, ParseError, impl_parse_ref};
+
+impl<'a> CowDomain<'a> {
+    //! Parse
+
+    /// Dot-separated labels of ASCII letters, digits, & dashes. (see [`Domain::is_valid_name`](crate::Domain::is_valid_name))
+    /// Borrows the text if it is already normalized, & otherwise parses an owned, lowercase copy.
+    pub fn parse_text(text: &'a [u8]) -> Result<Self, ParseError> {
+        match DomainRef::parse_text(text) {
+            Ok(domain) => Ok(Self::Borrowed(domain)),
+            Err(_) => Domain::parse_text(text).map(Self::Owned),
+        }
+    }
+}
+
+impl_parse_ref!(
+    CowDomain,
+    "Dot-separated labels of ASCII letters, digits, & dashes. (see [`Domain::is_valid_name`](crate::Domain::is_valid_name))",
+    "Borrows the text if it is already normalized, & otherwise parses an owned, lowercase copy."
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::InvalidDomain;
+    use crate::{CowDomain, Domain, DomainRef, ParseError};
+
+    #[test]
+    fn parse_text() {
+        let test_cases: &[(&str, Result<CowDomain, ParseError>)] = &[
+            ("localhost", Ok(CowDomain::Borrowed(DomainRef::LOCALHOST))),
+            ("LocalHost", Ok(CowDomain::Owned(Domain::localhost()))),
+            ("local host", Err(InvalidDomain)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<CowDomain, ParseError> = CowDomain::try_from(*input);
+            assert_eq!(result, *expected, "input={}", input);
+            let borrowed: bool = matches!(expected, Ok(CowDomain::Borrowed(_)));
+            assert_eq!(
+                result.is_ok_and(|result| result.is_borrowed()),
+                borrowed,
+                "input={}",
+                input
+            );
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{CowEndpoint, Endpoint, EndpointRef, ParseError, impl_parse_ref};
+
+impl<'a> CowEndpoint<'a> {
+    //! Parse
+
+    /// A domain name & a decimal port: `localhost:80`.
+    /// Borrows the text if it is already normalized, & otherwise parses an owned, lowercase copy.
+    pub fn parse_text(text: &'a [u8]) -> Result<Self, ParseError> {
+        match EndpointRef::parse_text(text) {
+            Ok(endpoint) => Ok(Self::Borrowed(endpoint)),
+            Err(_) => Endpoint::parse_text(text).map(Self::Owned),
+        }
+    }
+}
+
+impl_parse_ref!(
+    CowEndpoint,
+    "A domain name & a decimal port: `localhost:80`.",
+    "Borrows the text if it is already normalized, & otherwise parses an owned, lowercase copy."
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::InvalidPort;
+    use crate::{CowEndpoint, Domain, DomainRef, Endpoint, EndpointRef, ParseError};
+
+    #[test]
+    fn parse_text() {
+        let test_cases: &[(&str, Result<CowEndpoint, ParseError>)] = &[
+            (
+                "localhost:80",
+                Ok(CowEndpoint::Borrowed(EndpointRef::new(DomainRef::LOCALHOST, 80))),
+            ),
+            (
+                "LocalHost:80",
+                Ok(CowEndpoint::Owned(Endpoint::new(Domain::localhost(), 80))),
+            ),
+            ("localhost", Err(InvalidPort)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<CowEndpoint, ParseError> = CowEndpoint::try_from(*input);
+            assert_eq!(result, *expected, "input={}", input);
+            let borrowed: bool = matches!(expected, Ok(CowEndpoint::Borrowed(_)));
+            assert_eq!(
+                result.is_ok_and(|result| result.is_borrowed()),
+                borrowed,
+                "input={}",
+                input
+            );
+        }
+    }
+}
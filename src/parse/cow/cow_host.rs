@@ -0,0 +1,48 @@
+use crate::{CowHost, Host, HostRef, ParseError, impl_parse_ref};
+
+impl<'a> CowHost<'a> {
+    //! Parse
+
+    /// A domain name or an unbracketed IP address: `localhost`, `127.0.0.1`, or `::1`.
+    /// Borrows the text if it is already normalized, & otherwise parses an owned, lowercase copy.
+    pub fn parse_text(text: &'a [u8]) -> Result<Self, ParseError> {
+        match HostRef::parse_text(text) {
+            Ok(host) => Ok(Self::Borrowed(host)),
+            Err(_) => Host::parse_text(text).map(Self::Owned),
+        }
+    }
+}
+
+impl_parse_ref!(
+    CowHost,
+    "A domain name or an unbracketed IP address: `localhost`, `127.0.0.1`, or `::1`.",
+    "Borrows the text if it is already normalized, & otherwise parses an owned, lowercase copy."
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::InvalidHost;
+    use crate::{CowHost, Domain, DomainRef, HostRef, IPv6Address, ParseError};
+
+    #[test]
+    fn parse_text() {
+        let test_cases: &[(&str, Result<CowHost, ParseError>)] = &[
+            ("localhost", Ok(CowHost::Borrowed(HostRef::Name(DomainRef::LOCALHOST)))),
+            ("::1", Ok(CowHost::Borrowed(IPv6Address::LOCALHOST.to_host_ref()))),
+            ("LocalHost", Ok(CowHost::Owned(Domain::localhost().to_host()))),
+            ("[::1]", Err(InvalidHost)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<CowHost, ParseError> = CowHost::try_from(*input);
+            assert_eq!(result, *expected, "input={}", input);
+            let borrowed: bool = matches!(expected, Ok(CowHost::Borrowed(_)));
+            assert_eq!(
+                result.is_ok_and(|result| result.is_borrowed()),
+                borrowed,
+                "input={}",
+                input
+            );
+        }
+    }
+}
@@ -0,0 +1,4 @@
+mod cow_authority;
+mod cow_domain;
+mod cow_endpoint;
+mod cow_host;
@@ -1,5 +1,7 @@
 use crate::ParseError::InvalidDomain;
-use crate::{Domain, InvalidAddressError, NameClass, ParseError, impl_parse, impl_parse_string};
+use crate::{Domain, InlineDomain, InvalidAddressError, NameClass, ParseError, impl_parse, impl_parse_string};
+use alloc::string::String;
+use alloc::vec::Vec;
 
 impl Domain {
     //! Parse
@@ -7,24 +9,14 @@ impl Domain {
     /// Dot-separated labels of ASCII letters, digits, & dashes. (see [`Domain::is_valid_name`])
     /// The name is normalized to lowercase.
     pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
-        match Self::classify_name(text) {
-            NameClass::Invalid => Err(InvalidDomain),
-            class => {
-                let name: &str = unsafe { std::str::from_utf8_unchecked(text) };
-                if class == NameClass::MixedCase {
-                    Ok(unsafe { Self::new_unchecked(name.to_ascii_lowercase()) })
-                } else {
-                    Ok(unsafe { Self::new_unchecked(name) })
-                }
-            }
-        }
+        InlineDomain::parse_text(text).map(|domain| domain.to_domain())
     }
 
     /// Creates a domain from the first `len` bytes of `text`, normalizing the name to lowercase.
     ///
     /// Returns the unmodified `text` if the prefix is not a valid domain name.
     pub(crate) fn parse_vec_prefix(text: Vec<u8>, len: usize) -> Result<Self, Vec<u8>> {
-        match Self::classify_name(&text[..len]) {
+        match NameClass::of_name(&text[..len]) {
             NameClass::Invalid => Err(text),
             class => {
                 let mut text: Vec<u8> = text;
@@ -67,7 +59,10 @@ impl TryFrom<Vec<u8>> for Domain {
 mod tests {
     use crate::ParseError::InvalidDomain;
     use crate::{Domain, InvalidAddressError, ParseError};
-    use std::str::FromStr;
+    use alloc::string::String;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+    use core::str::FromStr;
 
     #[test]
     fn from_str() {
@@ -1,14 +1,14 @@
 use crate::ParseError::InvalidDomain;
-use crate::{Domain, DomainRef, ParseError, impl_parse_ref};
+use crate::{DomainRef, NameClass, ParseError, impl_parse_ref};
 
 impl<'a> DomainRef<'a> {
     //! Parse
 
-    /// Dot-separated labels of ASCII letters, digits, & dashes. (see [`Domain::is_valid_name`])
+    /// Dot-separated labels of ASCII letters, digits, & dashes. (see [`Domain::is_valid_name`](crate::Domain::is_valid_name))
     /// The name must already be in lowercase. Use [`Domain`](crate::Domain) to parse mixed-case input.
-    pub fn parse_text(text: &'a [u8]) -> Result<Self, ParseError> {
-        if Domain::is_valid_name(text) {
-            let name: &str = unsafe { std::str::from_utf8_unchecked(text) };
+    pub const fn parse_text(text: &'a [u8]) -> Result<Self, ParseError> {
+        if NameClass::of_name(text).is_valid(false) {
+            let name: &str = unsafe { core::str::from_utf8_unchecked(text) };
             Ok(unsafe { Self::new_unchecked(name) })
         } else {
             Err(InvalidDomain)
@@ -18,7 +18,7 @@ impl<'a> DomainRef<'a> {
 
 impl_parse_ref!(
     DomainRef,
-    "Dot-separated labels of ASCII letters, digits, & dashes. (see [`Domain::is_valid_name`])",
+    "Dot-separated labels of ASCII letters, digits, & dashes. (see [`Domain::is_valid_name`](crate::Domain::is_valid_name))",
     "The name must already be in lowercase. Use [`Domain`](crate::Domain) to parse mixed-case input."
 );
 
@@ -26,6 +26,7 @@ impl_parse_ref!(
 mod tests {
     use crate::ParseError::InvalidDomain;
     use crate::{DomainRef, ParseError};
+    use alloc::string::ToString;
 
     #[test]
     fn try_from_str() {
@@ -69,4 +70,14 @@ mod tests {
             assert_eq!(value.to_string(), *input, "input={}", input);
         }
     }
+
+    /// The parser runs in `const` contexts, so literals are validated at compile time.
+    #[test]
+    fn const_parse() {
+        const DOMAIN: Result<DomainRef, ParseError> = DomainRef::parse_text(b"example.com");
+        assert_eq!(DOMAIN, Ok(DomainRef::EXAMPLE));
+
+        const INVALID: Result<DomainRef, ParseError> = DomainRef::parse_text(b"example..com");
+        assert_eq!(INVALID, Err(InvalidDomain));
+    }
 }
@@ -1,5 +1,6 @@
 use crate::ParseError::InvalidDomain;
 use crate::{Domain, DomainRef, ParseError};
+use alloc::string::String;
 
 impl Domain {
     //! International Domain Names
@@ -38,6 +39,8 @@ impl<'a> DomainRef<'a> {
 mod tests {
     use crate::ParseError::InvalidDomain;
     use crate::{Domain, DomainRef, ParseError};
+    use alloc::string::String;
+    use alloc::string::ToString;
 
     #[test]
     fn parse_unicode() {
@@ -1,7 +1,10 @@
 pub(crate) use name_class::*;
+pub(crate) use name_scan::*;
 
 mod name_class;
+mod name_scan;
 
+#[cfg(feature = "alloc")]
 mod domain;
 mod domain_ref;
 mod validation;
@@ -0,0 +1,218 @@
+use crate::NameClass;
+
+/// A single-pass scan of a domain name: its [NameClass], & whether the text could be an IP address instead.
+///
+/// One table lookup per byte validates the byte, splits the labels, & lowercases the name, so the parsers read each
+/// input once. The table only accepts ASCII, which upholds the [NameClass] contract that a valid class proves the
+/// bytes are valid UTF-8.
+#[must_use]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) struct NameScan {
+    class: NameClass,
+    numeric: bool,
+    colon: bool,
+}
+
+impl NameScan {
+    //! Byte Flags
+
+    const LOWERCASE: u8 = 0x01;
+    const DIGIT: u8 = 0x02;
+    const DASH: u8 = 0x04;
+    const DOT: u8 = 0x08;
+    const COLON: u8 = 0x10;
+
+    /// The flag of the uppercase letters, equal to the bit that lowercases them.
+    const UPPERCASE: u8 = 0x20;
+
+    /// The flags of the letters & digits, which may appear anywhere in a label.
+    const ALPHANUMERIC: u8 = Self::LOWERCASE | Self::UPPERCASE | Self::DIGIT;
+
+    /// The flags of each byte.
+    const FLAGS: [u8; 256] = {
+        let mut table: [u8; 256] = [0; 256];
+        let mut c: u8 = 0;
+        while c < 26 {
+            table[(b'a' + c) as usize] = Self::LOWERCASE;
+            table[(b'A' + c) as usize] = Self::UPPERCASE;
+            c += 1;
+        }
+        let mut c: u8 = 0;
+        while c < 10 {
+            table[(b'0' + c) as usize] = Self::DIGIT;
+            c += 1;
+        }
+        table[b'-' as usize] = Self::DASH;
+        table[b'.' as usize] = Self::DOT;
+        table[b':' as usize] = Self::COLON;
+        table
+    };
+}
+
+impl NameScan {
+    //! Scan
+
+    /// Scans the `name`.
+    pub(crate) const fn of(name: &[u8]) -> Self {
+        Self::scan(name, None)
+    }
+
+    /// Scans the `name`, writing its lowercase form to the start of the `buffer`.
+    ///
+    /// The `buffer` holds the whole lowercase name when the class is valid; otherwise its contents are unspecified.
+    pub(crate) const fn of_lowercase(name: &[u8], buffer: &mut [u8; NameClass::MAX_NAME_LEN]) -> Self {
+        Self::scan(name, Some(buffer))
+    }
+
+    /// Scans the `name`, stopping at the first invalid byte or label.
+    const fn scan(name: &[u8], mut buffer: Option<&mut [u8; NameClass::MAX_NAME_LEN]>) -> Self {
+        if name.is_empty() || name.len() > NameClass::MAX_NAME_LEN {
+            return Self::invalid(false);
+        }
+
+        let mut seen: u8 = 0;
+        let mut label: usize = 0;
+        let mut i: usize = 0;
+        while i < name.len() {
+            let c: u8 = name[i];
+            let flags: u8 = Self::FLAGS[c as usize];
+            if flags & Self::ALPHANUMERIC == 0 {
+                if flags == Self::DOT {
+                    if !Self::is_label(name, label, i) {
+                        return Self::invalid(false);
+                    }
+                    label = i + 1;
+                } else if flags != Self::DASH || i == label {
+                    return Self::invalid(flags == Self::COLON);
+                }
+            }
+            if let Some(buffer) = &mut buffer {
+                buffer[i] = c | (flags & Self::UPPERCASE);
+            }
+            seen |= flags;
+            i += 1;
+        }
+        if !Self::is_label(name, label, name.len()) {
+            return Self::invalid(false);
+        }
+
+        Self {
+            class: if seen & Self::UPPERCASE == 0 {
+                NameClass::Lowercase
+            } else {
+                NameClass::MixedCase
+            },
+            numeric: seen & !(Self::DIGIT | Self::DOT) == 0,
+            colon: false,
+        }
+    }
+
+    /// Checks if the `name` bytes from the `start` to the `end` are a valid label. The label's bytes were validated
+    /// as they were scanned, & its first byte is not a dash.
+    const fn is_label(name: &[u8], start: usize, end: usize) -> bool {
+        end > start && end - start <= NameClass::MAX_LABEL_LEN && name[end - 1] != b'-'
+    }
+
+    /// Creates an invalid scan, which stopped at a `colon` or at another invalid byte or label.
+    const fn invalid(colon: bool) -> Self {
+        Self {
+            class: NameClass::Invalid,
+            numeric: false,
+            colon,
+        }
+    }
+}
+
+impl NameScan {
+    //! Properties
+
+    /// Gets the class of the name.
+    pub(crate) const fn class(self) -> NameClass {
+        self.class
+    }
+
+    /// Checks if the name is valid & could be an IPv4 address: every byte is a digit or a dot.
+    #[must_use]
+    pub(crate) const fn may_be_ipv4(self) -> bool {
+        self.numeric
+    }
+
+    /// Checks if the text could be an IPv6 address: the scan stopped at a colon, which no domain name contains.
+    ///
+    /// An IPv6 address has no dot, dash, or long label before its first colon, so no label error can stop the scan
+    /// first.
+    #[must_use]
+    pub(crate) const fn may_be_ipv6(self) -> bool {
+        self.colon
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{NameClass, NameScan};
+
+    #[cfg(feature = "alloc")]
+    use alloc::vec::Vec;
+
+    #[test]
+    fn scan() {
+        let test_cases: &[(&str, NameClass, bool, bool)] = &[
+            ("", NameClass::Invalid, false, false),
+            ("localhost", NameClass::Lowercase, false, false),
+            ("Local-Host.COM", NameClass::MixedCase, false, false),
+            ("127.0.0.1", NameClass::Lowercase, true, false),
+            ("999.1.1.1", NameClass::Lowercase, true, false),
+            ("::1", NameClass::Invalid, false, true),
+            ("fe80::1", NameClass::Invalid, false, true),
+            ("a_b::1", NameClass::Invalid, false, false),
+            ("-a", NameClass::Invalid, false, false),
+            ("a-", NameClass::Invalid, false, false),
+            ("a..b", NameClass::Invalid, false, false),
+            ("a.", NameClass::Invalid, false, false),
+            (".a", NameClass::Invalid, false, false),
+            ("ü", NameClass::Invalid, false, false),
+        ];
+
+        for (input, class, ipv4, ipv6) in test_cases {
+            let result: NameScan = NameScan::of(input.as_bytes());
+            assert_eq!(result.class(), *class, "input={}", input);
+            assert_eq!(result.may_be_ipv4(), *ipv4, "input={}", input);
+            assert_eq!(result.may_be_ipv6(), *ipv6, "input={}", input);
+        }
+    }
+
+    #[test]
+    fn lowercase() {
+        let mut buffer: [u8; NameClass::MAX_NAME_LEN] = [0; NameClass::MAX_NAME_LEN];
+        let input: &str = "WWW.Example-1.COM";
+        let result: NameScan = NameScan::of_lowercase(input.as_bytes(), &mut buffer);
+        assert_eq!(result.class(), NameClass::MixedCase);
+        assert_eq!(&buffer[..input.len()], b"www.example-1.com");
+    }
+
+    /// The scan agrees with the label-by-label classification over every short name of the interesting bytes.
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn matches_labels() {
+        let alphabet: &[u8] = b"aZ0-.:_";
+        for len in 0..=5 {
+            for mut index in 0..alphabet.len().pow(len) {
+                let mut name: Vec<u8> = Vec::new();
+                for _ in 0..len {
+                    name.push(alphabet[index % alphabet.len()]);
+                    index /= alphabet.len();
+                }
+                let expected: NameClass =
+                    name.split(|c| *c == b'.').fold(NameClass::Lowercase, |class, label| {
+                        match (class, NameClass::of_label(label)) {
+                            (NameClass::Invalid, _) | (_, NameClass::Invalid) => NameClass::Invalid,
+                            (NameClass::MixedCase, _) | (_, NameClass::MixedCase) => NameClass::MixedCase,
+                            _ => NameClass::Lowercase,
+                        }
+                    });
+                let expected: NameClass = if name.is_empty() { NameClass::Invalid } else { expected };
+                assert_eq!(NameScan::of(&name).class(), expected, "name={:?}", name);
+            }
+        }
+    }
+}
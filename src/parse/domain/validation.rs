@@ -1,42 +1,64 @@
-use crate::{Domain, NameClass};
+#[cfg(feature = "alloc")]
+use crate::Domain;
+use crate::{NameClass, NameScan};
 
-impl Domain {
-    //! Label Validation
+impl NameClass {
+    //! Classification
 
     /// The maximum length of a domain label.
-    pub const MAX_LABEL_LEN: usize = 63;
+    pub(crate) const MAX_LABEL_LEN: usize = 63;
+
+    /// The maximum length of a domain name.
+    pub(crate) const MAX_NAME_LEN: usize = 253;
 
     /// Classifies the domain `label`.
     ///
     /// The accepted bytes are ASCII, so a non-`Invalid` class proves the label is valid UTF-8. The parse impls
     /// rely on that to convert classified bytes without re-validating; widening the byte set here would make
     /// those conversions unsound.
-    pub(crate) fn classify_label(label: &[u8]) -> NameClass {
+    #[cfg(feature = "alloc")]
+    pub(crate) const fn of_label(label: &[u8]) -> Self {
         if (label.is_empty() || label.len() > Self::MAX_LABEL_LEN)
             || (label[0] == b'-' || label[label.len() - 1] == b'-')
         {
-            NameClass::Invalid
+            Self::Invalid
         } else {
-            let mut class: NameClass = NameClass::Lowercase;
-            for c in label {
+            let mut class: Self = Self::Lowercase;
+            let mut i: usize = 0;
+            while i < label.len() {
+                let c: u8 = label[i];
                 if c.is_ascii_uppercase() {
-                    class = NameClass::MixedCase;
-                } else if !(c.is_ascii_lowercase() || c.is_ascii_digit() || *c == b'-') {
-                    return NameClass::Invalid;
+                    class = Self::MixedCase;
+                } else if !(c.is_ascii_lowercase() || c.is_ascii_digit() || c == b'-') {
+                    return Self::Invalid;
                 }
+                i += 1;
             }
             class
         }
     }
 
-    /// Checks if the domain `label` is valid, optionally ignoring case.
-    pub(crate) fn is_valid_label_op_ignore_case(label: &[u8], ignore_case: bool) -> bool {
-        match Self::classify_label(label) {
-            NameClass::Lowercase => true,
-            NameClass::MixedCase => ignore_case,
-            NameClass::Invalid => false,
+    /// Classifies the domain `name`. (see [NameScan])
+    pub(crate) const fn of_name(name: &[u8]) -> Self {
+        NameScan::of(name).class()
+    }
+
+    /// Checks if the class is valid, optionally ignoring case.
+    pub(crate) const fn is_valid(self, ignore_case: bool) -> bool {
+        match self {
+            Self::Lowercase => true,
+            Self::MixedCase => ignore_case,
+            Self::Invalid => false,
         }
     }
+}
+
+#[cfg(feature = "alloc")]
+impl Domain {
+    //! Label Validation
+
+    /// The maximum length of a domain label.
+    pub const MAX_LABEL_LEN: usize = NameClass::MAX_LABEL_LEN;
 
     /// Checks if the domain `label` is valid.
     ///
@@ -47,60 +69,35 @@ impl Domain {
     /// are only valid with [`Self::is_valid_label_ignore_case`]; see [`Self::is_valid_name`] for how the crate
     /// diverges from those documents.
     #[must_use]
-    pub fn is_valid_label(label: &[u8]) -> bool {
-        Self::is_valid_label_op_ignore_case(label, false)
+    pub const fn is_valid_label(label: &[u8]) -> bool {
+        NameClass::of_label(label).is_valid(false)
     }
 
     /// Checks if the domain `label` is valid, accepting uppercase letters. (see [`Self::is_valid_label`])
     #[must_use]
-    pub fn is_valid_label_ignore_case(label: &[u8]) -> bool {
-        Self::is_valid_label_op_ignore_case(label, true)
+    pub const fn is_valid_label_ignore_case(label: &[u8]) -> bool {
+        NameClass::of_label(label).is_valid(true)
     }
 
     /// Checks if the domain `label` is valid.
     #[must_use]
-    pub fn is_valid_label_str(label: &str) -> bool {
+    pub const fn is_valid_label_str(label: &str) -> bool {
         Self::is_valid_label(label.as_bytes())
     }
 
     /// Checks if the domain `label` is valid, accepting uppercase letters.
     #[must_use]
-    pub fn is_valid_label_ignore_case_str(label: &str) -> bool {
+    pub const fn is_valid_label_ignore_case_str(label: &str) -> bool {
         Self::is_valid_label_ignore_case(label.as_bytes())
     }
 }
 
+#[cfg(feature = "alloc")]
 impl Domain {
     //! Domain Validation
 
     /// The maximum length of a domain name.
-    pub const MAX_NAME_LEN: usize = 253;
-
-    /// Classifies the domain `name`.
-    pub(crate) fn classify_name(name: &[u8]) -> NameClass {
-        if name.is_empty() || name.len() > Self::MAX_NAME_LEN {
-            NameClass::Invalid
-        } else {
-            let mut class: NameClass = NameClass::Lowercase;
-            for label in name.split(|c| *c == b'.') {
-                match Self::classify_label(label) {
-                    NameClass::Invalid => return NameClass::Invalid,
-                    NameClass::MixedCase => class = NameClass::MixedCase,
-                    NameClass::Lowercase => {}
-                }
-            }
-            class
-        }
-    }
-
-    /// Checks if the domain `name` is valid, optionally ignoring case.
-    pub(crate) fn is_valid_name_op_ignore_case(name: &[u8], ignore_case: bool) -> bool {
-        match Self::classify_name(name) {
-            NameClass::Lowercase => true,
-            NameClass::MixedCase => ignore_case,
-            NameClass::Invalid => false,
-        }
-    }
+    pub const MAX_NAME_LEN: usize = NameClass::MAX_NAME_LEN;
 
     /// Checks if the domain `name` is valid.
     ///
@@ -122,32 +119,34 @@ impl Domain {
     /// [RFC 2782](https://www.rfc-editor.org/rfc/rfc2782) cannot be represented, even though
     /// [RFC 2181](https://www.rfc-editor.org/rfc/rfc2181#section-11) permits any octet in a label.
     #[must_use]
-    pub fn is_valid_name(name: &[u8]) -> bool {
-        Self::is_valid_name_op_ignore_case(name, false)
+    pub const fn is_valid_name(name: &[u8]) -> bool {
+        NameClass::of_name(name).is_valid(false)
     }
 
     /// Checks if the domain `name` is valid, accepting uppercase letters. (see [`Self::is_valid_name`])
     #[must_use]
-    pub fn is_valid_name_ignore_case(name: &[u8]) -> bool {
-        Self::is_valid_name_op_ignore_case(name, true)
+    pub const fn is_valid_name_ignore_case(name: &[u8]) -> bool {
+        NameClass::of_name(name).is_valid(true)
     }
 
     /// Checks if the domain `name` is valid.
     #[must_use]
-    pub fn is_valid_name_str(name: &str) -> bool {
+    pub const fn is_valid_name_str(name: &str) -> bool {
         Self::is_valid_name(name.as_bytes())
     }
 
     /// Checks if the domain `name` is valid, accepting uppercase letters.
     #[must_use]
-    pub fn is_valid_name_ignore_case_str(name: &str) -> bool {
+    pub const fn is_valid_name_ignore_case_str(name: &str) -> bool {
         Self::is_valid_name_ignore_case(name.as_bytes())
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use crate::Domain;
+    use alloc::format;
+    use alloc::string::String;
 
     #[test]
     fn is_valid_label() {
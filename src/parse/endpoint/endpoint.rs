@@ -1,6 +1,7 @@
 use crate::ParseError::InvalidDomain;
 use crate::parse_port;
 use crate::{Domain, Endpoint, InvalidAddressError, ParseError, impl_parse, impl_parse_string};
+use alloc::vec::Vec;
 
 impl Endpoint {
     //! Parse
@@ -49,7 +50,10 @@ impl TryFrom<Vec<u8>> for Endpoint {
 mod tests {
     use crate::ParseError::{InvalidDomain, InvalidPort};
     use crate::{DomainRef, Endpoint, InvalidAddressError, ParseError};
-    use std::str::FromStr;
+    use alloc::string::String;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+    use core::str::FromStr;
 
     #[test]
     fn from_str() {
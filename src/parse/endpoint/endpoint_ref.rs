@@ -6,10 +6,15 @@ impl<'a> EndpointRef<'a> {
 
     /// A domain name & a decimal port: `localhost:80`.
     /// Domain names must already be in lowercase. Use [`Endpoint`](crate::Endpoint) to parse mixed-case input.
-    pub fn parse_text(text: &'a [u8]) -> Result<Self, ParseError> {
-        let (domain, port): (&[u8], u16) = parse_port(text)?;
-        let domain: DomainRef = DomainRef::parse_text(domain)?;
-        Ok(Self::new(domain, port))
+    pub const fn parse_text(text: &'a [u8]) -> Result<Self, ParseError> {
+        let (domain, port): (&[u8], u16) = match parse_port(text) {
+            Ok(parsed) => parsed,
+            Err(error) => return Err(error),
+        };
+        match DomainRef::parse_text(domain) {
+            Ok(domain) => Ok(Self::new(domain, port)),
+            Err(error) => Err(error),
+        }
     }
 }
 
@@ -23,6 +28,7 @@ impl_parse_ref!(
 mod tests {
     use crate::ParseError::InvalidDomain;
     use crate::{DomainRef, EndpointRef, ParseError};
+    use alloc::string::ToString;
 
     #[test]
     fn try_from_str() {
@@ -63,4 +69,14 @@ mod tests {
             assert_eq!(value.to_string(), *input, "input={}", input);
         }
     }
+
+    /// The parser runs in `const` contexts, so literals are validated at compile time.
+    #[test]
+    fn const_parse() {
+        const ENDPOINT: Result<EndpointRef, ParseError> = EndpointRef::parse_text(b"localhost:80");
+        assert_eq!(ENDPOINT, Ok(EndpointRef::new(DomainRef::LOCALHOST, 80)));
+
+        const INVALID: Result<EndpointRef, ParseError> = EndpointRef::parse_text(b"LocalHost:80");
+        assert_eq!(INVALID, Err(InvalidDomain));
+    }
 }
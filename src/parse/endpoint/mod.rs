@@ -1,2 +1,3 @@
+#[cfg(feature = "alloc")]
 mod endpoint;
 mod endpoint_ref;
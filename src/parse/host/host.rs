@@ -1,5 +1,6 @@
 use crate::ParseError::InvalidHost;
-use crate::{Domain, Host, IPAddress, InvalidAddressError, ParseError, impl_parse, impl_parse_string};
+use crate::{Domain, Host, IPAddress, InlineHost, InvalidAddressError, ParseError, impl_parse, impl_parse_string};
+use alloc::vec::Vec;
 
 impl Host {
     //! Parse
@@ -7,13 +8,7 @@ impl Host {
     /// A domain name or an unbracketed IP address: `localhost`, `127.0.0.1`, or `::1`.
     /// Domain names are normalized to lowercase.
     pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
-        if let Ok(ip) = IPAddress::parse_text(text) {
-            Ok(ip.to_host())
-        } else if let Ok(domain) = Domain::parse_text(text) {
-            Ok(domain.to_host())
-        } else {
-            Err(InvalidHost)
-        }
+        InlineHost::parse_text(text).map(|host| host.to_host())
     }
 
     /// Creates a host from the first `len` bytes of `text`, normalizing domain names to lowercase.
@@ -56,7 +51,10 @@ impl TryFrom<Vec<u8>> for Host {
 mod tests {
     use crate::ParseError::InvalidHost;
     use crate::{Domain, Host, IPv4Address, IPv6Address, InvalidAddressError, ParseError};
-    use std::str::FromStr;
+    use alloc::string::String;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+    use core::str::FromStr;
 
     #[test]
     fn from_str() {
@@ -1,18 +1,36 @@
 use crate::ParseError::InvalidHost;
-use crate::{DomainRef, HostRef, IPAddress, ParseError, impl_parse_ref};
+use crate::{DomainRef, HostRef, IPv4Address, IPv6Address, NameClass, NameScan, ParseError, impl_parse_ref};
 
 impl<'a> HostRef<'a> {
     //! Parse
 
     /// A domain name or an unbracketed IP address: `localhost`, `127.0.0.1`, or `::1`.
     /// Domain names must already be in lowercase. Use [`Host`](crate::Host) to parse mixed-case input.
-    pub fn parse_text(text: &'a [u8]) -> Result<Self, ParseError> {
-        if let Ok(ip) = IPAddress::parse_text(text) {
-            Ok(ip.to_host_ref())
-        } else if let Ok(domain) = DomainRef::parse_text(text) {
-            Ok(domain.to_host_ref())
-        } else {
-            Err(InvalidHost)
+    ///
+    /// The text is scanned once as a domain name. (see [`InlineHost::parse_text`](crate::InlineHost::parse_text))
+    pub const fn parse_text(text: &'a [u8]) -> Result<Self, ParseError> {
+        let scan: NameScan = NameScan::of(text);
+        match scan.class() {
+            NameClass::Lowercase => {
+                if scan.may_be_ipv4()
+                    && let Ok(ip) = IPv4Address::parse_text(text)
+                {
+                    Ok(ip.to_host_ref())
+                } else {
+                    let name: &str = unsafe { core::str::from_utf8_unchecked(text) };
+                    Ok(unsafe { DomainRef::new_unchecked(name) }.to_host_ref())
+                }
+            }
+            NameClass::MixedCase => Err(InvalidHost),
+            NameClass::Invalid => {
+                if scan.may_be_ipv6()
+                    && let Ok(ip) = IPv6Address::parse_text(text)
+                {
+                    Ok(ip.to_host_ref())
+                } else {
+                    Err(InvalidHost)
+                }
+            }
         }
     }
 }
@@ -27,6 +45,7 @@ impl_parse_ref!(
 mod tests {
     use crate::ParseError::InvalidHost;
     use crate::{DomainRef, HostRef, IPv4Address, ParseError};
+    use alloc::string::ToString;
 
     #[test]
     fn try_from_str() {
@@ -1,2 +1,3 @@
+#[cfg(feature = "alloc")]
 mod host;
 mod host_ref;
@@ -0,0 +1,160 @@
+use crate::ParseError::InvalidHost;
+use crate::{
+    Authority, Host, HostPort, IPv6Address, InvalidAddressError, ParseError, impl_parse, impl_parse_string, parse_port,
+};
+use alloc::vec::Vec;
+
+impl HostPort {
+    //! Parse
+
+    /// A host & an optional decimal port; an IPv6 host must be bracketed: `localhost`, `localhost:80`, `[::1]`, or
+    /// `[::1]:80`. Domain names are normalized to lowercase.
+    /// A numeric IPv6 zone is accepted & ignored: `[fe80::1%1]:80` parses as `[fe80::1]:80`.
+    pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+        if let Some(ip) = IPv6Address::parse_bracketed(text) {
+            Ok(Self::new(ip?.to_host(), None))
+        } else if text.contains(&b':') {
+            let (host, port): (&[u8], u16) = parse_port(text)?;
+            Ok(Self::new(Authority::parse_host(host)?, Some(port)))
+        } else {
+            Ok(Self::new(Authority::parse_host(text)?, None))
+        }
+    }
+}
+
+impl_parse!(
+    HostPort,
+    "A host & an optional decimal port; an IPv6 host must be bracketed: `localhost`, `localhost:80`, `[::1]`, or",
+    "`[::1]:80`. Domain names are normalized to lowercase.",
+    "A numeric IPv6 zone is accepted & ignored: `[fe80::1%1]:80` parses as `[fe80::1]:80`."
+);
+
+impl_parse_string!(
+    HostPort,
+    "A host & an optional decimal port; an IPv6 host must be bracketed: `localhost`, `localhost:80`, `[::1]`, or",
+    "`[::1]:80`. Domain names are normalized to lowercase.",
+    "A numeric IPv6 zone is accepted & ignored: `[fe80::1%1]:80` parses as `[fe80::1]:80`."
+);
+
+impl TryFrom<Vec<u8>> for HostPort {
+    type Error = InvalidAddressError<Vec<u8>>;
+
+    /// A host & an optional decimal port; an IPv6 host must be bracketed: `localhost`, `localhost:80`, `[::1]`, or
+    /// `[::1]:80`. Domain names are normalized to lowercase.
+    /// A numeric IPv6 zone is accepted & ignored: `[fe80::1%1]:80` parses as `[fe80::1]:80`.
+    /// The error contains the unmodified `text`, which `TryFrom<String>` soundly recovers as a string.
+    fn try_from(text: Vec<u8>) -> Result<Self, Self::Error> {
+        if IPv6Address::parse_bracketed(text.as_slice()).is_some() {
+            Self::parse_text(text.as_slice()).map_err(|error| InvalidAddressError::new(text, error))
+        } else if text.contains(&b':') {
+            Authority::try_from(text).map(Self::from)
+        } else {
+            let len: usize = text.len();
+            Host::parse_vec_prefix(text, len)
+                .map(Self::from)
+                .map_err(|text| InvalidAddressError::new(text, InvalidHost))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::{InvalidAuthority, InvalidHost, InvalidIPv6Address, InvalidPort};
+    use crate::{Domain, HostPort, IPv4Address, IPv6Address, InvalidAddressError, ParseError};
+    use alloc::string::String;
+    use alloc::string::ToString;
+    use core::str::FromStr;
+
+    #[test]
+    fn parse() {
+        let test_cases: &[(&str, Result<HostPort, ParseError>)] = &[
+            ("", Err(InvalidHost)),
+            ("localhost", Ok(HostPort::new(Domain::localhost().to_host(), None))),
+            ("LocalHost", Ok(HostPort::new(Domain::localhost().to_host(), None))),
+            (
+                "localhost:80",
+                Ok(HostPort::new(Domain::localhost().to_host(), Some(80))),
+            ),
+            ("localhost:", Err(InvalidPort)),
+            ("localhost:+80", Err(InvalidPort)),
+            ("localhost:65536", Err(InvalidPort)),
+            ("127.0.0.1", Ok(HostPort::new(IPv4Address::LOCALHOST.to_host(), None))),
+            (
+                "127.0.0.1:80",
+                Ok(HostPort::new(IPv4Address::LOCALHOST.to_host(), Some(80))),
+            ),
+            ("[::1]", Ok(HostPort::new(IPv6Address::LOCALHOST.to_host(), None))),
+            (
+                "[::1]:80",
+                Ok(HostPort::new(IPv6Address::LOCALHOST.to_host(), Some(80))),
+            ),
+            ("[::1%1]", Ok(HostPort::new(IPv6Address::LOCALHOST.to_host(), None))),
+            ("[::1%eth0]", Err(InvalidIPv6Address)),
+            ("[]", Err(InvalidIPv6Address)),
+            ("::1", Err(InvalidHost)),
+            ("::1:80", Err(InvalidAuthority)),
+            (":80", Err(InvalidHost)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<HostPort, ParseError> = HostPort::from_str(input);
+            assert_eq!(result, *expected, "input={}", input);
+
+            let result: Result<HostPort, ParseError> = HostPort::try_from(*input);
+            assert_eq!(result, *expected, "input={}", input);
+
+            let result: Result<HostPort, ParseError> = HostPort::parse_text(input.as_bytes());
+            assert_eq!(result, *expected, "input={}", input);
+        }
+    }
+
+    #[test]
+    fn try_from_string() {
+        let test_cases: &[(&str, Result<HostPort, ParseError>)] = &[
+            ("", Err(InvalidHost)),
+            ("LocalHost", Ok(HostPort::new(Domain::localhost().to_host(), None))),
+            (
+                "LocalHost:80",
+                Ok(HostPort::new(Domain::localhost().to_host(), Some(80))),
+            ),
+            ("[::1]", Ok(HostPort::new(IPv6Address::LOCALHOST.to_host(), None))),
+            (
+                "[::1]:80",
+                Ok(HostPort::new(IPv6Address::LOCALHOST.to_host(), Some(80))),
+            ),
+            ("[xx]", Err(InvalidIPv6Address)),
+            ("::1:80", Err(InvalidAuthority)),
+            ("local!host", Err(InvalidHost)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<HostPort, InvalidAddressError<String>> = HostPort::try_from(input.to_string());
+            match (result, expected) {
+                (Ok(result), Ok(expected)) => assert_eq!(result, *expected, "input={}", input),
+                (Err(error), Err(expected)) => {
+                    assert_eq!(error.error(), *expected, "input={}", input);
+                    assert_eq!(error.value(), *input, "input={}", input);
+                }
+                (result, expected) => panic!("input={} result={:?} expected={:?}", input, result, expected),
+            }
+        }
+    }
+
+    /// Each canonical string must parse and display back to the exact same string.
+    #[test]
+    fn round_trip() {
+        let canonical: &[&str] = &[
+            "localhost",
+            "localhost:80",
+            "127.0.0.1",
+            "127.0.0.1:80",
+            "[::1]",
+            "[::1]:443",
+        ];
+
+        for input in canonical {
+            let value: HostPort = HostPort::from_str(input).unwrap();
+            assert_eq!(value.to_string(), *input, "input={}", input);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{AuthorityRef, HostPortRef, IPv6Address, ParseError, impl_parse_ref, parse_port};
+
+impl<'a> HostPortRef<'a> {
+    //! Parse
+
+    /// A host & an optional decimal port; an IPv6 host must be bracketed: `localhost`, `localhost:80`, `[::1]`, or
+    /// `[::1]:80`. Domain names must already be in lowercase. Use [`HostPort`](crate::HostPort) to parse mixed-case
+    /// input. A numeric IPv6 zone is accepted & ignored: `[fe80::1%1]:80` parses as `[fe80::1]:80`.
+    pub fn parse_text(text: &'a [u8]) -> Result<Self, ParseError> {
+        if let Some(ip) = IPv6Address::parse_bracketed(text) {
+            Ok(Self::new(ip?.to_host_ref(), None))
+        } else if text.contains(&b':') {
+            let (host, port): (&[u8], u16) = parse_port(text)?;
+            Ok(Self::new(AuthorityRef::parse_host(host)?, Some(port)))
+        } else {
+            Ok(Self::new(AuthorityRef::parse_host(text)?, None))
+        }
+    }
+}
+
+impl_parse_ref!(
+    HostPortRef,
+    "A host & an optional decimal port; an IPv6 host must be bracketed: `localhost`, `localhost:80`, `[::1]`, or",
+    "`[::1]:80`. Domain names must already be in lowercase. Use [`HostPort`](crate::HostPort) to parse mixed-case",
+    "input. A numeric IPv6 zone is accepted & ignored: `[fe80::1%1]:80` parses as `[fe80::1]:80`."
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::{InvalidAuthority, InvalidHost, InvalidPort};
+    use crate::{DomainRef, HostPortRef, HostRef, IPv6Address, ParseError};
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse_text() {
+        let test_cases: &[(&str, Result<HostPortRef, ParseError>)] = &[
+            (
+                "localhost",
+                Ok(HostPortRef::new(HostRef::Name(DomainRef::LOCALHOST), None)),
+            ),
+            (
+                "localhost:80",
+                Ok(HostPortRef::new(HostRef::Name(DomainRef::LOCALHOST), Some(80))),
+            ),
+            ("LocalHost", Err(InvalidHost)),
+            ("localhost:", Err(InvalidPort)),
+            (
+                "[::1]",
+                Ok(HostPortRef::new(IPv6Address::LOCALHOST.to_host_ref(), None)),
+            ),
+            (
+                "[::1]:80",
+                Ok(HostPortRef::new(IPv6Address::LOCALHOST.to_host_ref(), Some(80))),
+            ),
+            ("::1", Err(InvalidHost)),
+            ("::1:80", Err(InvalidAuthority)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<HostPortRef, ParseError> = HostPortRef::try_from(*input);
+            assert_eq!(result, *expected, "input={}", input);
+        }
+    }
+
+    /// Each canonical string must parse and display back to the exact same string.
+    #[test]
+    fn round_trip() {
+        let canonical: &[&str] = &["localhost", "example.com:8443", "127.0.0.1", "[::1]", "[fe80::1]:0"];
+
+        for input in canonical {
+            let value: HostPortRef = HostPortRef::try_from(*input).unwrap();
+            assert_eq!(value.to_string(), *input, "input={}", input);
+        }
+    }
+}
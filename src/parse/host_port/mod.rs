@@ -0,0 +1,3 @@
+#[cfg(feature = "alloc")]
+mod host_port;
+mod host_port_ref;
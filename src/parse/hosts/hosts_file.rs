@@ -0,0 +1,141 @@
+use crate::ParseError::{InvalidDomain, InvalidHost};
+use crate::hosts::HostsLine;
+use crate::{Domain, HostsEntry, HostsFile, IPAddress, IPv6Address, LineParseError, ParseError};
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+impl HostsFile {
+    //! Parse
+
+    /// Parses the hosts file `text`, such as the contents of `/etc/hosts`.
+    ///
+    /// Each line is an IP address followed by its canonical name & aliases, separated by spaces or tabs, & a `#` starts
+    /// a comment. Blank & comment-only lines are kept but have no entry. Names are parsed with [`Domain::parse_text`],
+    /// so mixed-case names are normalized & names a [Domain] cannot hold (such as a trailing root dot) are an error.
+    /// An IPv6 zone (`fe80::1%lo0`) is accepted & ignored. Lines must be valid UTF-8, else the host is invalid.
+    pub fn parse_hosts(text: &[u8]) -> Result<Self, LineParseError> {
+        if text.is_empty() {
+            return Ok(Self::new());
+        }
+        let trailing_newline: bool = text.ends_with(b"\n");
+        let text: &[u8] = text.strip_suffix(b"\n").unwrap_or(text);
+
+        let mut lines: Vec<HostsLine> = Vec::new();
+        for (i, line) in text.split(|c| *c == b'\n').enumerate() {
+            let line: HostsLine = Self::parse_hosts_line(line).map_err(|error| LineParseError::new(i + 1, error))?;
+            lines.push(line);
+        }
+        Ok(Self::from_parts(lines, trailing_newline))
+    }
+
+    /// Parses the hosts file `line`.
+    fn parse_hosts_line(line: &[u8]) -> Result<HostsLine, ParseError> {
+        let text: &str = core::str::from_utf8(line).map_err(|_| InvalidHost)?;
+        let (content, comment): (&str, Option<&str>) = match text.split_once('#') {
+            Some((content, comment)) => (content, Some(comment.trim())),
+            None => (text, None),
+        };
+        let mut fields = content.split_ascii_whitespace();
+
+        let entry: Option<HostsEntry> = match fields.next() {
+            None => None,
+            Some(ip) => {
+                let ip: IPAddress = Self::parse_hosts_ip(ip)?;
+                let names: Vec<Domain> = fields
+                    .map(|name| Domain::parse_text(name.as_bytes()))
+                    .collect::<Result<Vec<Domain>, ParseError>>()?;
+                if names.is_empty() {
+                    return Err(InvalidDomain);
+                }
+                Some(HostsEntry::from_parts(ip, names, comment.map(str::to_string)))
+            }
+        };
+        Ok(HostsLine {
+            text: Some(text.to_string()),
+            entry,
+        })
+    }
+
+    /// Parses the `ip` field, ignoring an IPv6 zone.
+    fn parse_hosts_ip(ip: &str) -> Result<IPAddress, ParseError> {
+        match ip.split_once('%') {
+            Some((ip, zone)) if !zone.is_empty() => IPv6Address::parse_text(ip.as_bytes()).map(|ip| ip.to_ip()),
+            _ => IPAddress::parse_text(ip.as_bytes()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::{InvalidDomain, InvalidHost, InvalidIPAddress};
+    use crate::{Domain, HostsEntry, HostsFile, IPv4Address, IPv6Address, LineParseError};
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn parse_hosts() {
+        let text: &str = "\
+# Static table lookup for hostnames.
+
+127.0.0.1\tLocalHost\t# loopback
+::1 localhost example.com\r
+fe80::1%lo0 localhost
+";
+        let file: HostsFile = HostsFile::parse_hosts(text.as_bytes()).unwrap();
+        assert_eq!(file.line_count(), 5);
+
+        let entries: Vec<(usize, &HostsEntry)> = file.entries().collect();
+        let expected: &[(usize, HostsEntry)] = &[
+            (
+                3,
+                HostsEntry::new(IPv4Address::LOCALHOST.to_ip(), Domain::localhost()).with_comment("loopback"),
+            ),
+            (
+                4,
+                HostsEntry::new(IPv6Address::LOCALHOST.to_ip(), Domain::localhost()).with_alias(Domain::example()),
+            ),
+            (
+                5,
+                HostsEntry::new(
+                    IPv6Address::from_segments([0xFE80, 0, 0, 0, 0, 0, 0, 1]).to_ip(),
+                    Domain::localhost(),
+                ),
+            ),
+        ];
+        let expected: Vec<(usize, &HostsEntry)> = expected.iter().map(|(line, entry)| (*line, entry)).collect();
+        assert_eq!(entries, expected);
+    }
+
+    #[test]
+    fn parse_hosts_errors() {
+        let test_cases: &[(&[u8], LineParseError)] = &[
+            (b"127.0.0.1", LineParseError::new(1, InvalidDomain)),
+            (b"127.0.0.1 # localhost", LineParseError::new(1, InvalidDomain)),
+            (b"# ok\n127.0.0.1 local_host", LineParseError::new(2, InvalidDomain)),
+            (b"127.0.0.1 localhost.", LineParseError::new(1, InvalidDomain)),
+            (b"localhost 127.0.0.1", LineParseError::new(1, InvalidIPAddress)),
+            (b"127.0.0.1 \xFF", LineParseError::new(1, InvalidHost)),
+        ];
+
+        for (text, expected) in test_cases {
+            let result: Result<HostsFile, LineParseError> = HostsFile::parse_hosts(text);
+            assert_eq!(result, Err(*expected), "text={:?}", text);
+        }
+    }
+
+    /// Parsed files display as the exact same text, including comments, whitespace, & line endings.
+    #[test]
+    fn round_trip() {
+        let test_cases: &[&str] = &[
+            "",
+            "\n",
+            "127.0.0.1 localhost",
+            "# comment\n\n127.0.0.1\t LocalHost   alias # x\r\n::1 localhost\n",
+        ];
+
+        for text in test_cases {
+            let file: HostsFile = HostsFile::parse_hosts(text.as_bytes()).unwrap();
+            assert_eq!(file.to_string(), *text, "text={:?}", text);
+        }
+    }
+}
@@ -0,0 +1 @@
+mod hosts_file;
@@ -1,7 +1,7 @@
 /// Implements `FromStr` & `TryFrom<&str>` for an owned type, delegating to its byte-slice parser.
 macro_rules! impl_parse {
     ($ty:ident $(, $doc:expr)*) => {
-        impl ::std::str::FromStr for crate::$ty {
+        impl ::core::str::FromStr for crate::$ty {
             type Err = crate::ParseError;
 
             $(#[doc = $doc])*
@@ -24,13 +24,14 @@ macro_rules! impl_parse {
 /// Implements `TryFrom<String>` for an owned type, delegating to its byte-vector parser.
 ///
 /// The byte-vector parser must leave the value unmodified on failure, which is what makes the recovered `String` sound.
+#[cfg(feature = "alloc")]
 macro_rules! impl_parse_string {
     ($ty:ident $(, $doc:expr)*) => {
-        impl TryFrom<String> for crate::$ty {
-            type Error = crate::InvalidAddressError<String>;
+        impl TryFrom<::alloc::string::String> for crate::$ty {
+            type Error = crate::InvalidAddressError<::alloc::string::String>;
 
             $(#[doc = $doc])*
-            fn try_from(value: String) -> Result<Self, Self::Error> {
+            fn try_from(value: ::alloc::string::String) -> Result<Self, Self::Error> {
                 let len: usize = value.len();
                 Self::try_from(value.into_bytes()).map_err(|error| unsafe { error.into_string_unchecked(len) })
             }
@@ -54,4 +55,5 @@ macro_rules! impl_parse_ref {
 
 pub(crate) use impl_parse;
 pub(crate) use impl_parse_ref;
+#[cfg(feature = "alloc")]
 pub(crate) use impl_parse_string;
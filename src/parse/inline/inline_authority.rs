@@ -0,0 +1,89 @@
+use crate::ParseError::InvalidAuthority;
+use crate::{IPv6Address, InlineAuthority, InlineHost, ParseError, impl_parse, parse_port};
+
+impl InlineAuthority {
+    //! Parse
+
+    /// A host & a decimal port; an IPv6 host must be bracketed: `localhost:80` or `[::1]:80`.
+    /// Domain names are normalized to lowercase.
+    /// A numeric IPv6 zone is accepted & ignored: `[fe80::1%1]:80` parses as `[fe80::1]:80`.
+    pub const fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+        let (host, port): (&[u8], u16) = match parse_port(text) {
+            Ok(parsed) => parsed,
+            Err(error) => return Err(error),
+        };
+        match Self::parse_host(host) {
+            Ok(host) => Ok(Self::new(host, port)),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Parses the `host` text, the authority text before the port.
+    const fn parse_host(host: &[u8]) -> Result<InlineHost, ParseError> {
+        match IPv6Address::parse_bracketed(host) {
+            Some(Ok(ip)) => Ok(InlineHost::Address(ip.to_ip())),
+            Some(Err(error)) => Err(error),
+            None => match InlineHost::parse_text(host) {
+                Ok(InlineHost::Address(ip)) if ip.is_v6() => Err(InvalidAuthority),
+                result => result,
+            },
+        }
+    }
+}
+
+impl_parse!(
+    InlineAuthority,
+    "A host & a decimal port; an IPv6 host must be bracketed: `localhost:80` or `[::1]:80`.",
+    "Domain names are normalized to lowercase.",
+    "A numeric IPv6 zone is accepted & ignored: `[fe80::1%1]:80` parses as `[fe80::1]:80`."
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::{InvalidAuthority, InvalidHost, InvalidIPv6Address};
+    use crate::{IPv4Address, IPv6Address, InlineAuthority, InlineDomain, ParseError};
+
+    #[cfg(feature = "alloc")]
+    use crate::Authority;
+
+    #[test]
+    fn parse_text() {
+        let test_cases: &[(&[u8], Result<InlineAuthority, ParseError>)] = &[
+            ("LocalHost:80".as_bytes(), Ok((InlineDomain::LOCALHOST, 80).into())),
+            ("127.0.0.1:80".as_bytes(), Ok((IPv4Address::LOCALHOST, 80).into())),
+            ("[::1]:80".as_bytes(), Ok((IPv6Address::LOCALHOST, 80).into())),
+            (
+                "[fe80::1%1]:80".as_bytes(),
+                Ok((IPv6Address::from([0xFE80, 0, 0, 0, 0, 0, 0, 1]), 80).into()),
+            ),
+            ("::1:80".as_bytes(), Err(InvalidAuthority)),
+            ("[::1%eth0]:80".as_bytes(), Err(InvalidIPv6Address)),
+            ("-a:80".as_bytes(), Err(InvalidHost)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<InlineAuthority, ParseError> = InlineAuthority::parse_text(input);
+            assert_eq!(result, *expected, "input={:?}", input);
+        }
+    }
+
+    /// The inline parser accepts exactly what the heap-allocated parser accepts.
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn matches_authority() {
+        let inputs: &[&str] = &[
+            "Example.com:443",
+            "1.2.3.4:0",
+            "[::ffff:1.2.3.4]:1",
+            "a..b:1",
+            "[a]:1",
+            "a:65536",
+        ];
+
+        for input in inputs {
+            let inline: Result<InlineAuthority, ParseError> = input.parse();
+            let owned: Result<Authority, ParseError> = input.parse();
+            assert_eq!(inline.map(|a| a.to_authority()), owned, "input={}", input);
+        }
+    }
+}
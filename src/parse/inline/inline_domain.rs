@@ -0,0 +1,71 @@
+use crate::ParseError::InvalidDomain;
+use crate::{InlineDomain, NameClass, NameScan, ParseError, impl_parse};
+
+impl InlineDomain {
+    //! Parse
+
+    /// Dot-separated labels of ASCII letters, digits, & dashes. (see [`DomainRef`](crate::DomainRef))
+    /// The name is normalized to lowercase.
+    pub const fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+        let mut bytes: [u8; Self::CAPACITY] = [0; Self::CAPACITY];
+        match NameScan::of_lowercase(text, &mut bytes).class() {
+            NameClass::Invalid => Err(InvalidDomain),
+            _ => Ok(unsafe { Self::from_bytes_unchecked(bytes, text.len()) }),
+        }
+    }
+}
+
+impl_parse!(
+    InlineDomain,
+    "Dot-separated labels of ASCII letters, digits, & dashes. (see [`DomainRef`](crate::DomainRef))",
+    "The name is normalized to lowercase."
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::InvalidDomain;
+    use crate::{DomainRef, InlineDomain, ParseError};
+    use alloc::string::String;
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse_text() {
+        let test_cases: &[(&[u8], Result<&str, ParseError>)] = &[
+            ("localhost".as_bytes(), Ok("localhost")),
+            ("LocalHost".as_bytes(), Ok("localhost")),
+            ("Sub.Example.COM".as_bytes(), Ok("sub.example.com")),
+            ("".as_bytes(), Err(InvalidDomain)),
+            ("-a".as_bytes(), Err(InvalidDomain)),
+            (b"\xFF".as_slice(), Err(InvalidDomain)),
+            ("ü".as_bytes(), Err(InvalidDomain)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<InlineDomain, ParseError> = InlineDomain::parse_text(input);
+            assert_eq!(
+                result.map(|domain| domain.to_string()),
+                expected.map(str::to_string),
+                "input={:?}",
+                input
+            );
+        }
+    }
+
+    /// Names over the capacity are invalid domains, so they never reach the buffer.
+    #[test]
+    fn over_capacity() {
+        let name: String = ["a".repeat(63), "b".repeat(63), "c".repeat(63), "d".repeat(62)].join(".");
+        assert_eq!(name.len(), InlineDomain::CAPACITY + 1);
+
+        let result: Result<InlineDomain, ParseError> = name.parse();
+        assert_eq!(result, Err(InvalidDomain));
+    }
+
+    /// The parser runs in `const` contexts, so literals are validated at compile time.
+    #[test]
+    fn const_parse() {
+        const DOMAIN: Result<InlineDomain, ParseError> = InlineDomain::parse_text(b"Example.COM");
+        assert_eq!(DOMAIN, Ok(InlineDomain::EXAMPLE));
+        assert_eq!(DOMAIN.unwrap(), DomainRef::EXAMPLE);
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{InlineDomain, InlineEndpoint, ParseError, impl_parse, parse_port};
+
+impl InlineEndpoint {
+    //! Parse
+
+    /// A domain name & a decimal port: `localhost:80`.
+    /// Domain names are normalized to lowercase.
+    pub const fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+        let (name, port): (&[u8], u16) = match parse_port(text) {
+            Ok(parsed) => parsed,
+            Err(error) => return Err(error),
+        };
+        match InlineDomain::parse_text(name) {
+            Ok(domain) => Ok(Self::new(domain, port)),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+impl_parse!(
+    InlineEndpoint,
+    "A domain name & a decimal port: `localhost:80`.",
+    "Domain names are normalized to lowercase."
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::{InvalidDomain, InvalidPort};
+    use crate::{InlineDomain, InlineEndpoint, ParseError};
+
+    #[test]
+    fn parse_text() {
+        let test_cases: &[(&[u8], Result<InlineEndpoint, ParseError>)] = &[
+            (
+                "localhost:80".as_bytes(),
+                Ok(InlineEndpoint::new(InlineDomain::LOCALHOST, 80)),
+            ),
+            (
+                "LocalHost:80".as_bytes(),
+                Ok(InlineEndpoint::new(InlineDomain::LOCALHOST, 80)),
+            ),
+            ("localhost".as_bytes(), Err(InvalidPort)),
+            ("localhost:x".as_bytes(), Err(InvalidPort)),
+            ("[::1]:80".as_bytes(), Err(InvalidDomain)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<InlineEndpoint, ParseError> = InlineEndpoint::parse_text(input);
+            assert_eq!(result, *expected, "input={:?}", input);
+        }
+    }
+}
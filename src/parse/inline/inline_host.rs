@@ -0,0 +1,62 @@
+use crate::ParseError::InvalidHost;
+use crate::{IPv4Address, IPv6Address, InlineDomain, InlineHost, NameClass, NameScan, ParseError, impl_parse};
+
+impl InlineHost {
+    //! Parse
+
+    /// A domain name or an unbracketed IP address: `localhost`, `127.0.0.1`, or `::1`.
+    /// Domain names are normalized to lowercase.
+    ///
+    /// The text is scanned once as a domain name, which also tells which IP address version, if any, to parse: a
+    /// name of digits & dots may be an IPv4 address, & a name invalid at a colon may be an IPv6 address.
+    pub const fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+        let mut bytes: [u8; InlineDomain::CAPACITY] = [0; InlineDomain::CAPACITY];
+        let scan: NameScan = NameScan::of_lowercase(text, &mut bytes);
+        if let NameClass::Invalid = scan.class() {
+            if scan.may_be_ipv6()
+                && let Ok(ip) = IPv6Address::parse_text(text)
+            {
+                Ok(Self::Address(ip.to_ip()))
+            } else {
+                Err(InvalidHost)
+            }
+        } else if scan.may_be_ipv4()
+            && let Ok(ip) = IPv4Address::parse_text(text)
+        {
+            Ok(Self::Address(ip.to_ip()))
+        } else {
+            Ok(Self::Name(unsafe {
+                InlineDomain::from_bytes_unchecked(bytes, text.len())
+            }))
+        }
+    }
+}
+
+impl_parse!(
+    InlineHost,
+    "A domain name or an unbracketed IP address: `localhost`, `127.0.0.1`, or `::1`.",
+    "Domain names are normalized to lowercase."
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::InvalidHost;
+    use crate::{IPv4Address, IPv6Address, InlineDomain, InlineHost, ParseError};
+
+    #[test]
+    fn parse_text() {
+        let test_cases: &[(&[u8], Result<InlineHost, ParseError>)] = &[
+            ("localhost".as_bytes(), Ok(InlineHost::Name(InlineDomain::LOCALHOST))),
+            ("LocalHost".as_bytes(), Ok(InlineHost::Name(InlineDomain::LOCALHOST))),
+            ("127.0.0.1".as_bytes(), Ok(IPv4Address::LOCALHOST.into())),
+            ("::1".as_bytes(), Ok(IPv6Address::LOCALHOST.into())),
+            ("[::1]".as_bytes(), Err(InvalidHost)),
+            (b"\xFF".as_slice(), Err(InvalidHost)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<InlineHost, ParseError> = InlineHost::parse_text(input);
+            assert_eq!(result, *expected, "input={:?}", input);
+        }
+    }
+}
@@ -0,0 +1,4 @@
+mod inline_authority;
+mod inline_domain;
+mod inline_endpoint;
+mod inline_host;
@@ -1,5 +1,7 @@
 use crate::ParseError;
-use std::fmt::{Debug, Display, Formatter};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Debug, Display, Formatter};
 
 /// An error parsing an address that preserves the owned `value`.
 #[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
@@ -52,7 +54,7 @@ impl InvalidAddressError<Vec<u8>> {
     /// The value must be valid UTF-8.
     pub(crate) unsafe fn into_string_unchecked(self, len: usize) -> InvalidAddressError<String> {
         debug_assert_eq!(self.value.len(), len);
-        debug_assert!(std::str::from_utf8(self.value.as_slice()).is_ok());
+        debug_assert!(core::str::from_utf8(self.value.as_slice()).is_ok());
 
         InvalidAddressError::new(unsafe { String::from_utf8_unchecked(self.value) }, self.error)
     }
@@ -65,9 +67,9 @@ impl<T> From<InvalidAddressError<T>> for ParseError {
 }
 
 impl<T> Display for InvalidAddressError<T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         Display::fmt(&self.error, f)
     }
 }
 
-impl<T: Debug> std::error::Error for InvalidAddressError<T> {}
+impl<T: Debug> core::error::Error for InvalidAddressError<T> {}
@@ -5,7 +5,7 @@ impl IPAddress {
     //! Parse
 
     /// Parses the IP address text.
-    pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+    pub const fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
         if let Ok(ip) = IPv4Address::parse_text(text) {
             Ok(ip.to_ip())
         } else if let Ok(ip) = IPv6Address::parse_text(text) {
@@ -22,7 +22,8 @@ impl_parse!(IPAddress, "An IPv4 or an IPv6 address in the standard library synta
 mod tests {
     use crate::ParseError::InvalidIPAddress;
     use crate::{IPAddress, IPv4Address, IPv6Address, ParseError};
-    use std::str::FromStr;
+    use alloc::string::ToString;
+    use core::str::FromStr;
 
     #[test]
     fn parse() {
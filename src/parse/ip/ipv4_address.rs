@@ -1,7 +1,5 @@
 use crate::ParseError::InvalidIPv4Address;
 use crate::{IPv4Address, ParseError, impl_parse};
-use std::net::Ipv4Addr;
-use std::str::FromStr;
 
 impl IPv4Address {
     //! Parse
@@ -10,12 +8,68 @@ impl IPv4Address {
     const MAX_STR_LEN: usize = 15;
 
     /// Parses the IPv4 address text.
-    pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+    pub const fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
         if text.len() > Self::MAX_STR_LEN {
             return Err(InvalidIPv4Address);
         }
-        let text: &str = std::str::from_utf8(text).map_err(|_| InvalidIPv4Address)?;
-        Ok(Ipv4Addr::from_str(text).map_err(|_| InvalidIPv4Address)?.into())
+        match Self::read_octets(text, 0) {
+            Some((address, end)) if end == text.len() => Ok(Self::new(address)),
+            _ => Err(InvalidIPv4Address),
+        }
+    }
+
+    /// Reads the dotted-decimal address at the `start` of the `text`, with the octet rules of the standard library:
+    /// 1 to 3 decimal digits, no leading zeros, & at most 255. A leading zero ends its octet, so the next byte must
+    /// be the dot.
+    ///
+    /// Returns `(address, end)`, where the `end` follows the last octet. The text after the `end` is not read.
+    pub(crate) const fn read_octets(text: &[u8], start: usize) -> Option<([u8; 4], usize)> {
+        let mut address: [u8; 4] = [0; 4];
+        let mut i: usize = start;
+        let mut octet: usize = 0;
+        while octet < 4 {
+            if octet > 0 {
+                if i < text.len() && text[i] == b'.' {
+                    i += 1;
+                } else {
+                    return None;
+                }
+            }
+            let mut value: u16 = match Self::digit(text, i) {
+                Some(digit) => digit,
+                None => return None,
+            };
+            i += 1;
+            if value != 0 {
+                let mut count: usize = 1;
+                while count < 3
+                    && let Some(digit) = Self::digit(text, i)
+                {
+                    value = value * 10 + digit;
+                    i += 1;
+                    count += 1;
+                }
+                if value > u8::MAX as u16 {
+                    return None;
+                }
+            }
+            address[octet] = value as u8;
+            octet += 1;
+        }
+        Some((address, i))
+    }
+
+    /// Gets the value of the decimal digit at the `index` of the `text`.
+    ///
+    /// Returns `None` if the `index` is out of bounds or the byte is not a digit.
+    const fn digit(text: &[u8], index: usize) -> Option<u16> {
+        if index < text.len() {
+            let digit: u8 = text[index].wrapping_sub(b'0');
+            if digit <= 9 {
+                return Some(digit as u16);
+            }
+        }
+        None
     }
 }
 
@@ -27,8 +81,14 @@ impl_parse!(
 #[cfg(test)]
 mod tests {
     use crate::ParseError::InvalidIPv4Address;
+    use crate::test_util::Generator;
     use crate::{IPv4Address, ParseError};
-    use std::str::FromStr;
+    use alloc::string::String;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::net::Ipv4Addr;
+    use core::str::FromStr;
 
     #[test]
     fn parse() {
@@ -57,7 +117,7 @@ mod tests {
         }
     }
 
-    /// The length guard & the UTF-8 check run before the text is read as a string.
+    /// The length guard runs before the text is read, & non-ASCII bytes are never digits.
     #[test]
     fn parse_text_guards() {
         let over_max: Vec<u8> = vec![b'1'; IPv4Address::MAX_STR_LEN + 1];
@@ -79,4 +139,36 @@ mod tests {
             assert_eq!(value.to_string(), *input, "input={}", input);
         }
     }
+
+    /// The parser accepts exactly the inputs the standard library accepts, with the same values.
+    #[test]
+    fn matches_std() {
+        let pieces: &[&str] = &[
+            "0", "1", "9", "00", "01", "25", "255", "256", "999", "1000", ".", "..", "+", "-", " ",
+        ];
+        let alphabet: &[u8] = b"0123456789.+-: a";
+        let mut generator: Generator = Generator::new(0x4950_7634);
+
+        for _ in 0..100_000 {
+            let input: String = match generator.below(2) {
+                0 => generator.pieces(pieces),
+                _ => {
+                    let valid: String = Ipv4Addr::from(generator.next() as u32).to_string();
+                    generator.mutate(valid, alphabet)
+                }
+            };
+            let result: Option<Ipv4Addr> = IPv4Address::parse_text(input.as_bytes()).ok().map(IPv4Address::to_std);
+            assert_eq!(result, Ipv4Addr::from_str(input.as_str()).ok(), "input={}", input);
+        }
+    }
+
+    /// The parser runs in `const` contexts, so literals are validated at compile time.
+    #[test]
+    fn const_parse() {
+        const IP: Result<IPv4Address, ParseError> = IPv4Address::parse_text(b"127.0.0.1");
+        assert_eq!(IP, Ok(IPv4Address::LOCALHOST));
+
+        const INVALID: Result<IPv4Address, ParseError> = IPv4Address::parse_text(b"127.0.0.01");
+        assert_eq!(INVALID, Err(InvalidIPv4Address));
+    }
 }
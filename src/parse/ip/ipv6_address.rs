@@ -1,7 +1,5 @@
 use crate::ParseError::InvalidIPv6Address;
-use crate::{IPv6Address, ParseError, impl_parse};
-use std::net::Ipv6Addr;
-use std::str::FromStr;
+use crate::{IPv4Address, IPv6Address, ParseError, impl_parse};
 
 impl IPv6Address {
     //! Parse
@@ -9,13 +7,112 @@ impl IPv6Address {
     /// The maximum length of an IPv6 address string. (ffff:ffff:ffff:ffff:ffff:ffff:255.255.255.255)
     const MAX_STR_LEN: usize = 45;
 
+    /// The number of 16-bit groups in an IPv6 address.
+    const GROUP_COUNT: usize = 8;
+
+    /// The value of each byte as a hex digit, or `0xFF` if it is not a hex digit.
+    const HEX_DIGITS: [u8; 256] = {
+        let mut table: [u8; 256] = [0xFF; 256];
+        let mut i: u8 = 0;
+        while i < 10 {
+            table[(b'0' + i) as usize] = i;
+            i += 1;
+        }
+        let mut i: u8 = 0;
+        while i < 6 {
+            table[(b'a' + i) as usize] = 10 + i;
+            table[(b'A' + i) as usize] = 10 + i;
+            i += 1;
+        }
+        table
+    };
+
     /// Parses the IPv6 address text.
-    pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+    ///
+    /// The grammar matches the standard library: up to 8 groups of 1 to 4 hex digits, at most one `::` standing for
+    /// at least one zero group, & an optional trailing IPv4 address in place of the last 2 groups.
+    pub const fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
         if text.len() > Self::MAX_STR_LEN {
             return Err(InvalidIPv6Address);
         }
-        let text: &str = std::str::from_utf8(text).map_err(|_| InvalidIPv6Address)?;
-        Ok(Ipv6Addr::from_str(text).map_err(|_| InvalidIPv6Address)?.into())
+
+        let mut groups: [u16; 8] = [0; 8];
+        let (mut end, head_len, head_ipv4): (usize, usize, bool) =
+            Self::read_groups(text, 0, &mut groups, Self::GROUP_COUNT);
+        if head_len < Self::GROUP_COUNT {
+            if head_ipv4 || end + 1 >= text.len() || text[end] != b':' || text[end + 1] != b':' {
+                return Err(InvalidIPv6Address);
+            }
+            let mut tail: [u16; 8] = [0; 8];
+            let tail_len: usize;
+            (end, tail_len, _) = Self::read_groups(text, end + 2, &mut tail, Self::GROUP_COUNT - 1 - head_len);
+            let mut i: usize = 0;
+            while i < tail_len {
+                groups[Self::GROUP_COUNT - tail_len + i] = tail[i];
+                i += 1;
+            }
+        }
+        if end != text.len() {
+            return Err(InvalidIPv6Address);
+        }
+
+        let mut address: [u8; 16] = [0; 16];
+        let mut i: usize = 0;
+        while i < Self::GROUP_COUNT {
+            let [high, low]: [u8; 2] = groups[i].to_be_bytes();
+            address[2 * i] = high;
+            address[2 * i + 1] = low;
+            i += 1;
+        }
+        Ok(Self::new(address))
+    }
+
+    /// Reads up to `limit` colon-separated groups at the `start` of the `text` into the `groups`.
+    ///
+    /// A trailing IPv4 address is read as 2 groups when at least 2 groups remain; it is only tried for a group
+    /// followed by a dot, since its first octet also reads as a group. Reading stops before the first colon that is
+    /// not followed by a group, so the `::` of a compressed address is left unread.
+    ///
+    /// Returns `(end, group_count, ends_with_ipv4)`.
+    const fn read_groups(text: &[u8], start: usize, groups: &mut [u16; 8], limit: usize) -> (usize, usize, bool) {
+        let mut end: usize = start;
+        let mut count: usize = 0;
+        while count < limit {
+            let group: usize = if count == 0 {
+                end
+            } else if end < text.len() && text[end] == b':' {
+                end + 1
+            } else {
+                return (end, count, false);
+            };
+
+            let mut i: usize = group;
+            let mut value: u16 = 0;
+            while i < text.len() && i - group < 4 {
+                let digit: u8 = Self::HEX_DIGITS[text[i] as usize];
+                if digit > 0xF {
+                    break;
+                }
+                value = (value << 4) | digit as u16;
+                i += 1;
+            }
+            if i == group {
+                return (end, count, false);
+            }
+            if i < text.len()
+                && text[i] == b'.'
+                && count + 1 < limit
+                && let Some(([a, b, c, d], ipv4_end)) = IPv4Address::read_octets(text, group)
+            {
+                groups[count] = u16::from_be_bytes([a, b]);
+                groups[count + 1] = u16::from_be_bytes([c, d]);
+                return (ipv4_end, count + 2, true);
+            }
+            groups[count] = value;
+            count += 1;
+            end = i;
+        }
+        (end, count, false)
     }
 
     /// Parses the bracketed IPv6 address text, ignoring an optional numeric zone.
@@ -23,12 +120,13 @@ impl IPv6Address {
     /// Returns `None` if the address is not bracketed. A bracketed address with an invalid interior, the zone
     /// included, is `Some(Err(InvalidIPv6Address))`: the brackets declare the version, so the error blames the
     /// IPv6 address rather than the caller's own variant.
-    pub(crate) fn parse_bracketed(text: &[u8]) -> Option<Result<Self, ParseError>> {
-        let text: &[u8] = Self::strip_brackets(text)?;
-        if let Some(text) = Self::strip_zone(text) {
-            Some(Self::parse_text(text))
-        } else {
-            Some(Err(InvalidIPv6Address))
+    pub(crate) const fn parse_bracketed(text: &[u8]) -> Option<Result<Self, ParseError>> {
+        match Self::strip_brackets(text) {
+            Some(text) => match Self::strip_zone(text) {
+                Some(text) => Some(Self::parse_text(text)),
+                None => Some(Err(InvalidIPv6Address)),
+            },
+            None => None,
         }
     }
 
@@ -41,9 +139,10 @@ impl IPv6Address {
     /// `[]`      -> `Some("")`
     /// `::1`     -> `None`
     /// `[::1`    -> `None`
-    fn strip_brackets(text: &[u8]) -> Option<&[u8]> {
-        if !text.is_empty() && text[0] == b'[' && text[text.len() - 1] == b']' {
-            Some(&text[1..text.len() - 1])
+    const fn strip_brackets(text: &[u8]) -> Option<&[u8]> {
+        if text.len() >= 2 && text[0] == b'[' && text[text.len() - 1] == b']' {
+            let (_, text): (&[u8], &[u8]) = text.split_at(1);
+            Some(text.split_at(text.len() - 1).0)
         } else {
             None
         }
@@ -55,25 +154,36 @@ impl IPv6Address {
     /// zeros are allowed, matching the scope ids accepted by the standard library socket parser. Returns `None` if
     /// the zone is invalid.
     ///
-    /// The digit check runs first, so the zone is known to be ASCII before it is read as a string.
-    ///
     /// # Examples
     /// `fe80::1%1` -> `Some("fe80::1")`
     /// `fe80::1`   -> `Some("fe80::1")`
     /// `fe80::1%`  -> `None`
-    fn strip_zone(text: &[u8]) -> Option<&[u8]> {
-        if let Some(percent) = text.iter().position(|c| *c == b'%') {
-            let zone: &[u8] = &text[percent + 1..];
-            let valid: bool = !zone.is_empty() && zone.iter().all(|c| c.is_ascii_digit());
-            if !valid {
+    const fn strip_zone(text: &[u8]) -> Option<&[u8]> {
+        let mut percent: usize = 0;
+        while percent < text.len() && text[percent] != b'%' {
+            percent += 1;
+        }
+        if percent == text.len() {
+            return Some(text);
+        }
+
+        let (address, zone): (&[u8], &[u8]) = text.split_at(percent);
+        if zone.len() < 2 {
+            return None;
+        }
+        let mut zone_id: u64 = 0;
+        let mut i: usize = 1;
+        while i < zone.len() {
+            if !zone[i].is_ascii_digit() {
                 return None;
             }
-            let zone: &str = unsafe { std::str::from_utf8_unchecked(zone) };
-            let _: u32 = u32::from_str(zone).ok()?;
-            Some(&text[..percent])
-        } else {
-            Some(text)
+            zone_id = zone_id * 10 + (zone[i] - b'0') as u64;
+            if zone_id > u32::MAX as u64 {
+                return None;
+            }
+            i += 1;
         }
+        Some(address)
     }
 }
 
@@ -86,8 +196,15 @@ impl_parse!(
 #[cfg(test)]
 mod tests {
     use crate::ParseError::InvalidIPv6Address;
+    use crate::test_util::Generator;
     use crate::{IPv6Address, ParseError};
-    use std::str::FromStr;
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::net::{Ipv4Addr, Ipv6Addr};
+    use core::str::FromStr;
 
     #[test]
     fn parse() {
@@ -161,7 +278,7 @@ mod tests {
         }
     }
 
-    /// The length guard & the UTF-8 check run before the text is read as a string.
+    /// The length guard runs before the text is read, & non-ASCII bytes are never digits.
     #[test]
     fn parse_text_guards() {
         let over_max: Vec<u8> = vec![b'0'; IPv6Address::MAX_STR_LEN + 1];
@@ -194,4 +311,53 @@ mod tests {
             assert_eq!(value.to_string(), *input, "input={}", input);
         }
     }
+
+    /// The parser accepts exactly the inputs the standard library accepts, with the same values.
+    #[test]
+    fn matches_std() {
+        let pieces: &[&str] = &[
+            "0", "1", "00", "0000", "00000", "ffff", "FFFF", "abcd", "g", ":", "::", ":::", ".", "1.2.3.4", "255",
+            "256", "01", "%", "[", "]", " ",
+        ];
+        let alphabet: &[u8] = b"0123456789abcdefABCDEFg:.%[] ";
+        let mut generator: Generator = Generator::new(0x4950_7636);
+
+        for _ in 0..100_000 {
+            let input: String = match generator.below(3) {
+                0 => generator.pieces(pieces),
+                1 => {
+                    let valid: String = random_address(&mut generator).to_string();
+                    generator.mutate(valid, alphabet)
+                }
+                _ => {
+                    let ip: Ipv4Addr = Ipv4Addr::from(generator.next() as u32);
+                    let prefix: &str = ["::", "::ffff:", "1::", "1:2:3:4:5:6:", "1:2:3:4:5:6:7:"][generator.below(5)];
+                    generator.mutate(format!("{}{}", prefix, ip), alphabet)
+                }
+            };
+            let result: Option<Ipv6Addr> = IPv6Address::parse_text(input.as_bytes()).ok().map(IPv6Address::to_std);
+            assert_eq!(result, Ipv6Addr::from_str(input.as_str()).ok(), "input={}", input);
+        }
+    }
+
+    /// Generates an address whose groups are mostly zero, so the displayed form exercises the `::` compression.
+    fn random_address(generator: &mut Generator) -> Ipv6Addr {
+        let mut groups: [u16; 8] = [0; 8];
+        for group in groups.iter_mut() {
+            if generator.below(2) == 0 {
+                *group = generator.next() as u16;
+            }
+        }
+        Ipv6Addr::from(groups)
+    }
+
+    /// The parser runs in `const` contexts, so literals are validated at compile time.
+    #[test]
+    fn const_parse() {
+        const IP: Result<IPv6Address, ParseError> = IPv6Address::parse_text(b"::ffff:1.2.3.4");
+        assert_eq!(IP, Ok(IPv6Address::from([0, 0, 0, 0, 0, 0xFFFF, 0x0102, 0x0304])));
+
+        const INVALID: Result<IPv6Address, ParseError> = IPv6Address::parse_text(b"1::2::3");
+        assert_eq!(INVALID, Err(InvalidIPv6Address));
+    }
 }
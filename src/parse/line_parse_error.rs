@@ -0,0 +1,61 @@
+use crate::ParseError;
+use core::fmt::{Display, Formatter};
+
+/// An error parsing a line of a configuration file.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct LineParseError {
+    line: usize,
+    error: ParseError,
+}
+
+impl LineParseError {
+    //! Construction
+
+    /// Creates a new [LineParseError].
+    pub(crate) const fn new(line: usize, error: ParseError) -> Self {
+        Self { line, error }
+    }
+}
+
+impl LineParseError {
+    //! Properties
+
+    /// Gets the 1-based line number.
+    #[must_use]
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Gets the parse error.
+    #[must_use]
+    pub const fn error(&self) -> ParseError {
+        self.error
+    }
+}
+
+impl From<LineParseError> for ParseError {
+    fn from(error: LineParseError) -> Self {
+        error.error
+    }
+}
+
+impl Display for LineParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.error)
+    }
+}
+
+impl core::error::Error for LineParseError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::LineParseError;
+    use crate::ParseError::InvalidPort;
+    use alloc::string::ToString;
+
+    #[test]
+    fn display() {
+        let error: LineParseError = LineParseError::new(3, InvalidPort);
+        assert_eq!(error.to_string(), "line 3: invalid port");
+    }
+}
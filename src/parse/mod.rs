@@ -1,18 +1,48 @@
+#[cfg(feature = "alloc")]
 pub use invalid_address_error::*;
+#[cfg(feature = "alloc")]
+pub use line_parse_error::*;
 pub use parse_error::*;
 
 pub(crate) use domain::*;
 pub(crate) use impl_parse::*;
 pub(crate) use parse_port::*;
+#[cfg(feature = "alloc")]
+pub(crate) use percent::*;
 
 mod domain;
 mod impl_parse;
+#[cfg(feature = "alloc")]
 mod invalid_address_error;
+#[cfg(feature = "alloc")]
+mod line_parse_error;
 mod parse_error;
 mod parse_port;
+#[cfg(feature = "alloc")]
+mod percent;
 
+mod address;
 mod authority;
+#[cfg(feature = "alloc")]
+mod cow;
 mod endpoint;
 mod host;
+mod host_port;
+#[cfg(feature = "alloc")]
+mod hosts;
+mod inline;
 mod ip;
+#[cfg(feature = "alloc")]
+mod origin;
+mod port;
+#[cfg(feature = "alloc")]
+mod record;
+#[cfg(feature = "alloc")]
+mod resolv_conf;
+#[cfg(feature = "alloc")]
+mod service;
 mod socket;
+#[cfg(feature = "alloc")]
+mod uri;
+#[cfg(feature = "idna")]
+mod whatwg;
@@ -0,0 +1,2 @@
+mod origin;
+mod scheme;
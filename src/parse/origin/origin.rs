@@ -0,0 +1,151 @@
+use crate::ParseError::{InvalidOrigin, InvalidPort};
+use crate::{HostPort, InvalidAddressError, Origin, ParseError, Scheme, impl_parse, impl_parse_string};
+use alloc::vec::Vec;
+
+impl Origin {
+    //! Parse
+
+    /// A scheme, `://`, a host, & an optional decimal port: `https://example.com` or `http://[::1]:8080`.
+    /// A missing port is the scheme's default port; a scheme without a known default port requires one.
+    /// Schemes & domain names are normalized to lowercase. Paths, queries, & user-info are invalid.
+    pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+        let separator: usize = text.windows(3).position(|w| w == b"://").ok_or(InvalidOrigin)?;
+        let scheme: Scheme = Scheme::parse_text(&text[..separator])?;
+        let host_port: HostPort = HostPort::parse_text(&text[separator + 3..])?;
+        let port: u16 = host_port.port().or(scheme.default_port()).ok_or(InvalidPort)?;
+        Ok(Self::new(scheme, host_port.to_authority(port)))
+    }
+}
+
+impl_parse!(
+    Origin,
+    "A scheme, `://`, a host, & an optional decimal port: `https://example.com` or `http://[::1]:8080`.",
+    "A missing port is the scheme's default port; a scheme without a known default port requires one.",
+    "Schemes & domain names are normalized to lowercase. Paths, queries, & user-info are invalid."
+);
+
+impl_parse_string!(
+    Origin,
+    "A scheme, `://`, a host, & an optional decimal port: `https://example.com` or `http://[::1]:8080`.",
+    "A missing port is the scheme's default port; a scheme without a known default port requires one.",
+    "Schemes & domain names are normalized to lowercase. Paths, queries, & user-info are invalid."
+);
+
+impl TryFrom<Vec<u8>> for Origin {
+    type Error = InvalidAddressError<Vec<u8>>;
+
+    /// A scheme, `://`, a host, & an optional decimal port: `https://example.com` or `http://[::1]:8080`.
+    /// A missing port is the scheme's default port; a scheme without a known default port requires one.
+    /// Schemes & domain names are normalized to lowercase. Paths, queries, & user-info are invalid.
+    /// The error contains the unmodified `text`, which `TryFrom<String>` soundly recovers as a string.
+    fn try_from(text: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::parse_text(text.as_slice()).map_err(|error| InvalidAddressError::new(text, error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::{InvalidAuthority, InvalidHost, InvalidOrigin, InvalidPort, InvalidScheme};
+    use crate::{Domain, IPv6Address, Origin, ParseError, Scheme};
+    use alloc::string::ToString;
+    use core::str::FromStr;
+
+    #[test]
+    fn parse() {
+        let x_custom: Scheme = unsafe { Scheme::new_unchecked("x-custom") };
+        let test_cases: &[(&str, Result<Origin, ParseError>)] = &[
+            ("", Err(InvalidOrigin)),
+            ("example.com", Err(InvalidOrigin)),
+            ("https:example.com", Err(InvalidOrigin)),
+            ("://example.com", Err(InvalidScheme)),
+            (
+                "https://example.com",
+                Ok(Origin::new(
+                    Scheme::https(),
+                    Domain::example().to_host().to_authority(443),
+                )),
+            ),
+            (
+                "HTTPS://Example.COM",
+                Ok(Origin::new(
+                    Scheme::https(),
+                    Domain::example().to_host().to_authority(443),
+                )),
+            ),
+            (
+                "https://example.com:443",
+                Ok(Origin::new(
+                    Scheme::https(),
+                    Domain::example().to_host().to_authority(443),
+                )),
+            ),
+            (
+                "https://example.com:8443",
+                Ok(Origin::new(
+                    Scheme::https(),
+                    Domain::example().to_host().to_authority(8443),
+                )),
+            ),
+            (
+                "http://[::1]:8080",
+                Ok(Origin::new(
+                    Scheme::http(),
+                    IPv6Address::LOCALHOST.to_host().to_authority(8080),
+                )),
+            ),
+            ("x-custom://example.com", Err(InvalidPort)),
+            (
+                "x-custom://example.com:9000",
+                Ok(Origin::new(x_custom, Domain::example().to_host().to_authority(9000))),
+            ),
+            ("https://", Err(InvalidHost)),
+            ("https://example.com/", Err(InvalidHost)),
+            ("https://user@example.com", Err(InvalidHost)),
+            ("https://example.com:", Err(InvalidPort)),
+            ("http://::1", Err(InvalidHost)),
+            ("http://::1:80", Err(InvalidAuthority)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<Origin, ParseError> = Origin::from_str(input);
+            assert_eq!(result, *expected, "input={}", input);
+
+            let result: Result<Origin, ParseError> = Origin::try_from(input.to_string()).map_err(|e| e.error());
+            assert_eq!(result, *expected, "input={}", input);
+        }
+    }
+
+    /// Each canonical string must parse and display back to the exact same string.
+    #[test]
+    fn round_trip() {
+        let canonical: &[&str] = &[
+            "https://example.com",
+            "http://localhost:8080",
+            "wss://example.com",
+            "postgres://127.0.0.1:5433",
+            "http://[::1]",
+            "x-custom://example.com:9000",
+        ];
+
+        for input in canonical {
+            let value: Origin = Origin::from_str(input).unwrap();
+            assert_eq!(value.to_string(), *input, "input={}", input);
+        }
+    }
+
+    /// Explicit default ports are dropped from the serialization.
+    #[test]
+    fn normalization() {
+        let test_cases: &[(&str, &str)] = &[
+            ("HTTPS://Example.com:443", "https://example.com"),
+            ("http://localhost:80", "http://localhost"),
+            ("redis://127.0.0.1:6379", "redis://127.0.0.1"),
+            ("http://localhost:443", "http://localhost:443"),
+        ];
+
+        for (input, expected) in test_cases {
+            let value: Origin = Origin::from_str(input).unwrap();
+            assert_eq!(value.to_string(), *expected, "input={}", input);
+        }
+    }
+}
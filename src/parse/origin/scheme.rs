@@ -0,0 +1,120 @@
+use crate::ParseError::InvalidScheme;
+use crate::{InvalidAddressError, ParseError, Scheme, impl_parse, impl_parse_string};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+impl Scheme {
+    //! Validation
+
+    /// Checks if the `name` is a valid scheme: a letter followed by letters, digits, `+`, `-`, or `.`.
+    pub(crate) fn is_valid_name(name: &[u8]) -> bool {
+        match name.split_first() {
+            Some((first, rest)) => {
+                first.is_ascii_alphabetic()
+                    && rest
+                        .iter()
+                        .all(|c| c.is_ascii_alphanumeric() || matches!(c, b'+' | b'-' | b'.'))
+            }
+            None => false,
+        }
+    }
+}
+
+impl Scheme {
+    //! Parse
+
+    /// A letter followed by letters, digits, `+`, `-`, or `.`: `https` or `svn+ssh`.
+    /// Schemes are normalized to lowercase.
+    pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+        if Self::is_valid_name(text) {
+            let name: String = unsafe { core::str::from_utf8_unchecked(text) }.to_ascii_lowercase();
+            Ok(unsafe { Self::new_unchecked(name) })
+        } else {
+            Err(InvalidScheme)
+        }
+    }
+}
+
+impl_parse!(
+    Scheme,
+    "A letter followed by letters, digits, `+`, `-`, or `.`: `https` or `svn+ssh`.",
+    "Schemes are normalized to lowercase."
+);
+
+impl_parse_string!(
+    Scheme,
+    "A letter followed by letters, digits, `+`, `-`, or `.`: `https` or `svn+ssh`.",
+    "Schemes are normalized to lowercase."
+);
+
+impl TryFrom<Vec<u8>> for Scheme {
+    type Error = InvalidAddressError<Vec<u8>>;
+
+    /// A letter followed by letters, digits, `+`, `-`, or `.`: `https` or `svn+ssh`.
+    /// Schemes are normalized to lowercase.
+    /// The error contains the unmodified `text`, which `TryFrom<String>` soundly recovers as a string.
+    fn try_from(mut text: Vec<u8>) -> Result<Self, Self::Error> {
+        if Self::is_valid_name(text.as_slice()) {
+            text.make_ascii_lowercase();
+            let name: String = unsafe { String::from_utf8_unchecked(text) };
+            Ok(unsafe { Self::new_unchecked(name) })
+        } else {
+            Err(InvalidAddressError::new(text, InvalidScheme))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::InvalidScheme;
+    use crate::{InvalidAddressError, ParseError, Scheme};
+    use alloc::string::String;
+    use alloc::string::ToString;
+    use core::str::FromStr;
+
+    #[test]
+    fn parse() {
+        let test_cases: &[(&str, Result<&str, ParseError>)] = &[
+            ("", Err(InvalidScheme)),
+            ("https", Ok("https")),
+            ("HTTPS", Ok("https")),
+            ("svn+ssh", Ok("svn+ssh")),
+            ("x-custom.v1", Ok("x-custom.v1")),
+            ("h2", Ok("h2")),
+            ("2h", Err(InvalidScheme)),
+            ("+ssh", Err(InvalidScheme)),
+            ("http:", Err(InvalidScheme)),
+            ("ht tp", Err(InvalidScheme)),
+            ("héllo", Err(InvalidScheme)),
+        ];
+
+        for (input, expected) in test_cases {
+            let expected: Result<Scheme, ParseError> = expected.map(|name| unsafe { Scheme::new_unchecked(name) });
+
+            let result: Result<Scheme, ParseError> = Scheme::from_str(input);
+            assert_eq!(result, expected, "input={}", input);
+
+            let result: Result<Scheme, ParseError> = Scheme::try_from(*input);
+            assert_eq!(result, expected, "input={}", input);
+
+            let result: Result<Scheme, InvalidAddressError<String>> = Scheme::try_from(input.to_string());
+            match result {
+                Ok(result) => assert_eq!(Ok(result), expected, "input={}", input),
+                Err(error) => {
+                    assert_eq!(Err(error.error()), expected, "input={}", input);
+                    assert_eq!(error.value(), *input, "input={}", input);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let canonical: &[&str] = &["http", "https", "svn+ssh"];
+
+        for input in canonical {
+            let value: Scheme = Scheme::from_str(input).unwrap();
+            assert_eq!(value.to_string(), *input, "input={}", input);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::fmt::{Display, Formatter};
+use core::fmt::{Display, Formatter};
 
 /// An error parsing an address.
 ///
@@ -29,15 +29,39 @@ pub enum ParseError {
     /// The port is missing or invalid.
     InvalidPort,
 
+    /// The port range is invalid. (the start must not exceed the end)
+    InvalidPortRange,
+
     /// The host is invalid. (neither an IP address nor a domain)
     InvalidHost,
 
     /// The authority is invalid. (an IPv6 host must be bracketed)
     InvalidAuthority,
+
+    /// The address is invalid. (neither an IP address, a socket address, a domain, nor an endpoint)
+    InvalidAddress,
+
+    /// The URI scheme is invalid. (a letter followed by letters, digits, `+`, `-`, or `.`)
+    InvalidScheme,
+
+    /// The origin is invalid. (the scheme must be followed by `://`)
+    InvalidOrigin,
+
+    /// The URI user-info is invalid. (it must be percent-encoded & decode to UTF-8)
+    InvalidUserInfo,
+
+    /// The service entry is invalid. (see [`ServiceRegistry`](crate::ServiceRegistry))
+    InvalidService,
+
+    /// The resolver option is invalid. (see [`ResolverOptions`](crate::ResolverOptions))
+    InvalidResolverOption,
+
+    /// The DNS record is invalid. (see [`SrvRecord`](crate::SrvRecord) & [`MxRecord`](crate::MxRecord))
+    InvalidRecord,
 }
 
 impl Display for ParseError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         let s: &str = match self {
             Self::InvalidDomain => "invalid domain",
             Self::InvalidIPAddress => "invalid IP address",
@@ -46,11 +70,19 @@ impl Display for ParseError {
             Self::InvalidSocketAddress => "invalid socket address",
             Self::InvalidSocketAddressV6 => "invalid IPv6 socket address",
             Self::InvalidPort => "invalid port",
+            Self::InvalidPortRange => "invalid port range",
             Self::InvalidHost => "invalid host",
             Self::InvalidAuthority => "invalid authority",
+            Self::InvalidAddress => "invalid address",
+            Self::InvalidScheme => "invalid scheme",
+            Self::InvalidOrigin => "invalid origin",
+            Self::InvalidUserInfo => "invalid user-info",
+            Self::InvalidService => "invalid service",
+            Self::InvalidResolverOption => "invalid resolver option",
+            Self::InvalidRecord => "invalid record",
         };
         f.pad(s)
     }
 }
 
-impl std::error::Error for ParseError {}
+impl core::error::Error for ParseError {}
@@ -1,6 +1,7 @@
-use crate::ParseError;
 use crate::ParseError::InvalidPort;
-use std::str::FromStr;
+use crate::{ParseError, PortRange};
+#[cfg(feature = "alloc")]
+use crate::{ServiceRegistry, TransportProtocol};
 
 /// Parses the port from the `text`.
 ///
@@ -17,28 +18,110 @@ use std::str::FromStr;
 /// `:8x`          -> `Err(InvalidPort)`
 /// `:+80`         -> `Err(InvalidPort)`
 /// `80`           -> `Err(InvalidPort)`
-pub(crate) fn parse_port(text: &[u8]) -> Result<(&[u8], u16), ParseError> {
+pub(crate) const fn parse_port(text: &[u8]) -> Result<(&[u8], u16), ParseError> {
+    let mut colon: usize = text.len();
+    while colon > 0 {
+        colon -= 1;
+        if text[colon] == b':' {
+            let (text, port): (&[u8], &[u8]) = text.split_at(colon);
+            return match parse_port_number(port.split_at(1).1) {
+                Ok(port) => Ok((text, port)),
+                Err(error) => Err(error),
+            };
+        }
+    }
+    Err(InvalidPort)
+}
+
+/// Parses the port from the `text`, accepting a service name from the `services` as well as a decimal port.
+///
+/// Returns `(text_without_last_colon, port)`.
+///
+/// The port is decimal when it is all digits; otherwise it is looked up as a service name for the `protocol`.
+///
+/// # Examples
+/// `localhost:80`    -> `Ok(("localhost", 80))`
+/// `localhost:https` -> `Ok(("localhost", 443))` (with the IANA services)
+/// `localhost:nope`  -> `Err(InvalidPort)`
+#[cfg(feature = "alloc")]
+pub(crate) fn parse_service_port<'a>(
+    text: &'a [u8],
+    services: &ServiceRegistry,
+    protocol: TransportProtocol,
+) -> Result<(&'a [u8], u16), ParseError> {
     if let Some(colon) = text.iter().rposition(|c| *c == b':') {
         let port: &[u8] = &text[colon + 1..];
-        let valid: bool = !port.is_empty() && port.iter().all(|c| c.is_ascii_digit());
-        if !valid {
-            return Err(InvalidPort);
-        }
-        let port: &str = unsafe { std::str::from_utf8_unchecked(port) };
-        let port: u16 = u16::from_str(port).map_err(|_| InvalidPort)?;
+        let port: u16 = if port.iter().all(|c| c.is_ascii_digit()) {
+            parse_port_number(port)?
+        } else {
+            let name: &str = core::str::from_utf8(port).map_err(|_| InvalidPort)?;
+            services.port(name, protocol).ok_or(InvalidPort)?.value()
+        };
         Ok((&text[..colon], port))
     } else {
         Err(InvalidPort)
     }
 }
 
+/// Parses the port range from the `text`.
+///
+/// Returns `(text_without_last_colon, range)`.
+///
+/// The range syntax matches [`PortRange::parse_text`].
+///
+/// # Examples
+/// `localhost:80`        -> `Ok(("localhost", 80-80))`
+/// `localhost:8000-8010` -> `Ok(("localhost", 8000-8010))`
+/// `localhost:90-80`     -> `Err(InvalidPortRange)`
+/// `8000-8010`           -> `Err(InvalidPort)`
+pub(crate) fn parse_port_range(text: &[u8]) -> Result<(&[u8], PortRange), ParseError> {
+    if let Some(colon) = text.iter().rposition(|c| *c == b':') {
+        let range: PortRange = PortRange::parse_text(&text[colon + 1..])?;
+        Ok((&text[..colon], range))
+    } else {
+        Err(InvalidPort)
+    }
+}
+
+/// Parses the bare decimal `port` number, with the digit rules of [parse_port].
+///
+/// # Examples
+/// `80`  -> `Ok(80)`
+/// `080` -> `Ok(80)`
+/// `8x`  -> `Err(InvalidPort)`
+/// ``    -> `Err(InvalidPort)`
+pub(crate) const fn parse_port_number(port: &[u8]) -> Result<u16, ParseError> {
+    if port.is_empty() {
+        return Err(InvalidPort);
+    }
+    let mut value: u32 = 0;
+    let mut i: usize = 0;
+    while i < port.len() {
+        let c: u8 = port[i];
+        if !c.is_ascii_digit() {
+            return Err(InvalidPort);
+        }
+        value = value * 10 + (c - b'0') as u32;
+        if value > u16::MAX as u32 {
+            return Err(InvalidPort);
+        }
+        i += 1;
+    }
+    Ok(value as u16)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::ParseError;
-    use crate::ParseError::InvalidPort;
-    use crate::parse_port;
+    use crate::ParseError::{InvalidPort, InvalidPortRange};
+    use crate::{ParseError, Port, PortRange, parse_port, parse_port_number, parse_port_range};
+
+    #[cfg(feature = "alloc")]
+    use crate::TransportProtocol::Tcp;
+    #[cfg(feature = "alloc")]
+    use crate::{ServiceRegistry, parse_service_port};
 
     type TestCase<'a> = (&'a str, Result<(&'a str, u16), ParseError>);
+    type RangeTestCase<'a> = (&'a str, Result<(&'a str, u16, u16), ParseError>);
 
     #[test]
     fn ports() {
@@ -85,4 +168,73 @@ mod tests {
             assert_eq!(result, Err(InvalidPort), "input={:?}", input);
         }
     }
+
+    #[test]
+    fn port_numbers() {
+        let test_cases: &[(&str, Result<u16, ParseError>)] = &[
+            ("", Err(InvalidPort)),
+            ("80", Ok(80)),
+            ("080", Ok(80)),
+            ("65535", Ok(65535)),
+            ("65536", Err(InvalidPort)),
+            ("+80", Err(InvalidPort)),
+            ("8x", Err(InvalidPort)),
+            (":80", Err(InvalidPort)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<u16, ParseError> = parse_port_number(input.as_bytes());
+            assert_eq!(result, *expected, "input={}", input);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn service_ports() {
+        let services: ServiceRegistry = ServiceRegistry::iana();
+        let test_cases: &[TestCase] = &[
+            ("", Err(InvalidPort)),
+            ("localhost:80", Ok(("localhost", 80))),
+            ("localhost:https", Ok(("localhost", 443))),
+            ("localhost:HTTP", Ok(("localhost", 80))),
+            ("[::1]:ssh", Ok(("[::1]", 22))),
+            ("localhost:", Err(InvalidPort)),
+            ("localhost:nope", Err(InvalidPort)),
+            ("localhost:99999", Err(InvalidPort)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<(&[u8], u16), ParseError> = parse_service_port(input.as_bytes(), &services, Tcp);
+            let expected: Result<(&[u8], u16), ParseError> = match expected {
+                Ok((s, port)) => Ok((s.as_bytes(), *port)),
+                Err(error) => Err(*error),
+            };
+            assert_eq!(result, expected, "input={}", input);
+        }
+    }
+
+    #[test]
+    fn port_ranges() {
+        let test_cases: &[RangeTestCase] = &[
+            ("", Err(InvalidPort)),
+            ("8000-8010", Err(InvalidPort)),
+            ("localhost:80", Ok(("localhost", 80, 80))),
+            ("localhost:8000-8010", Ok(("localhost", 8000, 8010))),
+            ("[::1]:0-65535", Ok(("[::1]", 0, 65535))),
+            ("localhost:90-80", Err(InvalidPortRange)),
+            ("localhost:80-", Err(InvalidPort)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<(&[u8], PortRange), ParseError> = parse_port_range(input.as_bytes());
+            let expected: Result<(&[u8], PortRange), ParseError> = match expected {
+                Ok((s, start, end)) => Ok((
+                    s.as_bytes(),
+                    PortRange::new(Port::new(*start), Port::new(*end)).unwrap(),
+                )),
+                Err(error) => Err(*error),
+            };
+            assert_eq!(result, expected, "input={}", input);
+        }
+    }
 }
@@ -0,0 +1,110 @@
+use alloc::vec::Vec;
+/// Checks if the byte is unreserved in a URI: a letter, a digit, `-`, `.`, `_`, or `~`.
+pub(crate) const fn is_unreserved(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, b'-' | b'.' | b'_' | b'~')
+}
+
+/// Checks if the byte is a URI sub-delimiter: `!`, `$`, `&`, `'`, `(`, `)`, `*`, `+`, `,`, `;`, or `=`.
+pub(crate) const fn is_sub_delim(c: u8) -> bool {
+    matches!(
+        c,
+        b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b'='
+    )
+}
+
+/// Checks if the `text` is percent-encoded: each byte is `allowed` or starts a `%` triplet with 2 hex digits.
+pub(crate) fn is_percent_encoded(text: &[u8], allowed: fn(u8) -> bool) -> bool {
+    let mut i: usize = 0;
+    while i < text.len() {
+        if text[i] == b'%' {
+            let valid: bool = i + 2 < text.len() && text[i + 1].is_ascii_hexdigit() && text[i + 2].is_ascii_hexdigit();
+            if !valid {
+                return false;
+            }
+            i += 3;
+        } else if allowed(text[i]) {
+            i += 1;
+        } else {
+            return false;
+        }
+    }
+    true
+}
+
+/// Decodes the percent-encoded `text`.
+///
+/// The `text` must be valid per [is_percent_encoded]; a malformed triplet is copied through unchanged.
+pub(crate) fn percent_decode(text: &[u8]) -> Vec<u8> {
+    let mut decoded: Vec<u8> = Vec::with_capacity(text.len());
+    let mut i: usize = 0;
+    while i < text.len() {
+        let triplet: Option<u8> = if text[i] == b'%' && i + 2 < text.len() {
+            hex_value(text[i + 1])
+                .zip(hex_value(text[i + 2]))
+                .map(|(hi, lo)| hi << 4 | lo)
+        } else {
+            None
+        };
+        if let Some(c) = triplet {
+            decoded.push(c);
+            i += 3;
+        } else {
+            decoded.push(text[i]);
+            i += 1;
+        }
+    }
+    decoded
+}
+
+/// Gets the value of the hex digit.
+const fn hex_value(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{is_percent_encoded, is_unreserved, percent_decode};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn percent_encoded() {
+        let test_cases: &[(&str, bool)] = &[
+            ("", true),
+            ("abc", true),
+            ("a%20b", true),
+            ("%C3%bc", true),
+            ("%", false),
+            ("%2", false),
+            ("%2g", false),
+            ("a b", false),
+            ("a:b", false),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: bool = is_percent_encoded(input.as_bytes(), is_unreserved);
+            assert_eq!(result, *expected, "input={}", input);
+        }
+    }
+
+    #[test]
+    fn decode() {
+        let test_cases: &[(&str, &[u8])] = &[
+            ("", b""),
+            ("abc", b"abc"),
+            ("a%20b", b"a b"),
+            ("%C3%bc", "ü".as_bytes()),
+            ("%25", b"%"),
+            ("%FF", b"\xFF"),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Vec<u8> = percent_decode(input.as_bytes());
+            assert_eq!(result, *expected, "input={}", input);
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::ParseError::InvalidSocketAddress;
+#[cfg(feature = "alloc")]
+use crate::{Authorities, Authority, Domain, Endpoints};
+use crate::{IPv4Address, IPv6Address, ParseError, PortRange, SocketAddresses, parse_port_range};
+
+impl SocketAddresses {
+    //! Parse
+
+    /// An IPv4 address or a bracketed IPv6 address, & a port range: `127.0.0.1:8000-8010` or `[::1]:80`.
+    /// The syntax otherwise matches [`SocketAddress::parse_text`](crate::SocketAddress::parse_text).
+    pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+        let (ip, ports): (&[u8], PortRange) = parse_port_range(text)?;
+        if let Some(ip) = IPv6Address::parse_bracketed(ip) {
+            Ok(ip?.to_ip().to_sockets(ports))
+        } else {
+            let ip: IPv4Address = IPv4Address::parse_text(ip).map_err(|_| InvalidSocketAddress)?;
+            Ok(ip.to_ip().to_sockets(ports))
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Authorities {
+    //! Parse
+
+    /// A host & a port range; an IPv6 host must be bracketed: `localhost:8000-8010` or `[::1]:80`.
+    /// The syntax otherwise matches [`Authority::parse_text`].
+    pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+        let (host, ports): (&[u8], PortRange) = parse_port_range(text)?;
+        Ok(Authority::parse_host(host)?.to_authorities(ports))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Endpoints {
+    //! Parse
+
+    /// A domain name & a port range: `localhost:8000-8010` or `localhost:80`.
+    /// Domain names are normalized to lowercase.
+    pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+        let (name, ports): (&[u8], PortRange) = parse_port_range(text)?;
+        Ok(Domain::parse_text(name)?.to_endpoints(ports))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::{InvalidPort, InvalidPortRange, InvalidSocketAddress};
+    use crate::{IPv4Address, IPv6Address, ParseError, SocketAddress, SocketAddresses};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[cfg(feature = "alloc")]
+    use crate::ParseError::InvalidAuthority;
+    #[cfg(feature = "alloc")]
+    use crate::{Authorities, Authority, Endpoint, Endpoints};
+    #[cfg(feature = "alloc")]
+    use core::str::FromStr;
+
+    #[test]
+    fn parse_socket_addresses() {
+        let test_cases: &[(&str, Result<Vec<SocketAddress>, ParseError>)] = &[
+            ("", Err(InvalidPort)),
+            ("127.0.0.1", Err(InvalidPort)),
+            ("127.0.0.1:90-80", Err(InvalidPortRange)),
+            ("localhost:80-81", Err(InvalidSocketAddress)),
+            (
+                "127.0.0.1:80-81",
+                Ok(vec![
+                    IPv4Address::LOCALHOST.to_ip().to_socket(80),
+                    IPv4Address::LOCALHOST.to_ip().to_socket(81),
+                ]),
+            ),
+            ("[::1]:80", Ok(vec![IPv6Address::LOCALHOST.to_ip().to_socket(80)])),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<Vec<SocketAddress>, ParseError> =
+                SocketAddresses::parse_text(input.as_bytes()).map(Iterator::collect);
+            assert_eq!(result, *expected, "input={}", input);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn parse_authorities() {
+        let test_cases: &[(&str, Result<Vec<&str>, ParseError>)] = &[
+            ("", Err(InvalidPort)),
+            ("localhost:90-80", Err(InvalidPortRange)),
+            ("::1:80-81", Err(InvalidAuthority)),
+            ("Localhost:80-81", Ok(vec!["localhost:80", "localhost:81"])),
+            ("[::1]:80-81", Ok(vec!["[::1]:80", "[::1]:81"])),
+            ("127.0.0.1:80", Ok(vec!["127.0.0.1:80"])),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<Vec<Authority>, ParseError> =
+                Authorities::parse_text(input.as_bytes()).map(Iterator::collect);
+            let expected: Result<Vec<Authority>, ParseError> = expected
+                .as_ref()
+                .map(|expected| expected.iter().map(|a| Authority::from_str(a).unwrap()).collect())
+                .map_err(|error| *error);
+            assert_eq!(result, expected, "input={}", input);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn parse_endpoints() {
+        let test_cases: &[(&str, Result<Vec<&str>, ParseError>)] = &[
+            ("", Err(InvalidPort)),
+            ("localhost:90-80", Err(InvalidPortRange)),
+            (
+                "Example.com:30000-30002",
+                Ok(vec!["example.com:30000", "example.com:30001", "example.com:30002"]),
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<Vec<Endpoint>, ParseError> =
+                Endpoints::parse_text(input.as_bytes()).map(Iterator::collect);
+            let expected: Result<Vec<Endpoint>, ParseError> = expected
+                .as_ref()
+                .map(|expected| expected.iter().map(|e| Endpoint::from_str(e).unwrap()).collect())
+                .map_err(|error| *error);
+            assert_eq!(result, expected, "input={}", input);
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod expansion;
+mod port;
+mod port_range;
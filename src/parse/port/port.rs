@@ -0,0 +1,46 @@
+use crate::{ParseError, Port, impl_parse, parse_port_number};
+
+impl Port {
+    //! Parse
+
+    /// A decimal port number, with no sign: `80`. Leading zeros are allowed to match the standard library.
+    pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+        parse_port_number(text).map(Self::new)
+    }
+}
+
+impl_parse!(
+    Port,
+    "A decimal port number, with no sign: `80`. Leading zeros are allowed to match the standard library."
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::InvalidPort;
+    use crate::{ParseError, Port};
+    use core::str::FromStr;
+
+    #[test]
+    fn parse() {
+        let test_cases: &[(&str, Result<Port, ParseError>)] = &[
+            ("", Err(InvalidPort)),
+            ("80", Ok(Port::new(80))),
+            ("080", Ok(Port::new(80))),
+            ("65535", Ok(Port::new(65535))),
+            ("65536", Err(InvalidPort)),
+            ("+80", Err(InvalidPort)),
+            ("http", Err(InvalidPort)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<Port, ParseError> = Port::from_str(input);
+            assert_eq!(result, *expected, "input={}", input);
+
+            let result: Result<Port, ParseError> = Port::try_from(*input);
+            assert_eq!(result, *expected, "input={}", input);
+
+            let result: Result<Port, ParseError> = Port::parse_text(input.as_bytes());
+            assert_eq!(result, *expected, "input={}", input);
+        }
+    }
+}
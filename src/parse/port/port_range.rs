@@ -0,0 +1,80 @@
+use crate::ParseError::InvalidPortRange;
+use crate::{ParseError, Port, PortRange, impl_parse, parse_port_number};
+
+impl PortRange {
+    //! Parse
+
+    /// Two decimal ports joined by a hyphen, or a single decimal port: `8000-8010` or `80`.
+    /// The start must not exceed the end. Each port has the digit rules of [`Port::parse_text`].
+    pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+        if let Some(hyphen) = text.iter().position(|c| *c == b'-') {
+            let start: Port = Port::new(parse_port_number(&text[..hyphen])?);
+            let end: Port = Port::new(parse_port_number(&text[hyphen + 1..])?);
+            Self::new(start, end).ok_or(InvalidPortRange)
+        } else {
+            parse_port_number(text).map(|port| Self::single(Port::new(port)))
+        }
+    }
+}
+
+impl_parse!(
+    PortRange,
+    "Two decimal ports joined by a hyphen, or a single decimal port: `8000-8010` or `80`.",
+    "The start must not exceed the end. Each port has the digit rules of [`Port::parse_text`]."
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::{InvalidPort, InvalidPortRange};
+    use crate::{ParseError, Port, PortRange};
+    use alloc::string::ToString;
+    use core::str::FromStr;
+
+    type TestCase<'a> = (&'a str, Result<(u16, u16), ParseError>);
+
+    #[test]
+    fn parse() {
+        let test_cases: &[TestCase] = &[
+            ("", Err(InvalidPort)),
+            ("-", Err(InvalidPort)),
+            ("80", Ok((80, 80))),
+            ("80-80", Ok((80, 80))),
+            ("8000-8010", Ok((8000, 8010))),
+            ("0-65535", Ok((0, 65535))),
+            ("080-090", Ok((80, 90))),
+            ("90-80", Err(InvalidPortRange)),
+            ("80-", Err(InvalidPort)),
+            ("-80", Err(InvalidPort)),
+            ("+80-90", Err(InvalidPort)),
+            ("80-+90", Err(InvalidPort)),
+            ("80--90", Err(InvalidPort)),
+            ("80-90-100", Err(InvalidPort)),
+            ("80-65536", Err(InvalidPort)),
+            ("80 - 90", Err(InvalidPort)),
+        ];
+
+        for (input, expected) in test_cases {
+            let expected: Result<PortRange, ParseError> =
+                expected.map(|(start, end)| PortRange::new(Port::new(start), Port::new(end)).unwrap());
+
+            let result: Result<PortRange, ParseError> = PortRange::from_str(input);
+            assert_eq!(result, expected, "input={}", input);
+
+            let result: Result<PortRange, ParseError> = PortRange::try_from(*input);
+            assert_eq!(result, expected, "input={}", input);
+
+            let result: Result<PortRange, ParseError> = PortRange::parse_text(input.as_bytes());
+            assert_eq!(result, expected, "input={}", input);
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let test_cases: &[&str] = &["80", "8000-8010", "0-65535"];
+
+        for input in test_cases {
+            let range: PortRange = PortRange::from_str(input).unwrap();
+            assert_eq!(range.to_string(), *input, "input={}", input);
+        }
+    }
+}
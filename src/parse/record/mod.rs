@@ -0,0 +1,19 @@
+mod mx_record;
+mod srv_record;
+
+use crate::ParseError::InvalidRecord;
+use crate::{Domain, ParseError, parse_port_number};
+
+/// Parses the 16-bit decimal record `field`, such as a priority.
+fn parse_record_number(field: &[u8]) -> Result<u16, ParseError> {
+    parse_port_number(field).map_err(|_| InvalidRecord)
+}
+
+/// Parses the record `target`, removing one trailing root dot. The root `.` itself is `None`.
+fn parse_record_target(target: &[u8]) -> Result<Option<Domain>, ParseError> {
+    if target == b"." {
+        Ok(None)
+    } else {
+        Domain::parse_text(target.strip_suffix(b".").unwrap_or(target)).map(Some)
+    }
+}
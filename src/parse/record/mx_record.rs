@@ -0,0 +1,66 @@
+use crate::ParseError::InvalidRecord;
+use crate::parse::record::{parse_record_number, parse_record_target};
+use crate::{MxRecord, ParseError, impl_parse};
+use alloc::vec::Vec;
+
+impl MxRecord {
+    //! Parse
+
+    /// The record data of a zone file: `preference exchange`, separated by spaces or tabs.
+    /// The exchange may end in a root dot, & the exchange `.` is a null MX record: `10 mail.example.`
+    pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+        let fields: Vec<&[u8]> = text
+            .split(|c| *c == b' ' || *c == b'\t')
+            .filter(|field| !field.is_empty())
+            .collect();
+        let [preference, exchange] = fields.as_slice() else {
+            return Err(InvalidRecord);
+        };
+        let preference: u16 = parse_record_number(preference)?;
+        Ok(match parse_record_target(exchange)? {
+            Some(domain) => Self::new(preference, domain),
+            None => Self::null(preference),
+        })
+    }
+}
+
+impl_parse!(
+    MxRecord,
+    "The record data of a zone file: `preference exchange`, separated by spaces or tabs.",
+    "The exchange may end in a root dot, & the exchange `.` is a null MX record: `10 mail.example.`"
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::{InvalidDomain, InvalidRecord};
+    use crate::{Domain, MxRecord, ParseError};
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse_text() {
+        let test_cases: &[(&str, Result<MxRecord, ParseError>)] = &[
+            ("10 Example.COM.", Ok(MxRecord::new(10, Domain::example()))),
+            ("10 example.com", Ok(MxRecord::new(10, Domain::example()))),
+            ("0 .", Ok(MxRecord::null(0))),
+            ("10", Err(InvalidRecord)),
+            ("x example.com", Err(InvalidRecord)),
+            ("10 example.com..", Err(InvalidDomain)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<MxRecord, ParseError> = MxRecord::parse_text(input.as_bytes());
+            assert_eq!(result, *expected, "input={}", input);
+        }
+    }
+
+    /// Each canonical string must parse and display back to the exact same string.
+    #[test]
+    fn round_trip() {
+        let canonical: &[&str] = &["10 mail.example.com.", "0 ."];
+
+        for input in canonical {
+            let value: MxRecord = input.parse().unwrap();
+            assert_eq!(value.to_string(), *input, "input={}", input);
+        }
+    }
+}
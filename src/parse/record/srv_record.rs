@@ -0,0 +1,77 @@
+use crate::ParseError::InvalidRecord;
+use crate::parse::record::{parse_record_number, parse_record_target};
+use crate::{ParseError, SrvRecord, impl_parse, parse_port_number};
+use alloc::vec::Vec;
+
+impl SrvRecord {
+    //! Parse
+
+    /// The record data of a zone file: `priority weight port target`, separated by spaces or tabs.
+    /// The target may end in a root dot, & the target `.` means the service is not available: `10 60 5060 sip.example.`
+    pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+        let fields: Vec<&[u8]> = text
+            .split(|c| *c == b' ' || *c == b'\t')
+            .filter(|field| !field.is_empty())
+            .collect();
+        let [priority, weight, port, target] = fields.as_slice() else {
+            return Err(InvalidRecord);
+        };
+        let priority: u16 = parse_record_number(priority)?;
+        let weight: u16 = parse_record_number(weight)?;
+        let port: u16 = parse_port_number(port)?;
+        Ok(match parse_record_target(target)? {
+            Some(domain) => Self::new(priority, weight, domain.to_endpoint(port)),
+            None => Self::unavailable(priority, weight),
+        })
+    }
+}
+
+impl_parse!(
+    SrvRecord,
+    "The record data of a zone file: `priority weight port target`, separated by spaces or tabs.",
+    "The target may end in a root dot, & the target `.` means the service is not available: `10 60 5060 sip.example.`"
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::{InvalidDomain, InvalidPort, InvalidRecord};
+    use crate::{Domain, ParseError, SrvRecord};
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse_text() {
+        let test_cases: &[(&str, Result<SrvRecord, ParseError>)] = &[
+            (
+                "10 60 443 Example.COM.",
+                Ok(SrvRecord::new(10, 60, Domain::example().to_endpoint(443))),
+            ),
+            (
+                "10\t60\t443\texample.com",
+                Ok(SrvRecord::new(10, 60, Domain::example().to_endpoint(443))),
+            ),
+            ("0 0 0 .", Ok(SrvRecord::unavailable(0, 0))),
+            ("10 60 443", Err(InvalidRecord)),
+            ("10 60 443 example.com extra", Err(InvalidRecord)),
+            ("10 65536 443 example.com", Err(InvalidRecord)),
+            ("-1 60 443 example.com", Err(InvalidRecord)),
+            ("10 60 http example.com", Err(InvalidPort)),
+            ("10 60 443 example..com", Err(InvalidDomain)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<SrvRecord, ParseError> = SrvRecord::parse_text(input.as_bytes());
+            assert_eq!(result, *expected, "input={}", input);
+        }
+    }
+
+    /// Each canonical string must parse and display back to the exact same string.
+    #[test]
+    fn round_trip() {
+        let canonical: &[&str] = &["10 60 5060 sip.example.com.", "0 0 0 ."];
+
+        for input in canonical {
+            let value: SrvRecord = input.parse().unwrap();
+            assert_eq!(value.to_string(), *input, "input={}", input);
+        }
+    }
+}
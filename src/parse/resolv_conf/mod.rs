@@ -0,0 +1 @@
+mod resolv_conf;
@@ -0,0 +1,182 @@
+use crate::ParseError::{InvalidDomain, InvalidIPAddress, InvalidResolverOption};
+use crate::{Domain, IPAddress, IPv6Address, LineParseError, Nameserver, ParseError, ResolvConf, ResolverOptions};
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+
+impl ResolvConf {
+    //! Parse
+
+    /// Parses the `resolv.conf` file `text`.
+    ///
+    /// Each line is a keyword followed by its values, separated by spaces or tabs, & a `#` or `;` starts a comment.
+    /// The `nameserver`, `search`, `domain`, & `options` keywords are read; other keywords such as `sortlist` are
+    /// skipped.
+    ///
+    /// - A name server is an IP address on the DNS port, with an optional IPv6 zone: `fe80::1%eth0`.
+    /// - Search domains are parsed with [`Domain::parse_text`] after removing one trailing root dot.
+    /// - The `ndots`, `timeout`, & `attempts` options take a decimal value, capped at the glibc limits. The `rotate` &
+    ///   `edns0` options are flags, & other options are kept as written.
+    pub fn parse_resolv_conf(text: &[u8]) -> Result<Self, LineParseError> {
+        let mut conf: Self = Self::default();
+        for (i, line) in text.split(|c| *c == b'\n').enumerate() {
+            conf.parse_resolv_conf_line(line)
+                .map_err(|error| LineParseError::new(i + 1, error))?;
+        }
+        Ok(conf)
+    }
+
+    /// Parses the `resolv.conf` `line` into the configuration.
+    fn parse_resolv_conf_line(&mut self, line: &[u8]) -> Result<(), ParseError> {
+        let line: &[u8] = match line.iter().position(|c| *c == b'#' || *c == b';') {
+            Some(comment) => &line[..comment],
+            None => line,
+        };
+        let mut fields = line
+            .split(|c| c.is_ascii_whitespace())
+            .filter(|field| !field.is_empty());
+
+        match fields.next() {
+            Some(b"nameserver") => {
+                let ip: &[u8] = fields.next().ok_or(InvalidIPAddress)?;
+                self.nameservers.push(Self::parse_nameserver(ip)?);
+            }
+            Some(b"search") => {
+                self.search = fields
+                    .map(Self::parse_search_domain)
+                    .collect::<Result<Vec<Domain>, ParseError>>()?;
+            }
+            Some(b"domain") => {
+                let domain: &[u8] = fields.next().ok_or(InvalidDomain)?;
+                self.search = vec![Self::parse_search_domain(domain)?];
+            }
+            Some(b"options") => {
+                for option in fields {
+                    Self::parse_option(&mut self.options, option)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Parses the name server `ip`, with an optional IPv6 zone.
+    fn parse_nameserver(ip: &[u8]) -> Result<Nameserver, ParseError> {
+        match ip.iter().position(|c| *c == b'%') {
+            Some(percent) => {
+                let zone: &str = core::str::from_utf8(&ip[percent + 1..]).map_err(|_| InvalidIPAddress)?;
+                if zone.is_empty() {
+                    return Err(InvalidIPAddress);
+                }
+                let ip: IPv6Address = IPv6Address::parse_text(&ip[..percent])?;
+                Ok(Nameserver::new(ip.to_ip()).with_zone(zone))
+            }
+            None => IPAddress::parse_text(ip).map(Nameserver::new),
+        }
+    }
+
+    /// Parses the search `domain`, removing one trailing root dot.
+    fn parse_search_domain(domain: &[u8]) -> Result<Domain, ParseError> {
+        Domain::parse_text(domain.strip_suffix(b".").unwrap_or(domain))
+    }
+
+    /// Parses the `option` into the `options`.
+    fn parse_option(options: &mut ResolverOptions, option: &[u8]) -> Result<(), ParseError> {
+        let option: &str = core::str::from_utf8(option).map_err(|_| InvalidResolverOption)?;
+        match option.split_once(':') {
+            Some(("ndots", value)) => options.ndots = Self::parse_option_value(value, ResolverOptions::MAX_NDOTS)?,
+            Some(("timeout", value)) => {
+                options.timeout = Self::parse_option_value(value, ResolverOptions::MAX_TIMEOUT)?
+            }
+            Some(("attempts", value)) => {
+                options.attempts = Self::parse_option_value(value, ResolverOptions::MAX_ATTEMPTS)?
+            }
+            None if option == "rotate" => options.rotate = true,
+            None if option == "edns0" => options.edns0 = true,
+            _ => options.other.push(option.to_string()),
+        }
+        Ok(())
+    }
+
+    /// Parses the decimal option `value`, capped at the `max`.
+    fn parse_option_value(value: &str, max: u8) -> Result<u8, ParseError> {
+        if value.is_empty() || !value.bytes().all(|c| c.is_ascii_digit()) {
+            return Err(InvalidResolverOption);
+        }
+        let value: u32 = value.parse().unwrap_or(u32::MAX);
+        Ok(value.min(u32::from(max)) as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::{InvalidDomain, InvalidIPAddress, InvalidIPv6Address, InvalidResolverOption};
+    use crate::{Domain, IPv4Address, IPv6Address, LineParseError, Nameserver, ResolvConf};
+    use alloc::string::String;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn parse_resolv_conf() {
+        let text: &str = "\
+# Generated by the container runtime
+nameserver 10.96.0.10
+nameserver fe80::1%eth0 ; link-local
+; nameserver 10.96.0.11
+nameserver ::1
+domain ignored.example
+search Default.svc.cluster.local. svc.cluster.local
+sortlist 130.155.160.0/255.255.240.0
+options ndots:5 timeout:60 attempts:3 rotate edns0 single-request
+";
+        let conf: ResolvConf = ResolvConf::parse_resolv_conf(text.as_bytes()).unwrap();
+
+        let expected: &[Nameserver] = &[
+            Nameserver::new(IPv4Address::new([10, 96, 0, 10]).to_ip()),
+            Nameserver::new(IPv6Address::from_segments([0xFE80, 0, 0, 0, 0, 0, 0, 1]).to_ip()).with_zone("eth0"),
+            Nameserver::new(IPv6Address::LOCALHOST.to_ip()),
+        ];
+        assert_eq!(conf.nameservers(), expected);
+
+        let search: Vec<String> = conf.search().iter().map(Domain::to_string).collect();
+        assert_eq!(search, ["default.svc.cluster.local", "svc.cluster.local"]);
+
+        assert_eq!(conf.options().ndots(), 5);
+        assert_eq!(conf.options().timeout(), 30);
+        assert_eq!(conf.options().attempts(), 3);
+        assert!(conf.options().rotate());
+        assert!(conf.options().edns0());
+        assert_eq!(conf.options().other().collect::<Vec<&str>>(), ["single-request"]);
+    }
+
+    #[test]
+    fn parse_domain() {
+        let conf: ResolvConf = ResolvConf::parse_resolv_conf(b"search a.example\ndomain b.example").unwrap();
+        assert_eq!(conf.search(), [Domain::parse_text(b"b.example").unwrap()]);
+
+        let conf: ResolvConf = ResolvConf::parse_resolv_conf(b"search a.example # note").unwrap();
+        assert_eq!(conf.search(), [Domain::parse_text(b"a.example").unwrap()]);
+
+        let conf: ResolvConf = ResolvConf::parse_resolv_conf(b"").unwrap();
+        assert_eq!(conf, ResolvConf::default());
+    }
+
+    #[test]
+    fn parse_resolv_conf_errors() {
+        let test_cases: &[(&str, LineParseError)] = &[
+            ("nameserver", LineParseError::new(1, InvalidIPAddress)),
+            ("nameserver localhost", LineParseError::new(1, InvalidIPAddress)),
+            ("\nnameserver 127.0.0.1%lo", LineParseError::new(2, InvalidIPv6Address)),
+            ("nameserver fe80::1%", LineParseError::new(1, InvalidIPAddress)),
+            ("search a_b.example", LineParseError::new(1, InvalidDomain)),
+            ("domain", LineParseError::new(1, InvalidDomain)),
+            ("options ndots:x", LineParseError::new(1, InvalidResolverOption)),
+            ("options timeout:", LineParseError::new(1, InvalidResolverOption)),
+        ];
+
+        for (text, expected) in test_cases {
+            let result: Result<ResolvConf, LineParseError> = ResolvConf::parse_resolv_conf(text.as_bytes());
+            assert_eq!(result, Err(*expected), "text={:?}", text);
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::ParseError::InvalidService;
+use crate::{LineParseError, ParseError, Port, Service, ServiceRegistry, TransportProtocol, parse_port_number};
+
+impl ServiceRegistry {
+    //! Parse
+
+    /// Parses the `/etc/services` file `text`.
+    ///
+    /// Each line is `name port/protocol [aliases...]`, separated by spaces or tabs, & a `#` starts a comment. Blank
+    /// & comment-only lines are skipped, as are lines for protocols other than `tcp`, `udp`, `sctp`, & `dccp`.
+    /// Ports follow the strict digit rules of the socket address parsers.
+    pub fn parse_services(text: &[u8]) -> Result<Self, LineParseError> {
+        let mut registry: Self = Self::new();
+        for (i, line) in text.split(|c| *c == b'\n').enumerate() {
+            if let Some(service) = Self::parse_services_line(line).map_err(|error| LineParseError::new(i + 1, error))? {
+                registry.insert(service);
+            }
+        }
+        Ok(registry)
+    }
+
+    /// Parses the `/etc/services` `line`.
+    ///
+    /// Returns `None` for blank & comment-only lines & for unknown protocols.
+    fn parse_services_line(line: &[u8]) -> Result<Option<Service>, ParseError> {
+        let line: &[u8] = match line.iter().position(|c| *c == b'#') {
+            Some(hash) => &line[..hash],
+            None => line,
+        };
+        let mut fields = line
+            .split(|c| c.is_ascii_whitespace())
+            .filter(|field| !field.is_empty());
+
+        let Some(name) = fields.next() else {
+            return Ok(None);
+        };
+        let (port, protocol): (&[u8], &[u8]) = fields
+            .next()
+            .and_then(|field| field.iter().position(|c| *c == b'/').map(|slash| field.split_at(slash)))
+            .ok_or(InvalidService)?;
+        let port: Port = Port::new(parse_port_number(port)?);
+        let Some(protocol) = TransportProtocol::from_name(&protocol[1..]) else {
+            return Ok(None);
+        };
+
+        let mut service: Service = Service::new(Self::parse_service_name(name)?, port, protocol);
+        for alias in fields {
+            service = service.with_alias(Self::parse_service_name(alias)?);
+        }
+        Ok(Some(service))
+    }
+
+    /// Parses the service `name`, which must be valid UTF-8.
+    fn parse_service_name(name: &[u8]) -> Result<&str, ParseError> {
+        core::str::from_utf8(name).map_err(|_| InvalidService)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::{InvalidPort, InvalidService};
+    use crate::TransportProtocol::{Tcp, Udp};
+    use crate::{LineParseError, Port, Service, ServiceRegistry};
+    use alloc::format;
+    use alloc::string::String;
+
+    #[test]
+    fn parse_services() {
+        let text: &str = "\
+# Network services, Internet style
+tcpmux\t\t1/tcp\t\t\t\t# TCP port service multiplexer
+
+http\t\t80/tcp\t\twww www-http\t# WorldWideWeb HTTP
+domain\t\t53/udp
+ddp-thing\t7/ddp
+app 8000/TCP\r
+";
+        let registry: ServiceRegistry = ServiceRegistry::parse_services(text.as_bytes()).unwrap();
+        let expected: &[Service] = &[
+            Service::new("tcpmux", Port::new(1), Tcp),
+            Service::new("http", Port::new(80), Tcp)
+                .with_alias("www")
+                .with_alias("www-http"),
+            Service::new("domain", Port::new(53), Udp),
+            Service::new("app", Port::new(8000), Tcp),
+        ];
+        assert_eq!(registry.services(), expected);
+        assert_eq!(registry.port("www", Tcp), Some(Port::new(80)));
+    }
+
+    #[test]
+    fn parse_services_errors() {
+        let test_cases: &[(&str, LineParseError)] = &[
+            ("http", LineParseError::new(1, InvalidService)),
+            ("http 80", LineParseError::new(1, InvalidService)),
+            ("ok 1/tcp\nhttp 99999/tcp", LineParseError::new(2, InvalidPort)),
+            ("http +80/tcp", LineParseError::new(1, InvalidPort)),
+            ("http /tcp", LineParseError::new(1, InvalidPort)),
+        ];
+
+        for (text, expected) in test_cases {
+            let result: Result<ServiceRegistry, LineParseError> = ServiceRegistry::parse_services(text.as_bytes());
+            assert_eq!(result, Err(*expected), "text={:?}", text);
+        }
+
+        let result: Result<ServiceRegistry, LineParseError> = ServiceRegistry::parse_services(b"\xFF 80/tcp");
+        assert_eq!(result, Err(LineParseError::new(1, InvalidService)));
+    }
+
+    /// Every service displays as a line that parses back to an equal service.
+    #[test]
+    fn round_trip() {
+        let registry: ServiceRegistry = ServiceRegistry::iana();
+        let text: String = registry
+            .services()
+            .iter()
+            .map(|service| format!("{}\n", service))
+            .collect();
+        let parsed: ServiceRegistry = ServiceRegistry::parse_services(text.as_bytes()).unwrap();
+        assert_eq!(parsed, registry);
+    }
+}
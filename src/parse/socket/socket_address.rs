@@ -7,13 +7,18 @@ impl SocketAddress {
 
     /// An IPv4 address or a bracketed IPv6 address, & a decimal port: `127.0.0.1:80` or `[::1]:80`.
     /// A numeric IPv6 zone is accepted & ignored: `[fe80::1%1]:80` parses as `[fe80::1]:80`.
-    pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
-        let (ip, port): (&[u8], u16) = parse_port(text)?;
-        if let Some(ip) = IPv6Address::parse_bracketed(ip) {
-            Ok(ip?.to_ip().to_socket(port))
-        } else {
-            let ip: IPv4Address = IPv4Address::parse_text(ip).map_err(|_| InvalidSocketAddress)?;
-            Ok(ip.to_ip().to_socket(port))
+    pub const fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+        let (ip, port): (&[u8], u16) = match parse_port(text) {
+            Ok(parsed) => parsed,
+            Err(error) => return Err(error),
+        };
+        match IPv6Address::parse_bracketed(ip) {
+            Some(Ok(ip)) => Ok(ip.to_ip().to_socket(port)),
+            Some(Err(error)) => Err(error),
+            None => match IPv4Address::parse_text(ip) {
+                Ok(ip) => Ok(ip.to_ip().to_socket(port)),
+                Err(_) => Err(InvalidSocketAddress),
+            },
         }
     }
 }
@@ -28,7 +33,8 @@ impl_parse!(
 mod tests {
     use crate::ParseError::{InvalidIPv6Address, InvalidPort, InvalidSocketAddress};
     use crate::{IPv4Address, IPv6Address, ParseError, SocketAddress};
-    use std::str::FromStr;
+    use alloc::string::ToString;
+    use core::str::FromStr;
 
     #[test]
     fn parse() {
@@ -93,4 +99,14 @@ mod tests {
             assert_eq!(value.to_string(), *input, "input={}", input);
         }
     }
+
+    /// The parser runs in `const` contexts, so literals are validated at compile time.
+    #[test]
+    fn const_parse() {
+        const SOCKET: Result<SocketAddress, ParseError> = SocketAddress::parse_text(b"127.0.0.1:80");
+        assert_eq!(SOCKET, Ok(IPv4Address::LOCALHOST.to_ip().to_socket(80)));
+
+        const INVALID: Result<SocketAddress, ParseError> = SocketAddress::parse_text(b"127.0.0.1:65536");
+        assert_eq!(INVALID, Err(InvalidPort));
+    }
 }
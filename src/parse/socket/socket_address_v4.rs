@@ -5,10 +5,15 @@ impl SocketAddressV4 {
     //! Parse
 
     /// An IPv4 address & a decimal port: `127.0.0.1:80`.
-    pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
-        let (ip, port): (&[u8], u16) = parse_port(text)?;
-        let ip: IPv4Address = IPv4Address::parse_text(ip)?;
-        Ok(Self::new(ip, port))
+    pub const fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+        let (ip, port): (&[u8], u16) = match parse_port(text) {
+            Ok(parsed) => parsed,
+            Err(error) => return Err(error),
+        };
+        match IPv4Address::parse_text(ip) {
+            Ok(ip) => Ok(Self::new(ip, port)),
+            Err(error) => Err(error),
+        }
     }
 }
 
@@ -18,7 +23,8 @@ impl_parse!(SocketAddressV4, "An IPv4 address & a decimal port: `127.0.0.1:80`."
 mod tests {
     use crate::ParseError::{InvalidIPv4Address, InvalidPort};
     use crate::{IPv4Address, ParseError, SocketAddressV4};
-    use std::str::FromStr;
+    use alloc::string::ToString;
+    use core::str::FromStr;
 
     #[test]
     fn parse() {
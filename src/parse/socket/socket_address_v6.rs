@@ -7,10 +7,14 @@ impl SocketAddressV6 {
 
     /// A bracketed IPv6 address & a decimal port: `[::1]:80`.
     /// A numeric IPv6 zone is accepted & ignored: `[fe80::1%1]:80` parses as `[fe80::1]:80`.
-    pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
-        let (ip, port): (&[u8], u16) = parse_port(text)?;
+    pub const fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+        let (ip, port): (&[u8], u16) = match parse_port(text) {
+            Ok(parsed) => parsed,
+            Err(error) => return Err(error),
+        };
         match IPv6Address::parse_bracketed(ip) {
-            Some(ip) => Ok(Self::new(ip?, port)),
+            Some(Ok(ip)) => Ok(Self::new(ip, port)),
+            Some(Err(error)) => Err(error),
             None => Err(InvalidSocketAddressV6),
         }
     }
@@ -26,7 +30,8 @@ impl_parse!(
 mod tests {
     use crate::ParseError::{InvalidIPv6Address, InvalidPort, InvalidSocketAddressV6};
     use crate::{IPv6Address, ParseError, SocketAddressV6};
-    use std::str::FromStr;
+    use alloc::string::ToString;
+    use core::str::FromStr;
 
     #[test]
     fn parse() {
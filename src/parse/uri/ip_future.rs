@@ -0,0 +1,98 @@
+use crate::ParseError::InvalidHost;
+use crate::{IPvFuture, InvalidAddressError, ParseError, impl_parse, impl_parse_string, is_sub_delim, is_unreserved};
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+impl IPvFuture {
+    //! Parse
+
+    /// An IPvFuture literal, without its brackets: `v` 1*HEXDIG `.` 1*( unreserved / sub-delims / `:` ), as in
+    /// `v1.fe80::a+en1`.
+    pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+        let rest: &[u8] = match text.split_first() {
+            Some((b'v' | b'V', rest)) => rest,
+            _ => return Err(InvalidHost),
+        };
+        let dot: usize = rest.iter().position(|c| *c == b'.').ok_or(InvalidHost)?;
+        let (version, address): (&[u8], &[u8]) = (&rest[..dot], &rest[dot + 1..]);
+        let valid: bool = !version.is_empty()
+            && version.iter().all(u8::is_ascii_hexdigit)
+            && !address.is_empty()
+            && address
+                .iter()
+                .all(|c| is_unreserved(*c) || is_sub_delim(*c) || *c == b':');
+        if valid {
+            Ok(unsafe { Self::new_unchecked(core::str::from_utf8_unchecked(text).to_string()) })
+        } else {
+            Err(InvalidHost)
+        }
+    }
+}
+
+impl_parse!(
+    IPvFuture,
+    "An IPvFuture literal, without its brackets: `v` 1*HEXDIG `.` 1*( unreserved / sub-delims / `:` ), as in",
+    "`v1.fe80::a+en1`."
+);
+
+impl_parse_string!(
+    IPvFuture,
+    "An IPvFuture literal, without its brackets: `v` 1*HEXDIG `.` 1*( unreserved / sub-delims / `:` ), as in",
+    "`v1.fe80::a+en1`."
+);
+
+impl TryFrom<Vec<u8>> for IPvFuture {
+    type Error = InvalidAddressError<Vec<u8>>;
+
+    /// An IPvFuture literal, without its brackets: `v` 1*HEXDIG `.` 1*( unreserved / sub-delims / `:` ), as in
+    /// `v1.fe80::a+en1`.
+    /// The error contains the unmodified `text`, which `TryFrom<String>` soundly recovers as a string.
+    fn try_from(text: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::parse_text(text.as_slice()).map_err(|error| InvalidAddressError::new(text, error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::InvalidHost;
+    use crate::{IPvFuture, ParseError};
+    use alloc::string::ToString;
+    use core::str::FromStr;
+
+    #[test]
+    fn parse() {
+        let test_cases: &[(&str, Result<&str, ParseError>)] = &[
+            ("v1.fe80::a+en1", Ok("v1.fe80::a+en1")),
+            ("VF.x", Ok("VF.x")),
+            ("v1a.x:y", Ok("v1a.x:y")),
+            ("", Err(InvalidHost)),
+            ("v", Err(InvalidHost)),
+            ("v.x", Err(InvalidHost)),
+            ("v1.", Err(InvalidHost)),
+            ("v1x", Err(InvalidHost)),
+            ("vg.x", Err(InvalidHost)),
+            ("v1.x y", Err(InvalidHost)),
+            ("v1.x%20", Err(InvalidHost)),
+            ("[v1.x]", Err(InvalidHost)),
+            ("1.x", Err(InvalidHost)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<IPvFuture, ParseError> = IPvFuture::from_str(input);
+            assert_eq!(
+                result.as_ref().map(IPvFuture::literal).map_err(|e| *e),
+                *expected,
+                "input={}",
+                input
+            );
+
+            let result: Result<IPvFuture, ParseError> = IPvFuture::try_from(input.to_string()).map_err(|e| e.error());
+            assert_eq!(
+                result.as_ref().map(IPvFuture::literal).map_err(|e| *e),
+                *expected,
+                "input={}",
+                input
+            );
+        }
+    }
+}
@@ -0,0 +1,4 @@
+mod ip_future;
+mod reg_name;
+mod uri_authority;
+mod uri_host;
@@ -0,0 +1,105 @@
+use crate::ParseError::InvalidHost;
+use crate::{
+    InvalidAddressError, ParseError, RegName, impl_parse, impl_parse_string, is_percent_encoded, is_sub_delim,
+    is_unreserved,
+};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+impl RegName {
+    //! Parse
+
+    /// A percent-encoded registered name: `example.com` or `my_host`. Names are normalized to lowercase, with uppercase
+    /// percent-encoded hex digits. The name may be empty.
+    pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+        if is_percent_encoded(text, |c| is_unreserved(c) || is_sub_delim(c)) {
+            Ok(unsafe { Self::new_unchecked(Self::normalize(text)) })
+        } else {
+            Err(InvalidHost)
+        }
+    }
+
+    /// Normalizes the validated `name`: letters are lowercased & percent-encoded hex digits uppercased.
+    fn normalize(name: &[u8]) -> String {
+        let mut normalized: Vec<u8> = name.to_ascii_lowercase();
+        let mut i: usize = 0;
+        while i < normalized.len() {
+            if normalized[i] == b'%' {
+                normalized[i + 1].make_ascii_uppercase();
+                normalized[i + 2].make_ascii_uppercase();
+                i += 3;
+            } else {
+                i += 1;
+            }
+        }
+        unsafe { String::from_utf8_unchecked(normalized) }
+    }
+}
+
+impl_parse!(
+    RegName,
+    "A percent-encoded registered name: `example.com` or `my_host`. Names are normalized to lowercase, with uppercase",
+    "percent-encoded hex digits. The name may be empty."
+);
+
+impl_parse_string!(
+    RegName,
+    "A percent-encoded registered name: `example.com` or `my_host`. Names are normalized to lowercase, with uppercase",
+    "percent-encoded hex digits. The name may be empty."
+);
+
+impl TryFrom<Vec<u8>> for RegName {
+    type Error = InvalidAddressError<Vec<u8>>;
+
+    /// A percent-encoded registered name: `example.com` or `my_host`. Names are normalized to lowercase, with uppercase
+    /// percent-encoded hex digits. The name may be empty.
+    /// The error contains the unmodified `text`, which `TryFrom<String>` soundly recovers as a string.
+    fn try_from(text: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::parse_text(text.as_slice()).map_err(|error| InvalidAddressError::new(text, error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::InvalidHost;
+    use crate::{ParseError, RegName};
+    use alloc::string::ToString;
+    use core::str::FromStr;
+
+    #[test]
+    fn parse() {
+        let test_cases: &[(&str, Result<&str, ParseError>)] = &[
+            ("", Ok("")),
+            ("example.com", Ok("example.com")),
+            ("Example.COM", Ok("example.com")),
+            ("my_host", Ok("my_host")),
+            ("ex%c3%a4mple", Ok("ex%C3%A4mple")),
+            ("a!$&'()*+,;=b", Ok("a!$&'()*+,;=b")),
+            ("256.0.0.1", Ok("256.0.0.1")),
+            ("ex ample", Err(InvalidHost)),
+            ("ex%2", Err(InvalidHost)),
+            ("ex%zz", Err(InvalidHost)),
+            ("user@host", Err(InvalidHost)),
+            ("[::1]", Err(InvalidHost)),
+            ("ü", Err(InvalidHost)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<RegName, ParseError> = RegName::from_str(input);
+            assert_eq!(
+                result.as_ref().map(RegName::name).map_err(|e| *e),
+                *expected,
+                "input={}",
+                input
+            );
+
+            let result: Result<RegName, ParseError> = RegName::try_from(input.to_string()).map_err(|e| e.error());
+            assert_eq!(
+                result.as_ref().map(RegName::name).map_err(|e| *e),
+                *expected,
+                "input={}",
+                input
+            );
+        }
+    }
+}
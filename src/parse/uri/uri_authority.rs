@@ -0,0 +1,164 @@
+use crate::ParseError::{InvalidHost, InvalidUserInfo};
+use crate::{
+    InvalidAddressError, ParseError, UriAuthority, UriHost, impl_parse, impl_parse_string, is_percent_encoded,
+    is_sub_delim, is_unreserved, parse_port_number, percent_decode,
+};
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+impl UriAuthority {
+    //! Validation
+
+    /// Checks if the `userinfo` is valid: percent-encoded unreserved characters, sub-delimiters, & `:`, decoding to
+    /// UTF-8.
+    pub(crate) fn is_valid_userinfo(userinfo: &[u8]) -> bool {
+        is_percent_encoded(userinfo, |c| is_unreserved(c) || is_sub_delim(c) || c == b':')
+            && core::str::from_utf8(percent_decode(userinfo).as_slice()).is_ok()
+    }
+}
+
+impl UriAuthority {
+    //! Parse
+
+    /// Optional user-info & `@`, a host, & an optional decimal port: `user:pass@[::1]:5432` or `git@github.com`.
+    /// The host syntax matches [`UriHost::parse_text`]. An empty port is absent: `example.com:` has no port.
+    /// The user-info is percent-encoded & must decode to UTF-8.
+    pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+        let (userinfo, host_port): (Option<&[u8]>, &[u8]) = match text.iter().position(|c| *c == b'@') {
+            Some(at) => (Some(&text[..at]), &text[at + 1..]),
+            None => (None, text),
+        };
+
+        let userinfo: Option<String> = match userinfo {
+            Some(userinfo) if Self::is_valid_userinfo(userinfo) => {
+                Some(unsafe { core::str::from_utf8_unchecked(userinfo) }.to_string())
+            }
+            Some(_) => return Err(InvalidUserInfo),
+            None => None,
+        };
+
+        let host_end: usize = match host_port.iter().position(|c| *c == b']') {
+            Some(bracket) if host_port.first() == Some(&b'[') => bracket + 1,
+            _ => host_port.iter().rposition(|c| *c == b':').unwrap_or(host_port.len()),
+        };
+        let host: UriHost = UriHost::parse_text(&host_port[..host_end])?;
+        let port: Option<u16> = match &host_port[host_end..] {
+            [] | [b':'] => None,
+            [b':', port @ ..] => Some(parse_port_number(port)?),
+            _ => return Err(InvalidHost),
+        };
+
+        Ok(unsafe { Self::new_unchecked(userinfo, host, port) })
+    }
+}
+
+impl_parse!(
+    UriAuthority,
+    "Optional user-info & `@`, a host, & an optional decimal port: `user:pass@[::1]:5432` or `git@github.com`.",
+    "The host syntax matches [`UriHost::parse_text`]. An empty port is absent: `example.com:` has no port.",
+    "The user-info is percent-encoded & must decode to UTF-8."
+);
+
+impl_parse_string!(
+    UriAuthority,
+    "Optional user-info & `@`, a host, & an optional decimal port: `user:pass@[::1]:5432` or `git@github.com`.",
+    "The host syntax matches [`UriHost::parse_text`]. An empty port is absent: `example.com:` has no port.",
+    "The user-info is percent-encoded & must decode to UTF-8."
+);
+
+impl TryFrom<Vec<u8>> for UriAuthority {
+    type Error = InvalidAddressError<Vec<u8>>;
+
+    /// Optional user-info & `@`, a host, & an optional decimal port: `user:pass@[::1]:5432` or `git@github.com`.
+    /// The host syntax matches [`UriHost::parse_text`]. An empty port is absent: `example.com:` has no port.
+    /// The user-info is percent-encoded & must decode to UTF-8.
+    /// The error contains the unmodified `text`, which `TryFrom<String>` soundly recovers as a string.
+    fn try_from(text: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::parse_text(text.as_slice()).map_err(|error| InvalidAddressError::new(text, error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::{InvalidHost, InvalidIPv6Address, InvalidPort, InvalidUserInfo};
+    use crate::{IPv4Address, IPv6Address, IPvFuture, ParseError, RegName, UriAuthority, UriHost};
+    use alloc::string::ToString;
+    use core::str::FromStr;
+
+    type TestCase<'a> = (&'a str, Result<(Option<&'a str>, UriHost, Option<u16>), ParseError>);
+
+    #[test]
+    fn parse() {
+        let name = |name: &str| UriHost::Name(RegName::try_from(name).unwrap());
+        let localhost_v6: UriHost = UriHost::Address(IPv6Address::LOCALHOST.to_ip());
+        let test_cases: &[TestCase] = &[
+            ("", Ok((None, name(""), None))),
+            ("example.com", Ok((None, name("example.com"), None))),
+            ("Example.com:443", Ok((None, name("example.com"), Some(443)))),
+            ("example.com:", Ok((None, name("example.com"), None))),
+            ("git@github.com", Ok((Some("git"), name("github.com"), None))),
+            (
+                "user:pass@[::1]:5432",
+                Ok((Some("user:pass"), localhost_v6.clone(), Some(5432))),
+            ),
+            ("user:pass@[::1]", Ok((Some("user:pass"), localhost_v6.clone(), None))),
+            ("@[::1]:", Ok((Some(""), localhost_v6.clone(), None))),
+            (
+                "p%40ss@127.0.0.1:80",
+                Ok((Some("p%40ss"), IPv4Address::LOCALHOST.to_ip().into(), Some(80))),
+            ),
+            (
+                "[v1.x]:80",
+                Ok((None, UriHost::Future(IPvFuture::try_from("v1.x").unwrap()), Some(80))),
+            ),
+            ("my_host:8080", Ok((None, name("my_host"), Some(8080)))),
+            ("a@b@example.com", Err(InvalidHost)),
+            ("us er@example.com", Err(InvalidUserInfo)),
+            ("%FF@example.com", Err(InvalidUserInfo)),
+            ("%F@example.com", Err(InvalidUserInfo)),
+            ("example.com:+80", Err(InvalidPort)),
+            ("example.com:65536", Err(InvalidPort)),
+            ("example.com:http", Err(InvalidPort)),
+            ("::1", Err(InvalidHost)),
+            ("[::1]x", Err(InvalidHost)),
+            ("[::1]:x", Err(InvalidPort)),
+            ("[::1", Err(InvalidHost)),
+            ("[fe80::1%251]:80", Err(InvalidIPv6Address)),
+            ("example.com/path", Err(InvalidHost)),
+        ];
+
+        for (input, expected) in test_cases {
+            let expected: Result<UriAuthority, ParseError> = expected.clone().map(|(userinfo, host, port)| unsafe {
+                UriAuthority::new_unchecked(userinfo.map(str::to_string), host, port)
+            });
+
+            let result: Result<UriAuthority, ParseError> = UriAuthority::from_str(input);
+            assert_eq!(result, expected, "input={}", input);
+
+            let result: Result<UriAuthority, ParseError> =
+                UriAuthority::try_from(input.to_string()).map_err(|e| e.error());
+            assert_eq!(result, expected, "input={}", input);
+        }
+    }
+
+    /// Each canonical string must parse and display back to the exact same string.
+    #[test]
+    fn round_trip() {
+        let canonical: &[&str] = &[
+            "example.com",
+            "example.com:443",
+            "git@github.com",
+            "user:pass@[::1]:5432",
+            "p%40ss@127.0.0.1:80",
+            "[v1.x]:80",
+            "ex%C3%A4mple",
+            "",
+        ];
+
+        for input in canonical {
+            let value: UriAuthority = UriAuthority::from_str(input).unwrap();
+            assert_eq!(value.to_string(), *input, "input={}", input);
+        }
+    }
+}
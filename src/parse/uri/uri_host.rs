@@ -0,0 +1,136 @@
+use crate::ParseError::{InvalidHost, InvalidIPv6Address};
+use crate::{
+    IPv4Address, IPv6Address, IPvFuture, InvalidAddressError, ParseError, RegName, UriHost, impl_parse,
+    impl_parse_string,
+};
+use alloc::vec::Vec;
+
+impl UriHost {
+    //! Parse
+
+    /// An IPv4 address, a bracketed IPv6 or IPvFuture literal, or a percent-encoded registered name:
+    /// `127.0.0.1`, `[::1]`, `[v1.x]`, or `example.com`. Registered names are normalized to lowercase, with uppercase
+    /// percent-encoded hex digits. IPv6 zones are invalid.
+    pub fn parse_text(text: &[u8]) -> Result<Self, ParseError> {
+        if let Some(literal) = text.strip_prefix(b"[") {
+            let literal: &[u8] = literal.strip_suffix(b"]").ok_or(InvalidHost)?;
+            if matches!(literal.first(), Some(b'v' | b'V')) {
+                IPvFuture::parse_text(literal).map(Self::Future)
+            } else {
+                IPv6Address::parse_text(literal)
+                    .map(|ip| Self::Address(ip.to_ip()))
+                    .map_err(|_| InvalidIPv6Address)
+            }
+        } else if let Ok(ip) = IPv4Address::parse_text(text) {
+            Ok(Self::Address(ip.to_ip()))
+        } else {
+            RegName::parse_text(text).map(Self::Name)
+        }
+    }
+}
+
+impl_parse!(
+    UriHost,
+    "An IPv4 address, a bracketed IPv6 or IPvFuture literal, or a percent-encoded registered name:",
+    "`127.0.0.1`, `[::1]`, `[v1.x]`, or `example.com`. Registered names are normalized to lowercase, with uppercase",
+    "percent-encoded hex digits. IPv6 zones are invalid."
+);
+
+impl_parse_string!(
+    UriHost,
+    "An IPv4 address, a bracketed IPv6 or IPvFuture literal, or a percent-encoded registered name:",
+    "`127.0.0.1`, `[::1]`, `[v1.x]`, or `example.com`. Registered names are normalized to lowercase, with uppercase",
+    "percent-encoded hex digits. IPv6 zones are invalid."
+);
+
+impl TryFrom<Vec<u8>> for UriHost {
+    type Error = InvalidAddressError<Vec<u8>>;
+
+    /// An IPv4 address, a bracketed IPv6 or IPvFuture literal, or a percent-encoded registered name:
+    /// `127.0.0.1`, `[::1]`, `[v1.x]`, or `example.com`. Registered names are normalized to lowercase, with uppercase
+    /// percent-encoded hex digits. IPv6 zones are invalid.
+    /// The error contains the unmodified `text`, which `TryFrom<String>` soundly recovers as a string.
+    fn try_from(text: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::parse_text(text.as_slice()).map_err(|error| InvalidAddressError::new(text, error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::{InvalidHost, InvalidIPv6Address};
+    use crate::{IPv4Address, IPv6Address, IPvFuture, ParseError, RegName, UriHost};
+    use alloc::string::ToString;
+    use core::str::FromStr;
+
+    #[test]
+    fn parse() {
+        let test_cases: &[(&str, Result<UriHost, ParseError>)] = &[
+            ("", Ok(UriHost::Name(RegName::try_from("").unwrap()))),
+            (
+                "example.com",
+                Ok(UriHost::Name(RegName::try_from("example.com").unwrap())),
+            ),
+            (
+                "Example.COM",
+                Ok(UriHost::Name(RegName::try_from("example.com").unwrap())),
+            ),
+            ("my_host", Ok(UriHost::Name(RegName::try_from("my_host").unwrap()))),
+            (
+                "ex%c3%a4mple",
+                Ok(UriHost::Name(RegName::try_from("ex%C3%A4mple").unwrap())),
+            ),
+            (
+                "a!$&'()*+,;=b",
+                Ok(UriHost::Name(RegName::try_from("a!$&'()*+,;=b").unwrap())),
+            ),
+            ("256.0.0.1", Ok(UriHost::Name(RegName::try_from("256.0.0.1").unwrap()))),
+            ("127.0.0.1", Ok(UriHost::Address(IPv4Address::LOCALHOST.to_ip()))),
+            ("[::1]", Ok(UriHost::Address(IPv6Address::LOCALHOST.to_ip()))),
+            (
+                "[v1.fe80::a+en1]",
+                Ok(UriHost::Future(IPvFuture::try_from("v1.fe80::a+en1").unwrap())),
+            ),
+            ("[VF.x]", Ok(UriHost::Future(IPvFuture::try_from("VF.x").unwrap()))),
+            ("[v.x]", Err(InvalidHost)),
+            ("[v1.]", Err(InvalidHost)),
+            ("[v1x]", Err(InvalidHost)),
+            ("[vg.x]", Err(InvalidHost)),
+            ("[::1", Err(InvalidHost)),
+            ("[]", Err(InvalidIPv6Address)),
+            ("[127.0.0.1]", Err(InvalidIPv6Address)),
+            ("[fe80::1%25eth0]", Err(InvalidIPv6Address)),
+            ("::1", Err(InvalidHost)),
+            ("ex ample", Err(InvalidHost)),
+            ("ex%2", Err(InvalidHost)),
+            ("user@host", Err(InvalidHost)),
+            ("ü", Err(InvalidHost)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<UriHost, ParseError> = UriHost::from_str(input);
+            assert_eq!(result, *expected, "input={}", input);
+
+            let result: Result<UriHost, ParseError> = UriHost::try_from(input.to_string()).map_err(|e| e.error());
+            assert_eq!(result, *expected, "input={}", input);
+        }
+    }
+
+    /// Each canonical string must parse and display back to the exact same string.
+    #[test]
+    fn round_trip() {
+        let canonical: &[&str] = &[
+            "example.com",
+            "my_host",
+            "ex%C3%A4mple",
+            "127.0.0.1",
+            "[::1]",
+            "[v1.x]",
+            "",
+        ];
+
+        for input in canonical {
+            let value: UriHost = UriHost::from_str(input).unwrap();
+            assert_eq!(value.to_string(), *input, "input={}", input);
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use crate::IPv4Address;
+use alloc::vec::Vec;
+
+/// Checks if the ASCII `domain` ends in a number, so the WHATWG host parser reads it as an IPv4 address.
+///
+/// See [ends in a number](https://url.spec.whatwg.org/#ends-in-a-number-checker).
+pub(crate) fn ends_in_a_number(domain: &str) -> bool {
+    let mut parts: Vec<&str> = domain.split('.').collect();
+    if parts.last() == Some(&"") {
+        if parts.len() == 1 {
+            return false;
+        }
+        parts.pop();
+    }
+    let last: &str = parts.last().copied().unwrap_or_default();
+    (!last.is_empty() && last.bytes().all(|c| c.is_ascii_digit())) || parse_ipv4_number(last).is_some()
+}
+
+/// Parses the WHATWG IPv4 address `text`: 1 to 4 decimal, octal, or hex numbers, the last filling the remaining bytes.
+///
+/// Returns `None` on failure. See [IPv4 parser](https://url.spec.whatwg.org/#concept-ipv4-parser).
+///
+/// # Examples
+/// `127.1`       -> `Some(127.0.0.1)`
+/// `0x7f.1`      -> `Some(127.0.0.1)`
+/// `2130706433`  -> `Some(127.0.0.1)`
+/// `0177.0.0.01` -> `Some(127.0.0.1)`
+/// `1.2.3.256`   -> `None`
+pub(crate) fn parse_ipv4(text: &str) -> Option<IPv4Address> {
+    let mut parts: Vec<&str> = text.split('.').collect();
+    if parts.last() == Some(&"") && parts.len() > 1 {
+        parts.pop();
+    }
+    if parts.len() > 4 {
+        return None;
+    }
+
+    let numbers: Vec<u64> = parts.into_iter().map(parse_ipv4_number).collect::<Option<Vec<u64>>>()?;
+    let (last, rest): (&u64, &[u64]) = numbers.split_last()?;
+    if rest.iter().any(|n| *n > 255) || *last >= 256u64.pow(5 - numbers.len() as u32) {
+        return None;
+    }
+
+    let address: u64 = rest
+        .iter()
+        .enumerate()
+        .fold(*last, |address, (i, n)| address + n * 256u64.pow(3 - i as u32));
+    Some(IPv4Address::from(address as u32))
+}
+
+/// Parses the WHATWG IPv4 `number`: decimal, octal with a leading `0`, or hex with a leading `0x`.
+///
+/// Numbers too large for a `u64` saturate, which the callers' range checks then reject.
+/// See [IPv4 number parser](https://url.spec.whatwg.org/#ipv4-number-parser).
+fn parse_ipv4_number(number: &str) -> Option<u64> {
+    if number.is_empty() {
+        return None;
+    }
+    let (digits, radix): (&str, u32) = if let Some(hex) = number.strip_prefix("0x").or(number.strip_prefix("0X")) {
+        (hex, 16)
+    } else if number.len() >= 2
+        && let Some(octal) = number.strip_prefix('0')
+    {
+        (octal, 8)
+    } else {
+        (number, 10)
+    };
+    if digits.is_empty() {
+        Some(0)
+    } else if digits.chars().all(|c| c.is_digit(radix)) {
+        Some(u64::from_str_radix(digits, radix).unwrap_or(u64::MAX))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IPv4Address;
+    use crate::parse::whatwg::ipv4::{ends_in_a_number, parse_ipv4, parse_ipv4_number};
+
+    #[test]
+    fn ipv4_numbers() {
+        let test_cases: &[(&str, Option<u64>)] = &[
+            ("", None),
+            ("0", Some(0)),
+            ("10", Some(10)),
+            ("010", Some(8)),
+            ("0x10", Some(16)),
+            ("0X1f", Some(31)),
+            ("0x", Some(0)),
+            ("00", Some(0)),
+            ("08", None),
+            ("0xg", None),
+            ("1a", None),
+            ("-1", None),
+            ("99999999999999999999999", Some(u64::MAX)),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(parse_ipv4_number(input), *expected, "input={}", input);
+        }
+    }
+
+    #[test]
+    fn ends_in_number() {
+        let test_cases: &[(&str, bool)] = &[
+            ("", false),
+            (".", false),
+            ("example.com", false),
+            ("127.0.0.1", true),
+            ("127.0.0.1.", true),
+            ("foo.09", true),
+            ("foo.0x", true),
+            ("foo.0xg", false),
+            ("foo.09..", false),
+            ("1a", false),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(ends_in_a_number(input), *expected, "input={}", input);
+        }
+    }
+
+    #[test]
+    fn ipv4() {
+        let test_cases: &[(&str, Option<[u8; 4]>)] = &[
+            ("127.0.0.1", Some([127, 0, 0, 1])),
+            ("127.1", Some([127, 0, 0, 1])),
+            ("127.0.1", Some([127, 0, 0, 1])),
+            ("0x7f.1", Some([127, 0, 0, 1])),
+            ("0177.0.0.01", Some([127, 0, 0, 1])),
+            ("2130706433", Some([127, 0, 0, 1])),
+            ("0x7f000001", Some([127, 0, 0, 1])),
+            ("127.0.0.1.", Some([127, 0, 0, 1])),
+            ("4294967295", Some([255, 255, 255, 255])),
+            ("4294967296", None),
+            ("127.16777216", None),
+            ("256.0.0.1", None),
+            ("1.2.3.4.5", None),
+            ("1..2", None),
+            ("", None),
+        ];
+
+        for (input, expected) in test_cases {
+            let expected: Option<IPv4Address> = expected.map(IPv4Address::from);
+            assert_eq!(parse_ipv4(input), expected, "input={}", input);
+        }
+    }
+}
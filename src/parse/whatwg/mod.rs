@@ -0,0 +1,5 @@
+mod ipv4;
+mod whatwg_host;
+
+#[cfg(test)]
+mod wpt_host_cases;
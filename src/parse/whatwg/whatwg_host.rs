@@ -0,0 +1,257 @@
+use crate::ParseError::{InvalidDomain, InvalidHost, InvalidIPv4Address, InvalidIPv6Address};
+use crate::parse::whatwg::ipv4::{ends_in_a_number, parse_ipv4};
+use crate::{Domain, Host, HostRef, IPAddress, IPv6Address, ParseError, WhatwgHost, percent_decode};
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use idna::AsciiDenyList;
+
+impl Host {
+    //! WHATWG Parse
+
+    /// Parses the `text` with the [WHATWG host parser](https://url.spec.whatwg.org/#host-parsing) for special
+    /// schemes, the way browsers & `fetch` read the host of an `http` URL.
+    ///
+    /// Unlike [`Self::parse_text`], the text is percent-decoded & mapped with UTS 46 (`Ｇｏ.com` is `go.com`), a
+    /// bracketed IPv6 address is required for IPv6, & a domain ending in a number is read as a WHATWG IPv4 address
+    /// with decimal, octal, & hex parts (`0x7f.1`, `127.1`, & `2130706433` are all `127.0.0.1`).
+    ///
+    /// Browsers accept some domains that a [Domain] cannot hold, such as `a_b.example` or `example.com.`; those are
+    /// an `InvalidDomain` error, so a filter built on this parser fails closed. The URL parser removes ASCII tabs &
+    /// newlines before it parses the host; callers holding a host from a raw URL should do the same.
+    pub fn parse_whatwg(text: &str) -> Result<Self, ParseError> {
+        if let Some(literal) = text.strip_prefix('[') {
+            return Self::parse_whatwg_ipv6(literal);
+        }
+        if text.is_empty() {
+            return Err(InvalidHost);
+        }
+
+        let decoded: Vec<u8> = percent_decode(text.as_bytes());
+        let domain: Cow<str> = String::from_utf8_lossy(decoded.as_slice());
+        let ascii: Cow<str> =
+            idna::domain_to_ascii_cow(domain.as_bytes(), AsciiDenyList::URL).map_err(|_| InvalidDomain)?;
+        if ascii.is_empty() {
+            Err(InvalidDomain)
+        } else if ends_in_a_number(ascii.as_ref()) {
+            parse_ipv4(ascii.as_ref())
+                .map(|ip| ip.to_host())
+                .ok_or(InvalidIPv4Address)
+        } else {
+            Domain::parse_text(ascii.as_bytes()).map(Domain::to_host)
+        }
+    }
+
+    /// Parses the bracketed IPv6 `literal`, after its opening bracket.
+    fn parse_whatwg_ipv6(literal: &str) -> Result<Self, ParseError> {
+        let literal: &str = literal.strip_suffix(']').ok_or(InvalidIPv6Address)?;
+        IPv6Address::parse_text(literal.as_bytes())
+            .map(|ip| ip.to_host())
+            .map_err(|_| InvalidIPv6Address)
+    }
+}
+
+impl WhatwgHost {
+    //! Parse
+
+    /// Parses the `text` with the [WHATWG host parser](https://url.spec.whatwg.org/#host-parsing).
+    ///
+    /// When `is_opaque` is false, for special schemes such as `http`, this is [`Host::parse_whatwg`]. When it is true,
+    /// for other schemes, a bracketed IPv6 address is still a [Host], & other text is an opaque host: it must not
+    /// contain a forbidden host code point & is percent-encoded with the C0 control percent-encode set.
+    pub fn parse_text(text: &str, is_opaque: bool) -> Result<Self, ParseError> {
+        if !is_opaque {
+            Host::parse_whatwg(text).map(Self::Host)
+        } else if let Some(literal) = text.strip_prefix('[') {
+            Host::parse_whatwg_ipv6(literal).map(Self::Host)
+        } else if text.chars().any(Self::is_forbidden_host_code_point) {
+            Err(InvalidHost)
+        } else {
+            let mut opaque: String = String::with_capacity(text.len());
+            for c in text.chars() {
+                if c.is_ascii_control() || c as u32 > 0x7E {
+                    let mut buffer: [u8; 4] = [0; 4];
+                    for byte in c.encode_utf8(&mut buffer).bytes() {
+                        write!(opaque, "%{:02X}", byte).expect("writing to a string cannot fail");
+                    }
+                } else {
+                    opaque.push(c);
+                }
+            }
+            Ok(Self::Opaque(opaque))
+        }
+    }
+
+    /// Checks if the character is a [forbidden host code point](https://url.spec.whatwg.org/#forbidden-host-code-point).
+    fn is_forbidden_host_code_point(c: char) -> bool {
+        matches!(
+            c,
+            '\0' | '\t' | '\n' | '\r' | ' ' | '#' | '/' | ':' | '<' | '>' | '?' | '@' | '[' | '\\' | ']' | '^' | '|'
+        )
+    }
+}
+
+impl<'a> HostRef<'a> {
+    //! WHATWG Serialization
+
+    /// Serializes the host with the [WHATWG host serializer](https://url.spec.whatwg.org/#host-serializing).
+    ///
+    /// IPv6 addresses are bracketed & never use the dotted IPv4 form: `[::ffff:7f00:1]`.
+    #[must_use]
+    pub fn to_whatwg_string(self) -> String {
+        match self {
+            Self::Name(domain) => domain.name().to_string(),
+            Self::Address(IPAddress::V4(ip)) => ip.to_string(),
+            Self::Address(IPAddress::V6(ip)) => {
+                let segments: [u16; 8] = ip.segments();
+                let compress: Option<usize> = Self::whatwg_compress(&segments);
+                let mut text: String = String::from("[");
+                let mut i: usize = 0;
+                while i < 8 {
+                    if Some(i) == compress {
+                        text.push_str(if i == 0 { "::" } else { ":" });
+                        while i < 8 && segments[i] == 0 {
+                            i += 1;
+                        }
+                        continue;
+                    }
+                    write!(text, "{:x}", segments[i]).expect("writing to a string cannot fail");
+                    if i != 7 {
+                        text.push(':');
+                    }
+                    i += 1;
+                }
+                text.push(']');
+                text
+            }
+        }
+    }
+
+    /// Finds the start of the first longest run of 2 or more zero `segments`, which the serialization compresses.
+    fn whatwg_compress(segments: &[u16; 8]) -> Option<usize> {
+        let mut best: Option<(usize, usize)> = None;
+        let mut i: usize = 0;
+        while i < 8 {
+            if segments[i] == 0 {
+                let start: usize = i;
+                while i < 8 && segments[i] == 0 {
+                    i += 1;
+                }
+                let len: usize = i - start;
+                if len >= 2 && best.is_none_or(|(_, best_len)| len > best_len) {
+                    best = Some((start, len));
+                }
+            } else {
+                i += 1;
+            }
+        }
+        best.map(|(start, _)| start)
+    }
+}
+
+impl Host {
+    //! WHATWG Serialization
+
+    /// Serializes the host with the [WHATWG host serializer](https://url.spec.whatwg.org/#host-serializing).
+    ///
+    /// IPv6 addresses are bracketed & never use the dotted IPv4 form: `[::ffff:7f00:1]`.
+    #[must_use]
+    pub fn to_whatwg_string(&self) -> String {
+        self.to_ref().to_whatwg_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError::{InvalidDomain, InvalidHost, InvalidIPv4Address, InvalidIPv6Address};
+    use crate::{Domain, Host, IPv4Address, IPv6Address, ParseError, WhatwgHost};
+    use alloc::string::String;
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse_whatwg() {
+        let test_cases: &[(&str, Result<&str, ParseError>)] = &[
+            ("", Err(InvalidHost)),
+            ("example.com", Ok("example.com")),
+            ("EXAMPLE.com", Ok("example.com")),
+            ("0x7f.1", Ok("127.0.0.1")),
+            ("127.1", Ok("127.0.0.1")),
+            ("2130706433", Ok("127.0.0.1")),
+            ("%31%32%37.0.0.1", Ok("127.0.0.1")),
+            ("127.0.0.1.", Ok("127.0.0.1")),
+            ("1.2.3.256", Err(InvalidIPv4Address)),
+            ("example.09", Err(InvalidIPv4Address)),
+            ("[::1]", Ok("[::1]")),
+            ("[::1", Err(InvalidIPv6Address)),
+            ("[fe80::1%251]", Err(InvalidIPv6Address)),
+            ("::1", Err(InvalidDomain)),
+            ("bücher.example", Ok("xn--bcher-kva.example")),
+            ("a_b.example", Err(InvalidDomain)),
+            ("example.com.", Err(InvalidDomain)),
+            ("exa mple.com", Err(InvalidDomain)),
+            ("exa%mple.com", Err(InvalidDomain)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<String, ParseError> = Host::parse_whatwg(input).map(|host| host.to_whatwg_string());
+            let expected: Result<String, ParseError> = expected.map(str::to_string);
+            assert_eq!(result, expected, "input={}", input);
+        }
+    }
+
+    #[test]
+    fn parse_opaque() {
+        let test_cases: &[(&str, Result<WhatwgHost, ParseError>)] = &[
+            ("", Ok(WhatwgHost::Opaque(String::new()))),
+            ("Example.COM", Ok(WhatwgHost::Opaque("Example.COM".to_string()))),
+            ("a_b%zz", Ok(WhatwgHost::Opaque("a_b%zz".to_string()))),
+            ("bü\u{7F}", Ok(WhatwgHost::Opaque("b%C3%BC%7F".to_string()))),
+            ("[::1]", Ok(WhatwgHost::Host(IPv6Address::LOCALHOST.to_host()))),
+            ("127.1", Ok(WhatwgHost::Opaque("127.1".to_string()))),
+            ("a b", Err(InvalidHost)),
+            ("a@b", Err(InvalidHost)),
+            ("[x]", Err(InvalidIPv6Address)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<WhatwgHost, ParseError> = WhatwgHost::parse_text(input, true);
+            assert_eq!(result, *expected, "input={}", input);
+        }
+
+        let result: Result<WhatwgHost, ParseError> = WhatwgHost::parse_text("127.1", false);
+        assert_eq!(result, Ok(WhatwgHost::Host(IPv4Address::LOCALHOST.to_host())));
+    }
+
+    #[test]
+    fn to_whatwg_string() {
+        let test_cases: &[(Host, &str)] = &[
+            (Domain::example().to_host(), "example.com"),
+            (IPv4Address::LOCALHOST.to_host(), "127.0.0.1"),
+            (IPv6Address::LOCALHOST.to_host(), "[::1]"),
+            (IPv6Address::UNSPECIFIED.to_host(), "[::]"),
+            (
+                IPv6Address::from_segments([0, 0, 0, 0, 0, 0xFFFF, 0x7F00, 1]).to_host(),
+                "[::ffff:7f00:1]",
+            ),
+            (
+                IPv6Address::from_segments([1, 0, 0, 2, 0, 0, 0, 3]).to_host(),
+                "[1:0:0:2::3]",
+            ),
+            (
+                IPv6Address::from_segments([1, 0, 0, 2, 0, 0, 3, 4]).to_host(),
+                "[1::2:0:0:3:4]",
+            ),
+            (
+                IPv6Address::from_segments([1, 0, 2, 0, 3, 0, 4, 0]).to_host(),
+                "[1:0:2:0:3:0:4:0]",
+            ),
+            (IPv6Address::from_segments([1, 0, 0, 0, 0, 0, 0, 0]).to_host(), "[1::]"),
+        ];
+
+        for (host, expected) in test_cases {
+            assert_eq!(host.to_whatwg_string(), *expected, "host={:?}", host);
+        }
+    }
+}
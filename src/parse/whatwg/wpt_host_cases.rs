@@ -0,0 +1,112 @@
+//! Host cases vendored from the web-platform-tests `url/resources/urltestdata.json` & `toascii.json` files.
+//!
+//! Each case is the host text of a URL & its expected serialized host, or `None` when the URL fails to parse. The
+//! cases whose host is valid for browsers but cannot be held by a [Domain](crate::Domain) are listed separately.
+
+use crate::{Host, WhatwgHost};
+use alloc::string::String;
+use alloc::string::ToString;
+
+/// Hosts of URLs with a special scheme (`http://<host>/`).
+const SPECIAL: &[(&str, Option<&str>)] = &[
+    // IPv4 numbers
+    ("192.0x00A80001", Some("192.168.0.1")),
+    ("0Xc0.0250.01", Some("192.168.0.1")),
+    ("%30%78%63%30%2e%30%32%35%30.01", Some("192.168.0.1")),
+    ("%30%78%63%30%2e%30%32%35%30.01%2e", Some("192.168.0.1")),
+    ("0x.0x.0", Some("0.0.0.0")),
+    ("4294967295", Some("255.255.255.255")),
+    ("0xffffffff", Some("255.255.255.255")),
+    ("192.168.0.257", None),
+    ("0x100000000", None),
+    ("4294967296", None),
+    ("0xffffffff1", None),
+    ("256.256.256.256", None),
+    ("1.2.3.4.5", None),
+    ("1.2.3.4.5.", None),
+    ("0..0x300", None),
+    ("1.2.3.09", None),
+    ("09.2.3.4", None),
+    ("01.2.3.4.5", None),
+    ("foo.09", None),
+    ("foo.0x4", None),
+    ("0999999999999999999", None),
+    ("foo.0x", None),
+    ("foo.0XFfFfFfFfFfFfFfFfFfAcE123", None),
+    ("💩.123", None),
+    // Domains
+    ("ExAmPlE.CoM", Some("example.com")),
+    ("www.foo。bar.com", Some("www.foo.bar.com")),
+    ("Ｇｏ.com", Some("go.com")),
+    ("你好你好", Some("xn--6qqa088eba")),
+    ("faß.ExAmPlE", Some("xn--fa-hia.example")),
+    ("%zz%66%a.com", None),
+    ("%25", None),
+    ("hello%00", None),
+    ("GOO\u{a0}\u{3000}goo.com", None),
+    ("\u{FDD0}zyx.com", None),
+    ("%ef%b7%90zyx.com", None),
+    ("％４１.com", None),
+    ("%ef%bc%85%ef%bc%94%ef%bc%91.com", None),
+    ("％００.com", None),
+    ("%ef%bc%85%ef%bc%90%ef%bc%90.com", None),
+    ("a.b.c.xn--pokxncvks", None),
+    ("10.0.0.xn--pokxncvks", None),
+    ("\u{ad}", None),
+    ("a<b", None),
+    ("a>b", None),
+    ("a^b", None),
+    ("192.168.0.1 hello", None),
+    ("x x", None),
+    // IPv6 addresses
+    ("[::127.0.0.1]", Some("[::7f00:1]")),
+    ("[0:0:0:0:0:0:13.1.68.3]", Some("[::d01:4403]")),
+    ("[1:0::]", Some("[1::]")),
+    ("[0:1:0:1:0:1:0:1]", Some("[0:1:0:1:0:1:0:1]")),
+    ("[1:0:1:0:1:0:1:0]", Some("[1:0:1:0:1:0:1:0]")),
+    ("[2001::1]", Some("[2001::1]")),
+    ("[www.google.com]", None),
+    ("[google.com]", None),
+    ("[::1.2.3.4x]", None),
+    ("[::1.2.3.]", None),
+    ("[::1.2.]", None),
+    ("[::1.]", None),
+    ("[]", None),
+    ("[:]", None),
+];
+
+/// Hosts that browsers accept but a [Domain](crate::Domain) cannot hold: empty labels, a trailing dot, & characters
+/// outside of letters, digits, & dashes.
+const UNREPRESENTABLE: &[&str] = &["foo.09..", "!\"$&'()*+,-.;=_`{}~"];
+
+/// Hosts of URLs with a non-special scheme (`sc://<host>/`).
+const OPAQUE: &[(&str, Option<&str>)] = &[
+    ("faß.ExAmPlE", Some("fa%C3%9F.ExAmPlE")),
+    ("\u{1F}!\"$&'()*+,-.;=_`{}~", Some("%1F!\"$&'()*+,-.;=_`{}~")),
+    ("a<b", None),
+    ("a>b", None),
+    ("a^b", None),
+];
+
+#[test]
+fn special() {
+    for (input, expected) in SPECIAL {
+        let result: Option<String> = Host::parse_whatwg(input).ok().map(|host| host.to_whatwg_string());
+        assert_eq!(result.as_deref(), *expected, "input={}", input);
+    }
+}
+
+#[test]
+fn unrepresentable() {
+    for input in UNREPRESENTABLE {
+        assert!(Host::parse_whatwg(input).is_err(), "input={}", input);
+    }
+}
+
+#[test]
+fn opaque() {
+    for (input, expected) in OPAQUE {
+        let result: Option<String> = WhatwgHost::parse_text(input, true).ok().map(|host| host.to_string());
+        assert_eq!(result.as_deref(), *expected, "input={}", input);
+    }
+}
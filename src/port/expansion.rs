@@ -0,0 +1,189 @@
+#[cfg(feature = "alloc")]
+use crate::{Authority, Domain, Endpoint, Host};
+use crate::{IPAddress, PortRange, PortRangeIter, SocketAddress};
+use core::iter::FusedIterator;
+
+/// An iterator over the socket addresses of an IP address & each port of a [PortRange].
+#[must_use]
+#[derive(Clone, Debug)]
+pub struct SocketAddresses {
+    ip: IPAddress,
+    ports: PortRangeIter,
+}
+
+/// An iterator over the authorities of a host & each port of a [PortRange].
+#[cfg(feature = "alloc")]
+#[must_use]
+#[derive(Clone, Debug)]
+pub struct Authorities {
+    host: Host,
+    ports: PortRangeIter,
+}
+
+/// An iterator over the endpoints of a domain & each port of a [PortRange].
+#[cfg(feature = "alloc")]
+#[must_use]
+#[derive(Clone, Debug)]
+pub struct Endpoints {
+    domain: Domain,
+    ports: PortRangeIter,
+}
+
+impl SocketAddresses {
+    //! Construction
+
+    /// Creates a new iterator over the `ip` with each of the `ports`.
+    pub const fn new(ip: IPAddress, ports: PortRange) -> Self {
+        Self {
+            ip,
+            ports: ports.iter(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Authorities {
+    //! Construction
+
+    /// Creates a new iterator over the `host` with each of the `ports`.
+    pub const fn new(host: Host, ports: PortRange) -> Self {
+        Self {
+            host,
+            ports: ports.iter(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Endpoints {
+    //! Construction
+
+    /// Creates a new iterator over the `domain` with each of the `ports`.
+    pub const fn new(domain: Domain, ports: PortRange) -> Self {
+        Self {
+            domain,
+            ports: ports.iter(),
+        }
+    }
+}
+
+impl Iterator for SocketAddresses {
+    type Item = SocketAddress;
+
+    fn next(&mut self) -> Option<SocketAddress> {
+        self.ports.next().map(|port| self.ip.to_socket(port.value()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ports.size_hint()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Iterator for Authorities {
+    type Item = Authority;
+
+    fn next(&mut self) -> Option<Authority> {
+        self.ports
+            .next()
+            .map(|port| self.host.clone().to_authority(port.value()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ports.size_hint()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Iterator for Endpoints {
+    type Item = Endpoint;
+
+    fn next(&mut self) -> Option<Endpoint> {
+        self.ports
+            .next()
+            .map(|port| self.domain.clone().to_endpoint(port.value()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ports.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for SocketAddresses {
+    fn next_back(&mut self) -> Option<SocketAddress> {
+        self.ports.next_back().map(|port| self.ip.to_socket(port.value()))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl DoubleEndedIterator for Authorities {
+    fn next_back(&mut self) -> Option<Authority> {
+        self.ports
+            .next_back()
+            .map(|port| self.host.clone().to_authority(port.value()))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl DoubleEndedIterator for Endpoints {
+    fn next_back(&mut self) -> Option<Endpoint> {
+        self.ports
+            .next_back()
+            .map(|port| self.domain.clone().to_endpoint(port.value()))
+    }
+}
+
+impl ExactSizeIterator for SocketAddresses {}
+
+#[cfg(feature = "alloc")]
+impl ExactSizeIterator for Authorities {}
+
+#[cfg(feature = "alloc")]
+impl ExactSizeIterator for Endpoints {}
+
+impl FusedIterator for SocketAddresses {}
+
+#[cfg(feature = "alloc")]
+impl FusedIterator for Authorities {}
+
+#[cfg(feature = "alloc")]
+impl FusedIterator for Endpoints {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IPv4Address, Port, PortRange, SocketAddress};
+    use alloc::vec::Vec;
+
+    #[cfg(feature = "alloc")]
+    use crate::{Authority, Domain, Endpoint};
+
+    #[test]
+    fn ip_to_sockets() {
+        let range: PortRange = PortRange::new(Port::new(80), Port::new(82)).unwrap();
+        let result: Vec<SocketAddress> = IPv4Address::LOCALHOST.to_ip().to_sockets(range).collect();
+        let expected: Vec<SocketAddress> = (80..=82)
+            .map(|port| IPv4Address::LOCALHOST.to_ip().to_socket(port))
+            .collect();
+        assert_eq!(result, expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn host_to_authorities() {
+        let range: PortRange = PortRange::new(Port::new(80), Port::new(82)).unwrap();
+        let result: Vec<Authority> = Domain::localhost().to_host().to_authorities(range).rev().collect();
+        let expected: Vec<Authority> = (80..=82)
+            .rev()
+            .map(|port| Domain::localhost().to_host().to_authority(port))
+            .collect();
+        assert_eq!(result, expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn domain_to_endpoints() {
+        let range: PortRange = PortRange::single(Port::new(80));
+        let result: Vec<Endpoint> = Domain::localhost().to_endpoints(range).collect();
+        assert_eq!(result, [Domain::localhost().to_endpoint(80)]);
+    }
+}
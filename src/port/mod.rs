@@ -0,0 +1,11 @@
+pub use expansion::*;
+pub use port::*;
+pub use port_class::*;
+pub use port_range::*;
+pub use port_range_iter::*;
+
+mod expansion;
+mod port;
+mod port_class;
+mod port_range;
+mod port_range_iter;
@@ -0,0 +1,152 @@
+use crate::PortClass;
+
+/// A port number.
+#[must_use]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub struct Port {
+    value: u16,
+}
+
+impl Port {
+    //! Special Ports
+
+    /// The first registered port. (1024)
+    pub const MIN_REGISTERED: Self = Self::new(1024);
+
+    /// The first dynamic port. (49152)
+    pub const MIN_DYNAMIC: Self = Self::new(49152);
+}
+
+impl Port {
+    //! Construction
+
+    /// Creates a new [Port].
+    pub const fn new(value: u16) -> Self {
+        Self { value }
+    }
+}
+
+impl From<u16> for Port {
+    fn from(value: u16) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<Port> for u16 {
+    fn from(port: Port) -> Self {
+        port.value
+    }
+}
+
+impl PartialEq<u16> for Port {
+    fn eq(&self, other: &u16) -> bool {
+        self.value == *other
+    }
+}
+
+impl PartialEq<Port> for u16 {
+    fn eq(&self, other: &Port) -> bool {
+        *self == other.value
+    }
+}
+
+impl Port {
+    //! Properties
+
+    /// Gets the port number.
+    #[must_use]
+    pub const fn value(self) -> u16 {
+        self.value
+    }
+
+    /// Gets the IANA range of the port.
+    #[must_use]
+    pub const fn class(self) -> PortClass {
+        if self.value < Self::MIN_REGISTERED.value {
+            PortClass::WellKnown
+        } else if self.value < Self::MIN_DYNAMIC.value {
+            PortClass::Registered
+        } else {
+            PortClass::Dynamic
+        }
+    }
+}
+
+impl Port {
+    //! Matching
+
+    /// Checks if the port is a well-known (system) port. (0-1023)
+    #[must_use]
+    pub const fn is_well_known(self) -> bool {
+        matches!(self.class(), PortClass::WellKnown)
+    }
+
+    /// Checks if the port is a registered (user) port. (1024-49151)
+    #[must_use]
+    pub const fn is_registered(self) -> bool {
+        matches!(self.class(), PortClass::Registered)
+    }
+
+    /// Checks if the port is a dynamic (private or ephemeral) port. (49152-65535)
+    #[must_use]
+    pub const fn is_dynamic(self) -> bool {
+        matches!(self.class(), PortClass::Dynamic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Port, PortClass};
+
+    #[test]
+    fn construction() {
+        let port: Port = Port::new(80);
+        assert_eq!(port.value, 80);
+
+        let port: Port = 80.into();
+        assert_eq!(port.value, 80);
+    }
+
+    #[test]
+    fn deconstruction() {
+        let result: u16 = Port::new(80).into();
+        assert_eq!(result, 80);
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(Port::new(80), 80);
+        assert_ne!(Port::new(80), 81);
+        assert_eq!(80, Port::new(80));
+    }
+
+    #[test]
+    fn class() {
+        let test_cases: &[(u16, PortClass)] = &[
+            (0, PortClass::WellKnown),
+            (1023, PortClass::WellKnown),
+            (1024, PortClass::Registered),
+            (49151, PortClass::Registered),
+            (49152, PortClass::Dynamic),
+            (65535, PortClass::Dynamic),
+        ];
+
+        for (value, expected) in test_cases {
+            let port: Port = Port::new(*value);
+            assert_eq!(port.class(), *expected, "port={}", value);
+            assert_eq!(
+                port.is_well_known(),
+                *expected == PortClass::WellKnown,
+                "port={}",
+                value
+            );
+            assert_eq!(
+                port.is_registered(),
+                *expected == PortClass::Registered,
+                "port={}",
+                value
+            );
+            assert_eq!(port.is_dynamic(), *expected == PortClass::Dynamic, "port={}", value);
+        }
+    }
+}
@@ -0,0 +1,12 @@
+/// The IANA range of a port. ([RFC 6335](https://www.rfc-editor.org/rfc/rfc6335#section-6))
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum PortClass {
+    /// A well-known (system) port. (0-1023)
+    WellKnown,
+
+    /// A registered (user) port. (1024-49151)
+    Registered,
+
+    /// A dynamic (private or ephemeral) port. (49152-65535)
+    Dynamic,
+}
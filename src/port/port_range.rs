@@ -0,0 +1,198 @@
+use crate::{Port, PortRangeIter};
+
+/// An inclusive, non-empty range of ports. (`8000-8010`)
+#[must_use]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct PortRange {
+    start: Port,
+    end: Port,
+}
+
+impl PortRange {
+    //! Special Ranges
+
+    /// Every port. (0-65535)
+    pub const ALL: Self = Self::from_port_numbers(0, u16::MAX);
+
+    /// The well-known (system) ports. (0-1023)
+    pub const WELL_KNOWN: Self = Self::from_port_numbers(0, Port::MIN_REGISTERED.value() - 1);
+
+    /// The registered (user) ports. (1024-49151)
+    pub const REGISTERED: Self = Self::from_port_numbers(Port::MIN_REGISTERED.value(), Port::MIN_DYNAMIC.value() - 1);
+
+    /// The dynamic (private or ephemeral) ports. (49152-65535)
+    pub const DYNAMIC: Self = Self::from_port_numbers(Port::MIN_DYNAMIC.value(), u16::MAX);
+}
+
+impl PortRange {
+    //! Construction
+
+    /// Creates a new [PortRange] from the inclusive `start` & `end`.
+    ///
+    /// Returns `None` if the `start` exceeds the `end`.
+    #[must_use]
+    pub const fn new(start: Port, end: Port) -> Option<Self> {
+        if start.value() <= end.value() {
+            Some(Self { start, end })
+        } else {
+            None
+        }
+    }
+
+    /// Creates a [PortRange] with the single `port`.
+    pub const fn single(port: Port) -> Self {
+        Self { start: port, end: port }
+    }
+
+    /// Creates a [PortRange] from the ordered `start` & `end` port numbers.
+    const fn from_port_numbers(start: u16, end: u16) -> Self {
+        debug_assert!(start <= end);
+
+        Self {
+            start: Port::new(start),
+            end: Port::new(end),
+        }
+    }
+}
+
+impl From<Port> for PortRange {
+    fn from(port: Port) -> Self {
+        Self::single(port)
+    }
+}
+
+impl From<PortRange> for (Port, Port) {
+    fn from(range: PortRange) -> Self {
+        (range.start, range.end)
+    }
+}
+
+impl PortRange {
+    //! Properties
+
+    /// Gets the first port.
+    pub const fn start(self) -> Port {
+        self.start
+    }
+
+    /// Gets the last port. (inclusive)
+    pub const fn end(self) -> Port {
+        self.end
+    }
+
+    /// Gets the number of ports. (1 to 65536)
+    #[must_use]
+    pub const fn port_count(self) -> u32 {
+        (self.end.value() - self.start.value()) as u32 + 1
+    }
+
+    /// Checks if the range has a single port.
+    #[must_use]
+    pub const fn is_single(self) -> bool {
+        self.start.value() == self.end.value()
+    }
+}
+
+impl PortRange {
+    //! Matching
+
+    /// Checks if the range contains the `port`.
+    #[must_use]
+    pub const fn contains(self, port: Port) -> bool {
+        self.start.value() <= port.value() && port.value() <= self.end.value()
+    }
+
+    /// Checks if the range contains every port of the `other` range.
+    #[must_use]
+    pub const fn contains_range(self, other: Self) -> bool {
+        self.contains(other.start) && self.contains(other.end)
+    }
+
+    /// Checks if the ranges share at least one port.
+    #[must_use]
+    pub const fn overlaps(self, other: Self) -> bool {
+        self.start.value() <= other.end.value() && other.start.value() <= self.end.value()
+    }
+}
+
+impl PortRange {
+    //! Iteration
+
+    /// Gets an iterator over the ports, in ascending order.
+    pub const fn iter(self) -> PortRangeIter {
+        PortRangeIter::new(self)
+    }
+}
+
+impl IntoIterator for PortRange {
+    type Item = Port;
+    type IntoIter = PortRangeIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Port, PortRange};
+
+    #[test]
+    fn specials() {
+        assert_eq!(PortRange::ALL.port_count(), 65536);
+        assert_eq!(PortRange::WELL_KNOWN.end(), 1023);
+        assert_eq!(PortRange::REGISTERED.start(), 1024);
+        assert_eq!(PortRange::REGISTERED.end(), 49151);
+        assert_eq!(PortRange::DYNAMIC.start(), 49152);
+    }
+
+    #[test]
+    fn construction() {
+        let range: PortRange = PortRange::new(Port::new(80), Port::new(90)).unwrap();
+        assert_eq!(range.start, 80);
+        assert_eq!(range.end, 90);
+
+        assert_eq!(PortRange::new(Port::new(90), Port::new(80)), None);
+
+        let range: PortRange = Port::new(80).into();
+        assert_eq!(range, PortRange::single(Port::new(80)));
+        assert!(range.is_single());
+    }
+
+    #[test]
+    fn properties() {
+        let range: PortRange = PortRange::new(Port::new(8000), Port::new(8010)).unwrap();
+        assert_eq!(range.start(), 8000);
+        assert_eq!(range.end(), 8010);
+        assert_eq!(range.port_count(), 11);
+        assert!(!range.is_single());
+
+        let (start, end): (Port, Port) = range.into();
+        assert_eq!((start, end), (Port::new(8000), Port::new(8010)));
+    }
+
+    #[test]
+    fn matching() {
+        let range: PortRange = PortRange::new(Port::new(8000), Port::new(8010)).unwrap();
+        assert!(range.contains(Port::new(8000)));
+        assert!(range.contains(Port::new(8010)));
+        assert!(!range.contains(Port::new(7999)));
+        assert!(!range.contains(Port::new(8011)));
+
+        let test_cases: &[(u16, u16, bool, bool)] = &[
+            (7990, 7999, false, false),
+            (7990, 8000, true, false),
+            (8002, 8005, true, true),
+            (8000, 8010, true, true),
+            (8010, 8020, true, false),
+            (8011, 8020, false, false),
+        ];
+
+        for (start, end, overlaps, contains) in test_cases {
+            let other: PortRange = PortRange::new(Port::new(*start), Port::new(*end)).unwrap();
+            assert_eq!(range.overlaps(other), *overlaps, "other={}", other);
+            assert_eq!(other.overlaps(range), *overlaps, "other={}", other);
+            assert_eq!(range.contains_range(other), *contains, "other={}", other);
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{Port, PortRange};
+use core::iter::FusedIterator;
+
+/// An iterator over the ports of a [PortRange].
+#[must_use]
+#[derive(Copy, Clone, Debug)]
+pub struct PortRangeIter {
+    range: Option<PortRange>,
+}
+
+impl PortRangeIter {
+    //! Construction
+
+    /// Creates a new port iterator for the `range`.
+    pub(crate) const fn new(range: PortRange) -> Self {
+        Self { range: Some(range) }
+    }
+}
+
+impl Iterator for PortRangeIter {
+    type Item = Port;
+
+    fn next(&mut self) -> Option<Port> {
+        let range: PortRange = self.range?;
+        self.range = if range.is_single() {
+            None
+        } else {
+            PortRange::new(Port::new(range.start().value() + 1), range.end())
+        };
+        Some(range.start())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len: usize = self.range.map_or(0, |range| range.port_count() as usize);
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for PortRangeIter {
+    fn next_back(&mut self) -> Option<Port> {
+        let range: PortRange = self.range?;
+        self.range = if range.is_single() {
+            None
+        } else {
+            PortRange::new(range.start(), Port::new(range.end().value() - 1))
+        };
+        Some(range.end())
+    }
+}
+
+impl ExactSizeIterator for PortRangeIter {}
+
+impl FusedIterator for PortRangeIter {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Port, PortRange, PortRangeIter};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn iteration() {
+        let range: PortRange = PortRange::new(Port::new(8000), Port::new(8002)).unwrap();
+        let result: Vec<u16> = range.iter().map(Port::value).collect();
+        assert_eq!(result, [8000, 8001, 8002]);
+
+        let result: Vec<u16> = range.iter().rev().map(Port::value).collect();
+        assert_eq!(result, [8002, 8001, 8000]);
+
+        let mut ports: PortRangeIter = range.into_iter();
+        assert_eq!(ports.len(), 3);
+        assert_eq!(ports.next(), Some(Port::new(8000)));
+        assert_eq!(ports.next_back(), Some(Port::new(8002)));
+        assert_eq!(ports.len(), 1);
+        assert_eq!(ports.next(), Some(Port::new(8001)));
+        assert_eq!(ports.next(), None);
+        assert_eq!(ports.next_back(), None);
+        assert_eq!(ports.len(), 0);
+    }
+
+    /// The bounds of the port space iterate without overflow.
+    #[test]
+    fn iteration_bounds() {
+        assert_eq!(PortRange::ALL.iter().count(), 65536);
+        assert_eq!(PortRange::ALL.iter().max(), Some(Port::new(u16::MAX)));
+        assert_eq!(PortRange::ALL.iter().rev().count(), 65536);
+        assert_eq!(PortRange::ALL.iter().rev().min(), Some(Port::new(0)));
+    }
+}
@@ -0,0 +1,7 @@
+pub use mx_record::*;
+pub use srv_record::*;
+
+mod mx_record;
+#[cfg(feature = "std")]
+mod random;
+mod srv_record;
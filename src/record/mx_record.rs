@@ -0,0 +1,163 @@
+use crate::{Domain, DomainRef};
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use crate::record::random::random;
+
+/// A DNS MX record (RFC 5321): a mail exchange [Domain] with a preference.
+///
+/// A null MX record (RFC 7505) has the `.` exchange & means the domain accepts no mail; such a record has no domain.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct MxRecord {
+    preference: u16,
+    exchange: Option<Domain>,
+}
+
+impl MxRecord {
+    //! Construction
+
+    /// Creates a new [MxRecord].
+    #[must_use]
+    pub const fn new(preference: u16, exchange: Domain) -> Self {
+        Self {
+            preference,
+            exchange: Some(exchange),
+        }
+    }
+
+    /// Creates a new null [MxRecord] with the `.` exchange: the domain accepts no mail.
+    #[must_use]
+    pub const fn null(preference: u16) -> Self {
+        Self {
+            preference,
+            exchange: None,
+        }
+    }
+}
+
+impl MxRecord {
+    //! Properties
+
+    /// Gets the preference. Lower preferences are tried first.
+    #[must_use]
+    pub const fn preference(&self) -> u16 {
+        self.preference
+    }
+
+    /// Gets the exchange, or `None` for a null MX record.
+    #[must_use]
+    pub fn exchange(&self) -> Option<DomainRef<'_>> {
+        self.exchange.as_ref().map(Domain::to_ref)
+    }
+
+    /// Checks if the record is a null MX record: the domain accepts no mail.
+    #[must_use]
+    pub const fn is_null(&self) -> bool {
+        self.exchange.is_none()
+    }
+}
+
+impl MxRecord {
+    //! Selection
+
+    /// Orders the exchanges of the `records` for delivery attempts, as described in RFC 5321 section 5.1.
+    ///
+    /// Records are ordered by preference, & records of the same preference are shuffled. The `random` function
+    /// returns a uniformly random number in `0..=max` for its `max` argument; inject a fixed sequence for
+    /// deterministic tests.
+    ///
+    /// Returns `None` when the domain accepts no mail: a single null MX record. Otherwise null records are skipped.
+    pub fn select<R>(records: &[Self], mut random: R) -> Option<Vec<Domain>>
+    where
+        R: FnMut(u32) -> u32,
+    {
+        if let [record] = records
+            && record.is_null()
+        {
+            return None;
+        }
+
+        let mut records: Vec<&Self> = records.iter().filter(|record| !record.is_null()).collect();
+        records.sort_by_key(|record| record.preference);
+
+        let mut exchanges: Vec<Domain> = Vec::with_capacity(records.len());
+        for group in records.chunk_by(|a, b| a.preference == b.preference) {
+            let mut group: Vec<&Self> = group.to_vec();
+            while !group.is_empty() {
+                let max: u32 = (group.len() - 1) as u32;
+                let record: &Self = group.remove(random(max).min(max) as usize);
+                exchanges.extend(record.exchange.clone());
+            }
+        }
+        Some(exchanges)
+    }
+
+    /// Orders the exchanges of the `records` with [`Self::select`] & a randomly seeded generator.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn select_random(records: &[Self]) -> Option<Vec<Domain>> {
+        Self::select(records, random)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Domain, MxRecord};
+    use alloc::string::String;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    fn record(preference: u16, name: &str) -> MxRecord {
+        MxRecord::new(preference, Domain::parse_text(name.as_bytes()).unwrap())
+    }
+
+    fn names(exchanges: Option<Vec<Domain>>) -> Option<Vec<String>> {
+        exchanges.map(|exchanges| exchanges.into_iter().map(String::from).collect())
+    }
+
+    #[test]
+    fn select() {
+        let records: Vec<MxRecord> = vec![
+            record(20, "backup.example"),
+            record(10, "a.example"),
+            record(10, "b.example"),
+        ];
+        let test_cases: &[(u32, &[&str])] = &[
+            (0, &["a.example", "b.example", "backup.example"]),
+            (1, &["b.example", "a.example", "backup.example"]),
+        ];
+
+        for (number, expected) in test_cases {
+            let result: Option<Vec<String>> = names(MxRecord::select(&records, |_| *number));
+            let expected: Vec<String> = expected.iter().map(|name| name.to_string()).collect();
+            assert_eq!(result, Some(expected), "number={}", number);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn select_random() {
+        let records: Vec<MxRecord> = vec![record(20, "backup.example"), record(10, "a.example")];
+        let result: Option<Vec<Domain>> = MxRecord::select_random(&records);
+        assert_eq!(
+            result,
+            Some(vec![
+                Domain::try_from("a.example").unwrap(),
+                Domain::try_from("backup.example").unwrap()
+            ])
+        );
+    }
+
+    #[test]
+    fn select_null() {
+        assert_eq!(MxRecord::select(&[MxRecord::null(0)], |_| 0), None);
+        assert_eq!(MxRecord::select(&[], |_| 0), Some(Vec::new()));
+
+        let records: Vec<MxRecord> = vec![MxRecord::null(0), record(10, "a.example")];
+        assert_eq!(
+            names(MxRecord::select(&records, |_| 0)),
+            Some(vec!["a.example".to_string()])
+        );
+    }
+}
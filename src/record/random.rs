@@ -0,0 +1,30 @@
+use core::hash::{BuildHasher, Hasher};
+use std::collections::hash_map::RandomState;
+
+/// Gets a random number in `0..=max` from the randomly seeded standard library hasher.
+///
+/// This is not a cryptographic generator; it spreads load across records, which is all the orderings need.
+pub(crate) fn random(max: u32) -> u32 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u32(max);
+    let value: u64 = hasher.finish();
+    if max == u32::MAX {
+        value as u32
+    } else {
+        (value % (u64::from(max) + 1)) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::record::random::random;
+
+    #[test]
+    fn bounds() {
+        for max in [0, 1, 7, u32::MAX] {
+            for _ in 0..100 {
+                assert!(random(max) <= max, "max={}", max);
+            }
+        }
+    }
+}
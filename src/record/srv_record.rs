@@ -0,0 +1,211 @@
+use crate::{Endpoint, EndpointRef};
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use crate::record::random::random;
+
+/// A DNS SRV record (RFC 2782): an [Endpoint] with a priority & a weight.
+///
+/// A target of `.` means the service is decidedly not available at the domain; such a record has no endpoint.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct SrvRecord {
+    priority: u16,
+    weight: u16,
+    endpoint: Option<Endpoint>,
+}
+
+impl SrvRecord {
+    //! Construction
+
+    /// Creates a new [SrvRecord].
+    #[must_use]
+    pub const fn new(priority: u16, weight: u16, endpoint: Endpoint) -> Self {
+        Self {
+            priority,
+            weight,
+            endpoint: Some(endpoint),
+        }
+    }
+
+    /// Creates a new [SrvRecord] with the `.` target: the service is not available.
+    #[must_use]
+    pub const fn unavailable(priority: u16, weight: u16) -> Self {
+        Self {
+            priority,
+            weight,
+            endpoint: None,
+        }
+    }
+}
+
+impl SrvRecord {
+    //! Properties
+
+    /// Gets the priority. Lower priorities are tried first.
+    #[must_use]
+    pub const fn priority(&self) -> u16 {
+        self.priority
+    }
+
+    /// Gets the weight, the relative share of selections among records of the same priority.
+    #[must_use]
+    pub const fn weight(&self) -> u16 {
+        self.weight
+    }
+
+    /// Gets the endpoint, or `None` for the `.` target.
+    #[must_use]
+    pub fn endpoint(&self) -> Option<EndpointRef<'_>> {
+        self.endpoint.as_ref().map(Endpoint::to_ref)
+    }
+
+    /// Checks if the record has the `.` target: the service is not available.
+    #[must_use]
+    pub const fn is_unavailable(&self) -> bool {
+        self.endpoint.is_none()
+    }
+}
+
+impl SrvRecord {
+    //! Selection
+
+    /// Orders the endpoints of the `records` for connection attempts, as described in RFC 2782.
+    ///
+    /// Records are ordered by priority, & records of the same priority are ordered by a weighted random selection.
+    /// The `random` function returns a uniformly random number in `0..=max` for its `max` argument; inject a fixed
+    /// sequence for deterministic tests.
+    ///
+    /// Returns `None` when the service is not available: a single record with the `.` target. Otherwise records
+    /// with the `.` target are skipped.
+    pub fn select<R>(records: &[Self], mut random: R) -> Option<Vec<Endpoint>>
+    where
+        R: FnMut(u32) -> u32,
+    {
+        if let [record] = records
+            && record.is_unavailable()
+        {
+            return None;
+        }
+
+        let mut records: Vec<&Self> = records.iter().filter(|record| !record.is_unavailable()).collect();
+        records.sort_by_key(|record| (record.priority, record.weight != 0));
+
+        let mut endpoints: Vec<Endpoint> = Vec::with_capacity(records.len());
+        for group in records.chunk_by(|a, b| a.priority == b.priority) {
+            let mut group: Vec<&Self> = group.to_vec();
+            while !group.is_empty() {
+                let total: u32 = group.iter().map(|record| u32::from(record.weight)).sum();
+                let selected: u32 = random(total).min(total);
+                let mut running: u32 = 0;
+                let index: usize = group
+                    .iter()
+                    .position(|record| {
+                        running += u32::from(record.weight);
+                        running >= selected
+                    })
+                    .unwrap_or(group.len() - 1);
+                let record: &Self = group.remove(index);
+                endpoints.extend(record.endpoint.clone());
+            }
+        }
+        Some(endpoints)
+    }
+
+    /// Orders the endpoints of the `records` with [`Self::select`] & a randomly seeded generator.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn select_random(records: &[Self]) -> Option<Vec<Endpoint>> {
+        Self::select(records, random)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Endpoint, SrvRecord};
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    fn record(priority: u16, weight: u16, name: &str) -> SrvRecord {
+        SrvRecord::new(
+            priority,
+            weight,
+            Endpoint::parse_text(format!("{}:443", name).as_bytes()).unwrap(),
+        )
+    }
+
+    fn names(endpoints: Option<Vec<Endpoint>>) -> Option<Vec<String>> {
+        endpoints.map(|endpoints| endpoints.iter().map(|endpoint| endpoint.domain().to_string()).collect())
+    }
+
+    #[test]
+    fn select() {
+        let records: Vec<SrvRecord> = vec![
+            record(20, 0, "backup.example"),
+            record(10, 60, "a.example"),
+            record(10, 20, "b.example"),
+            record(10, 0, "c.example"),
+            record(10, 20, "d.example"),
+        ];
+
+        // The group of priority 10 in selection order: c (0), a (60), b (80), d (100).
+        let test_cases: &[(&[u32], &[&str])] = &[
+            (
+                &[0, 0, 0, 0, 0],
+                &["c.example", "a.example", "b.example", "d.example", "backup.example"],
+            ),
+            (
+                &[100, 100, 100, 0, 0],
+                &["d.example", "b.example", "a.example", "c.example", "backup.example"],
+            ),
+            (
+                &[61, 0, 0, 0, 0],
+                &["b.example", "c.example", "a.example", "d.example", "backup.example"],
+            ),
+        ];
+
+        for (sequence, expected) in test_cases {
+            let mut numbers = sequence.iter().copied();
+            let result: Option<Vec<String>> = names(SrvRecord::select(&records, |_| numbers.next().unwrap_or(0)));
+            let expected: Vec<String> = expected.iter().map(|name| name.to_string()).collect();
+            assert_eq!(result, Some(expected), "sequence={:?}", sequence);
+        }
+    }
+
+    #[test]
+    fn select_bounds() {
+        let records: Vec<SrvRecord> = vec![record(10, 1, "a.example"), record(10, 1, "b.example")];
+        let mut maxes: Vec<u32> = Vec::new();
+        let result: Option<Vec<Endpoint>> = SrvRecord::select(&records, |max| {
+            maxes.push(max);
+            u32::MAX
+        });
+        assert_eq!(maxes, [2, 1]);
+        assert_eq!(
+            names(result),
+            Some(vec!["b.example".to_string(), "a.example".to_string()])
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn select_random() {
+        let records: Vec<SrvRecord> = vec![record(10, 1, "a.example"), record(10, 1, "b.example")];
+        let result: Option<Vec<Endpoint>> = SrvRecord::select_random(&records);
+        assert_eq!(result.map(|endpoints| endpoints.len()), Some(2));
+    }
+
+    #[test]
+    fn select_unavailable() {
+        assert_eq!(SrvRecord::select(&[SrvRecord::unavailable(0, 0)], |_| 0), None);
+        assert_eq!(SrvRecord::select(&[], |_| 0), Some(Vec::new()));
+
+        let records: Vec<SrvRecord> = vec![SrvRecord::unavailable(0, 0), record(10, 0, "a.example")];
+        assert_eq!(
+            names(SrvRecord::select(&records, |_| 0)),
+            Some(vec!["a.example".to_string()])
+        );
+    }
+}
@@ -0,0 +1,7 @@
+pub use nameserver::*;
+pub use resolv_conf::*;
+pub use resolver_options::*;
+
+mod nameserver;
+mod resolv_conf;
+mod resolver_options;
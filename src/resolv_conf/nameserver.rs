@@ -0,0 +1,93 @@
+use crate::{IPAddress, SocketAddress};
+use alloc::string::String;
+
+/// A name server of a `resolv.conf` file: a socket address with an optional IPv6 zone.
+///
+/// The zone names the interface of a link-local IPv6 address: `fe80::1%eth0`.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Nameserver {
+    socket: SocketAddress,
+    zone: Option<String>,
+}
+
+impl Nameserver {
+    //! Construction
+
+    /// The DNS port.
+    pub const DNS_PORT: u16 = 53;
+
+    /// Creates a new [Nameserver] for the `ip` on the DNS port.
+    #[must_use]
+    pub const fn new(ip: IPAddress) -> Self {
+        Self {
+            socket: ip.to_socket(Self::DNS_PORT),
+            zone: None,
+        }
+    }
+
+    /// Sets the `port`.
+    #[must_use]
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.socket = self.socket.ip().to_socket(port);
+        self
+    }
+
+    /// Sets the IPv6 `zone`.
+    #[must_use]
+    pub fn with_zone<S>(mut self, zone: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.zone = Some(zone.into());
+        self
+    }
+}
+
+impl From<SocketAddress> for Nameserver {
+    fn from(socket: SocketAddress) -> Self {
+        Self { socket, zone: None }
+    }
+}
+
+impl Nameserver {
+    //! Properties
+
+    /// Gets the socket address.
+    pub const fn socket(&self) -> SocketAddress {
+        self.socket
+    }
+
+    /// Gets the IP address.
+    pub const fn ip(&self) -> IPAddress {
+        self.socket.ip()
+    }
+
+    /// Gets the port.
+    #[must_use]
+    pub const fn port(&self) -> u16 {
+        self.socket.port()
+    }
+
+    /// Gets the optional IPv6 zone.
+    #[must_use]
+    pub fn zone(&self) -> Option<&str> {
+        self.zone.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IPv6Address, Nameserver};
+
+    #[test]
+    fn properties() {
+        let nameserver: Nameserver = Nameserver::new(IPv6Address::LOCALHOST.to_ip());
+        assert_eq!(nameserver.ip(), IPv6Address::LOCALHOST.to_ip());
+        assert_eq!(nameserver.port(), 53);
+        assert_eq!(nameserver.zone(), None);
+
+        let nameserver: Nameserver = nameserver.with_port(5353).with_zone("eth0");
+        assert_eq!(nameserver.socket(), IPv6Address::LOCALHOST.to_ip().to_socket(5353));
+        assert_eq!(nameserver.zone(), Some("eth0"));
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{Domain, DomainRef, Nameserver, ParseError, ResolverOptions};
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A resolver configuration, as read from a `resolv.conf` file.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+pub struct ResolvConf {
+    pub(crate) nameservers: Vec<Nameserver>,
+    pub(crate) search: Vec<Domain>,
+    pub(crate) options: ResolverOptions,
+}
+
+impl ResolvConf {
+    //! Properties
+
+    /// Gets the name servers, in file order.
+    #[must_use]
+    pub fn nameservers(&self) -> &[Nameserver] {
+        self.nameservers.as_slice()
+    }
+
+    /// Gets the search list, in file order.
+    ///
+    /// The last `search` or `domain` line sets the list; a `domain` line sets it to the single domain.
+    pub fn search(&self) -> &[Domain] {
+        self.search.as_slice()
+    }
+
+    /// Gets the options.
+    #[must_use]
+    pub const fn options(&self) -> &ResolverOptions {
+        &self.options
+    }
+}
+
+impl ResolvConf {
+    //! Search
+
+    /// Expands the `name` into the ordered list of names the resolver queries, the way the glibc `res_search` does.
+    ///
+    /// A name ending in a dot is absolute & is the only candidate. A name with at least `ndots` dots is tried as is
+    /// first, then with each search domain appended; a name with fewer dots is tried with each search domain first &
+    /// then as is. Appended names longer than a [Domain] can hold are skipped.
+    pub fn search_candidates(&self, name: &[u8]) -> Result<Vec<Domain>, ParseError> {
+        if let Some(absolute) = name.strip_suffix(b".") {
+            return Domain::parse_text(absolute).map(|domain| vec![domain]);
+        }
+        let name: Domain = Domain::parse_text(name)?;
+        let dots: usize = name.name().bytes().filter(|c| *c == b'.').count();
+        let as_is_first: bool = dots >= usize::from(self.options.ndots);
+
+        let mut candidates: Vec<Domain> = Vec::with_capacity(self.search.len() + 1);
+        if as_is_first {
+            candidates.push(name.clone());
+        }
+        candidates.extend(
+            self.search
+                .iter()
+                .filter_map(|search| Self::append(name.to_ref(), search.to_ref())),
+        );
+        if !as_is_first {
+            candidates.push(name);
+        }
+        Ok(candidates)
+    }
+
+    /// Appends the `search` domain to the `name`.
+    fn append(name: DomainRef, search: DomainRef) -> Option<Domain> {
+        Domain::parse_text(format!("{}.{}", name, search).as_bytes()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Domain, ParseError, ResolvConf};
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn search_candidates() {
+        let conf: ResolvConf =
+            ResolvConf::parse_resolv_conf(b"search svc.cluster.local cluster.local\noptions ndots:2\n").unwrap();
+        let test_cases: &[(&str, Result<&[&str], ParseError>)] = &[
+            ("api", Ok(&["api.svc.cluster.local", "api.cluster.local", "api"])),
+            (
+                "api.ns",
+                Ok(&["api.ns.svc.cluster.local", "api.ns.cluster.local", "api.ns"]),
+            ),
+            (
+                "api.ns.svc",
+                Ok(&["api.ns.svc", "api.ns.svc.svc.cluster.local", "api.ns.svc.cluster.local"]),
+            ),
+            ("Example.COM.", Ok(&["example.com"])),
+            ("a_b", Err(ParseError::InvalidDomain)),
+        ];
+
+        for (input, expected) in test_cases {
+            let result: Result<Vec<Domain>, ParseError> = conf.search_candidates(input.as_bytes());
+            let result: Result<Vec<String>, ParseError> =
+                result.map(|domains| domains.into_iter().map(String::from).collect());
+            let expected: Result<Vec<String>, ParseError> =
+                expected.map(|names| names.iter().map(|name| name.to_string()).collect());
+            assert_eq!(result, expected, "input={}", input);
+        }
+    }
+
+    #[test]
+    fn search_candidates_too_long() {
+        let search: String = format!("{}.example", "a".repeat(60));
+        let text: String = format!("search {} {} example.com\n", search, search);
+        let conf: ResolvConf = ResolvConf::parse_resolv_conf(text.as_bytes()).unwrap();
+        let name: String = vec!["b".repeat(50); 4].join(".");
+
+        let result: Vec<String> = conf
+            .search_candidates(name.as_bytes())
+            .unwrap()
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let expected: Vec<String> = vec![name.clone(), format!("{}.example.com", name)];
+        assert_eq!(result, expected);
+    }
+}
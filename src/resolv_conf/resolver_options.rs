@@ -0,0 +1,97 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+/// The `options` of a `resolv.conf` file.
+///
+/// Numeric options are capped at the limits of the glibc resolver, & options this type does not model are kept as
+/// written in [`Self::other`].
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct ResolverOptions {
+    pub(crate) ndots: u8,
+    pub(crate) timeout: u8,
+    pub(crate) attempts: u8,
+    pub(crate) rotate: bool,
+    pub(crate) edns0: bool,
+    pub(crate) other: Vec<String>,
+}
+
+impl ResolverOptions {
+    //! Limits
+
+    /// The maximum `ndots` value.
+    pub const MAX_NDOTS: u8 = 15;
+
+    /// The maximum `timeout` value in seconds.
+    pub const MAX_TIMEOUT: u8 = 30;
+
+    /// The maximum `attempts` value.
+    pub const MAX_ATTEMPTS: u8 = 5;
+}
+
+impl Default for ResolverOptions {
+    /// The glibc defaults: `ndots:1`, `timeout:5`, & `attempts:2`.
+    fn default() -> Self {
+        Self {
+            ndots: 1,
+            timeout: 5,
+            attempts: 2,
+            rotate: false,
+            edns0: false,
+            other: Vec::new(),
+        }
+    }
+}
+
+impl ResolverOptions {
+    //! Properties
+
+    /// Gets the number of dots a name needs to be tried as is before the search list.
+    #[must_use]
+    pub const fn ndots(&self) -> u8 {
+        self.ndots
+    }
+
+    /// Gets the timeout of each query in seconds.
+    #[must_use]
+    pub const fn timeout(&self) -> u8 {
+        self.timeout
+    }
+
+    /// Gets the number of attempts for each name server.
+    #[must_use]
+    pub const fn attempts(&self) -> u8 {
+        self.attempts
+    }
+
+    /// Checks if the name servers are used round-robin.
+    #[must_use]
+    pub const fn rotate(&self) -> bool {
+        self.rotate
+    }
+
+    /// Checks if the EDNS0 extensions are enabled.
+    #[must_use]
+    pub const fn edns0(&self) -> bool {
+        self.edns0
+    }
+
+    /// Gets the other options, as written: `single-request` or `use-vc`.
+    pub fn other(&self) -> impl Iterator<Item = &str> {
+        self.other.iter().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ResolverOptions;
+
+    #[test]
+    fn default() {
+        let options: ResolverOptions = ResolverOptions::default();
+        assert_eq!(options.ndots(), 1);
+        assert_eq!(options.timeout(), 5);
+        assert_eq!(options.attempts(), 2);
+        assert!(!options.rotate());
+        assert!(!options.edns0());
+        assert_eq!(options.other().count(), 0);
+    }
+}
@@ -0,0 +1,12 @@
+pub use resolver::*;
+pub use static_resolver::*;
+#[cfg(feature = "system-resolver")]
+pub use system_resolver::*;
+
+mod resolution;
+mod resolver;
+mod static_resolver;
+#[cfg(feature = "system-resolver")]
+mod system_resolver;
+#[cfg(feature = "system-resolver")]
+mod to_socket_addrs;
@@ -0,0 +1,79 @@
+use crate::{Authority, AuthorityRef, Resolver, SocketAddress};
+use std::io;
+
+impl Authority {
+    //! Resolution
+
+    /// Resolves the authority to its socket addresses with the `resolver`.
+    ///
+    /// An IP address host resolves to its single socket address without calling the `resolver`.
+    pub fn resolve<R: Resolver + ?Sized>(&self, resolver: &R) -> io::Result<Vec<SocketAddress>> {
+        self.to_ref().resolve(resolver)
+    }
+}
+
+impl<'a> AuthorityRef<'a> {
+    //! Resolution
+
+    /// Resolves the authority to its socket addresses with the `resolver`.
+    ///
+    /// An IP address host resolves to its single socket address without calling the `resolver`.
+    pub fn resolve<R: Resolver + ?Sized>(self, resolver: &R) -> io::Result<Vec<SocketAddress>> {
+        match self.to_endpoint_ref() {
+            Some(endpoint) => resolver.resolve_endpoint(endpoint),
+            None => Ok(self.to_socket().into_iter().collect()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Authority, Domain, DomainRef, IPAddress, IPv4Address, IPv6Address, Resolver, SocketAddress, StaticResolver,
+    };
+    use std::io;
+
+    /// A resolver that fails every lookup, to check that IP address hosts never reach it.
+    struct UnreachableResolver;
+
+    impl Resolver for UnreachableResolver {
+        fn resolve_domain(&self, domain: DomainRef) -> io::Result<Vec<IPAddress>> {
+            panic!("resolved {}", domain)
+        }
+    }
+
+    #[test]
+    fn resolve() {
+        let resolver: StaticResolver = [
+            (Domain::localhost(), IPv6Address::LOCALHOST.to_ip()),
+            (Domain::localhost(), IPv4Address::LOCALHOST.to_ip()),
+        ]
+        .into_iter()
+        .collect();
+
+        let authority: Authority = Domain::localhost().to_host().to_authority(80);
+        let result: Vec<SocketAddress> = authority.resolve(&resolver).unwrap();
+        let expected: Vec<SocketAddress> = vec![
+            IPv6Address::LOCALHOST.to_ip().to_socket(80),
+            IPv4Address::LOCALHOST.to_ip().to_socket(80),
+        ];
+        assert_eq!(result, expected);
+
+        let authority: Authority = Domain::example().to_host().to_authority(80);
+        assert!(authority.resolve(&resolver).is_err());
+    }
+
+    #[test]
+    fn resolve_ip() {
+        let test_cases: &[IPAddress] = &[IPv4Address::LOCALHOST.to_ip(), IPv6Address::LOCALHOST.to_ip()];
+
+        for ip in test_cases {
+            let authority: Authority = ip.to_host().to_authority(443);
+            let result: Vec<SocketAddress> = authority.resolve(&UnreachableResolver).unwrap();
+            assert_eq!(result, [ip.to_socket(443)], "ip={}", ip);
+
+            let result: Vec<SocketAddress> = authority.to_ref().resolve(&&UnreachableResolver).unwrap();
+            assert_eq!(result, [ip.to_socket(443)], "ip={}", ip);
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{DomainRef, EndpointRef, HostRef, IPAddress, SocketAddress};
+use std::io;
+
+/// A name resolver that maps domain names to IP addresses.
+///
+/// Implement [`Self::resolve_domain`]; the host & endpoint methods build on it, & IP address hosts are returned as is
+/// without calling it. The crate does no resolution of its own: use the `SystemResolver` with the `system-resolver`
+/// feature, a [StaticResolver](crate::StaticResolver) in tests, or a custom implementation.
+pub trait Resolver {
+    /// Resolves the `domain` to its IP addresses.
+    fn resolve_domain(&self, domain: DomainRef) -> io::Result<Vec<IPAddress>>;
+
+    /// Resolves the `host` to its IP addresses. An IP address host resolves to itself.
+    fn resolve_host(&self, host: HostRef) -> io::Result<Vec<IPAddress>> {
+        match host {
+            HostRef::Name(domain) => self.resolve_domain(domain),
+            HostRef::Address(ip) => Ok(vec![ip]),
+        }
+    }
+
+    /// Resolves the `endpoint` to its socket addresses.
+    fn resolve_endpoint(&self, endpoint: EndpointRef) -> io::Result<Vec<SocketAddress>> {
+        let ips: Vec<IPAddress> = self.resolve_domain(endpoint.domain())?;
+        Ok(ips.into_iter().map(|ip| ip.to_socket(endpoint.port())).collect())
+    }
+}
+
+impl<R: Resolver + ?Sized> Resolver for &R {
+    fn resolve_domain(&self, domain: DomainRef) -> io::Result<Vec<IPAddress>> {
+        (**self).resolve_domain(domain)
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{Domain, DomainRef, IPAddress, Resolver};
+use std::collections::HashMap;
+use std::io;
+
+/// An in-memory [Resolver] with a fixed table of domain names, for tests & offline tools.
+///
+/// Unknown domain names are an [`io::ErrorKind::NotFound`] error.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct StaticResolver {
+    names: HashMap<String, Vec<IPAddress>>,
+}
+
+impl StaticResolver {
+    //! Construction
+
+    /// Creates an empty [StaticResolver].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the `ip` to the addresses of the `domain`, after any it already has.
+    pub fn insert(&mut self, domain: Domain, ip: IPAddress) {
+        self.names.entry(domain.into()).or_default().push(ip);
+    }
+}
+
+impl FromIterator<(Domain, IPAddress)> for StaticResolver {
+    fn from_iter<I: IntoIterator<Item = (Domain, IPAddress)>>(entries: I) -> Self {
+        let mut resolver: Self = Self::new();
+        for (domain, ip) in entries {
+            resolver.insert(domain, ip);
+        }
+        resolver
+    }
+}
+
+impl StaticResolver {
+    //! Properties
+
+    /// Checks if the resolver is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+impl Resolver for StaticResolver {
+    fn resolve_domain(&self, domain: DomainRef) -> io::Result<Vec<IPAddress>> {
+        self.names
+            .get(domain.name())
+            .cloned()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("unknown domain: {}", domain)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Domain, DomainRef, IPAddress, IPv4Address, IPv6Address, Resolver, StaticResolver};
+    use std::io;
+
+    #[test]
+    fn resolve_domain() {
+        let resolver: StaticResolver = [
+            (Domain::localhost(), IPv4Address::LOCALHOST.to_ip()),
+            (Domain::localhost(), IPv6Address::LOCALHOST.to_ip()),
+        ]
+        .into_iter()
+        .collect();
+        assert!(!resolver.is_empty());
+
+        let result: Vec<IPAddress> = resolver.resolve_domain(DomainRef::LOCALHOST).unwrap();
+        assert_eq!(result, [IPv4Address::LOCALHOST.to_ip(), IPv6Address::LOCALHOST.to_ip()]);
+
+        let error: io::Error = resolver.resolve_domain(DomainRef::EXAMPLE).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn resolve_host() {
+        let resolver: StaticResolver = StaticResolver::new();
+        assert!(resolver.is_empty());
+
+        let result: Vec<IPAddress> = resolver.resolve_host(IPv4Address::LOCALHOST.to_host_ref()).unwrap();
+        assert_eq!(result, [IPv4Address::LOCALHOST.to_ip()]);
+        assert!(resolver.resolve_host(Domain::localhost().to_host().to_ref()).is_err());
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{DomainRef, IPAddress, Resolver};
+use std::io;
+use std::net::{SocketAddr, ToSocketAddrs};
+
+/// A [Resolver] that uses the operating system resolver (`getaddrinfo` on Unix) through the standard library.
+///
+/// Resolution blocks the calling thread & follows the system configuration: `/etc/hosts`, `/etc/resolv.conf`, & the
+/// address order the system chooses.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+pub struct SystemResolver;
+
+impl Resolver for SystemResolver {
+    fn resolve_domain(&self, domain: DomainRef) -> io::Result<Vec<IPAddress>> {
+        let sockets: std::vec::IntoIter<SocketAddr> = (domain.name(), 0).to_socket_addrs()?;
+        Ok(sockets.map(|socket| IPAddress::from(socket.ip())).collect())
+    }
+}
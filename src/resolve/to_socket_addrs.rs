@@ -0,0 +1,46 @@
+use crate::{Authority, AuthorityRef, SocketAddress, SystemResolver};
+use std::io;
+use std::net::{SocketAddr, ToSocketAddrs};
+
+impl ToSocketAddrs for Authority {
+    type Iter = std::vec::IntoIter<SocketAddr>;
+
+    /// Resolves the authority with the [SystemResolver], blocking the calling thread for domain names.
+    fn to_socket_addrs(&self) -> io::Result<Self::Iter> {
+        self.to_ref().to_socket_addrs()
+    }
+}
+
+impl<'a> ToSocketAddrs for AuthorityRef<'a> {
+    type Iter = std::vec::IntoIter<SocketAddr>;
+
+    /// Resolves the authority with the [SystemResolver], blocking the calling thread for domain names.
+    fn to_socket_addrs(&self) -> io::Result<Self::Iter> {
+        let sockets: Vec<SocketAddress> = self.resolve(&SystemResolver)?;
+        Ok(sockets
+            .into_iter()
+            .map(SocketAddress::to_std)
+            .collect::<Vec<SocketAddr>>()
+            .into_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Authority, IPv4Address, IPv6Address};
+    use std::net::{SocketAddr, ToSocketAddrs};
+
+    #[test]
+    fn to_socket_addrs() {
+        let test_cases: &[Authority] = &[
+            IPv4Address::LOCALHOST.to_host().to_authority(80),
+            IPv6Address::LOCALHOST.to_host().to_authority(443),
+        ];
+
+        for authority in test_cases {
+            let result: Vec<SocketAddr> = authority.to_socket_addrs().unwrap().collect();
+            let expected: Vec<SocketAddr> = vec![authority.to_socket().unwrap().to_std()];
+            assert_eq!(result, expected, "authority={}", authority);
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use crate::{Authority, Domain, IPv4Address, IPv6Address, ScanKinds, ScanMatch, ScannedAddress, SocketAddress};
+use alloc::vec::Vec;
+
+/// Finds addresses embedded in free text, such as logs & tickets.
+///
+/// The text is split into tokens of ASCII letters, digits, & the `.`, `-`, `:`, `[`, & `]` characters, & each token
+/// is parsed whole, so `1.2.3.4` is never found inside `11.2.3.45` & `1.2.3.4.5` is not an address at all. A trailing
+/// dot, as at the end of a sentence, is not part of a match, & neither are trailing colons & dashes. A token that is
+/// not an address is split at its colons, so `ip:192.0.2.1` finds the IP address.
+///
+/// Domains must have at least 2 labels & a final label of at least 2 letters, so words & version numbers are not
+/// domains; file names such as `notes.txt` still are. When a kind is not searched for, a socket address falls back to
+/// its IP address & an authority to its domain.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+pub struct AddressScanner {
+    kinds: ScanKinds,
+}
+
+impl AddressScanner {
+    //! Construction
+
+    /// Creates a new [AddressScanner] for the `kinds`.
+    #[must_use]
+    pub const fn new(kinds: ScanKinds) -> Self {
+        Self { kinds }
+    }
+}
+
+impl AddressScanner {
+    //! Properties
+
+    /// Gets the kinds of addresses to search for.
+    #[must_use]
+    pub const fn kinds(&self) -> ScanKinds {
+        self.kinds
+    }
+}
+
+impl AddressScanner {
+    //! Scan
+
+    /// Finds the addresses in the `text`, in order.
+    #[must_use]
+    pub fn scan(&self, text: &[u8]) -> Vec<ScanMatch> {
+        let mut matches: Vec<ScanMatch> = Vec::new();
+        let mut i: usize = 0;
+        while i < text.len() {
+            if Self::is_token_byte(text[i]) {
+                let start: usize = i;
+                while i < text.len() && Self::is_token_byte(text[i]) {
+                    i += 1;
+                }
+                self.scan_token(text, start, i, &mut matches);
+            } else {
+                i += 1;
+            }
+        }
+        matches
+    }
+
+    /// Finds the addresses in the `text`, in order. The spans are byte offsets into the `text`.
+    #[must_use]
+    pub fn scan_str(&self, text: &str) -> Vec<ScanMatch> {
+        self.scan(text.as_bytes())
+    }
+
+    /// Checks if the byte `c` can be part of an address token.
+    const fn is_token_byte(c: u8) -> bool {
+        c.is_ascii_alphanumeric() || matches!(c, b'.' | b'-' | b':' | b'[' | b']')
+    }
+
+    /// Finds the address in the token `text[start..end]`, or splits the token at its colons when it is not one.
+    fn scan_token(&self, text: &[u8], start: usize, end: usize, matches: &mut Vec<ScanMatch>) {
+        let (start, end): (usize, usize) = Self::trim(text, start, end);
+        if start == end {
+            return;
+        }
+        if let Some(found) = self.classify(text, start, end) {
+            matches.push(found);
+            return;
+        }
+
+        let token: &[u8] = &text[start..end];
+        if token.contains(&b':') {
+            let mut part_start: usize = start;
+            for part in token.split(|c| *c == b':') {
+                let part_end: usize = part_start + part.len();
+                if part_end > part_start {
+                    let (s, e): (usize, usize) = Self::trim(text, part_start, part_end);
+                    if let Some(found) = (s < e).then(|| self.classify(text, s, e)).flatten() {
+                        matches.push(found);
+                    }
+                }
+                part_start = part_end + 1;
+            }
+        }
+    }
+
+    /// Trims the leading dots & dashes, the trailing dots, colons, & dashes, & unmatched brackets from the token.
+    fn trim(text: &[u8], mut start: usize, mut end: usize) -> (usize, usize) {
+        loop {
+            let before: (usize, usize) = (start, end);
+            while start < end && matches!(text[start], b'.' | b'-') {
+                start += 1;
+            }
+            while start < end && matches!(text[end - 1], b'.' | b'-') {
+                end -= 1;
+            }
+            // A trailing colon is kept when it ends an IPv6 address, as in `2001:db8::`.
+            if start < end
+                && text[end - 1] == b':'
+                && (end - start < 2 || text[end - 2] != b':' || Self::parse_ipv6(&text[start..end]).is_none())
+            {
+                end -= 1;
+            }
+            let token: &[u8] = &text[start..end];
+            if token.first() == Some(&b'[') && !token.contains(&b']') {
+                start += 1;
+            }
+            if token.last() == Some(&b']') && !token.contains(&b'[') {
+                end -= 1;
+            }
+            if (start, end) == before {
+                return (start, end);
+            }
+        }
+    }
+
+    /// Parses the token `text[start..end]` as the most specific address of a searched-for kind.
+    fn classify(&self, text: &[u8], start: usize, end: usize) -> Option<ScanMatch> {
+        let token: &[u8] = &text[start..end];
+        let found = |address: ScannedAddress| Some(ScanMatch::new(start, end, address));
+
+        if let Some(ip) = token.strip_prefix(b"[").and_then(|token| token.strip_suffix(b"]")) {
+            return match Self::parse_ipv6(ip) {
+                Some(ip) if self.kinds.contains(ScanKinds::IPV6) => found(ScannedAddress::IPAddress(ip.to_ip())),
+                _ => None,
+            };
+        }
+        if let Ok(socket) = SocketAddress::parse_text(token) {
+            if self.kinds.contains(ScanKinds::SOCKET) {
+                return found(ScannedAddress::SocketAddress(socket));
+            }
+            let ip_end: usize = end - 1 - token.iter().rev().position(|c| *c == b':').unwrap_or(0);
+            return if token[0] == b'[' {
+                self.classify(text, start, ip_end)
+            } else {
+                self.classify(text, start, ip_end).filter(|_| socket.is_v4())
+            };
+        }
+        if let Ok(ip) = IPv4Address::parse_text(token) {
+            return self
+                .kinds
+                .contains(ScanKinds::IPV4)
+                .then(|| ScanMatch::new(start, end, ScannedAddress::IPAddress(ip.to_ip())));
+        }
+        if let Some(ip) = Self::parse_ipv6(token) {
+            return self
+                .kinds
+                .contains(ScanKinds::IPV6)
+                .then(|| ScanMatch::new(start, end, ScannedAddress::IPAddress(ip.to_ip())));
+        }
+        if let Ok(authority) = Authority::parse_text(token)
+            && authority
+                .host()
+                .to_domain_ref()
+                .is_some_and(|domain| Self::is_domain_like(domain.name()))
+        {
+            if self.kinds.contains(ScanKinds::AUTHORITY) {
+                return found(ScannedAddress::Authority(authority));
+            }
+            let domain_end: usize = end - 1 - token.iter().rev().position(|c| *c == b':').unwrap_or(0);
+            return self.classify(text, start, domain_end);
+        }
+        if let Ok(domain) = Domain::parse_text(token)
+            && Self::is_domain_like(domain.name())
+            && self.kinds.contains(ScanKinds::DOMAIN)
+        {
+            return found(ScannedAddress::Domain(domain));
+        }
+        None
+    }
+
+    /// Parses the IPv6 address `text`, which must contain a hex digit so `::` alone is not an address.
+    fn parse_ipv6(text: &[u8]) -> Option<IPv6Address> {
+        if text.iter().any(u8::is_ascii_hexdigit) {
+            IPv6Address::parse_text(text).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Checks if the domain `name` has at least 2 labels & a final label of at least 2 letters.
+    fn is_domain_like(name: &str) -> bool {
+        match name.rsplit_once('.') {
+            Some((_, last)) => last.len() >= 2 && last.bytes().all(|c| c.is_ascii_alphabetic()),
+            None => false,
+        }
+    }
+}
+
+impl From<ScanKinds> for AddressScanner {
+    fn from(kinds: ScanKinds) -> Self {
+        Self::new(kinds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AddressScanner, ScanKinds, ScanMatch, ScannedAddress};
+    use alloc::string::String;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+    use core::ops::Range;
+
+    /// Scans the `text` & formats each match as its span text & kind.
+    fn scan(kinds: ScanKinds, text: &str) -> Vec<(String, &'static str)> {
+        AddressScanner::new(kinds)
+            .scan_str(text)
+            .into_iter()
+            .map(|found: ScanMatch| {
+                let span: Range<usize> = found.span();
+                let kind: &str = match found.address() {
+                    ScannedAddress::IPAddress(_) => "ip",
+                    ScannedAddress::SocketAddress(_) => "socket",
+                    ScannedAddress::Domain(_) => "domain",
+                    ScannedAddress::Authority(_) => "authority",
+                };
+                (text[span].to_string(), kind)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn scan_all() {
+        let test_cases: &[(&str, &[(&str, &str)])] = &[
+            ("connect to 192.0.2.1 failed", &[("192.0.2.1", "ip")]),
+            ("11.2.3.45 & 1.2.3.4.5", &[("11.2.3.45", "ip")]),
+            (
+                "from 192.0.2.1:443, to [2001:db8::1]:80.",
+                &[("192.0.2.1:443", "socket"), ("[2001:db8::1]:80", "socket")],
+            ),
+            (
+                "ip=2001:db8::1 prefix 2001:db8::",
+                &[("2001:db8::1", "ip"), ("2001:db8::", "ip")],
+            ),
+            ("bracketed [::1] & (fe80::1)", &[("[::1]", "ip"), ("fe80::1", "ip")]),
+            ("Visit Example.com.", &[("Example.com", "domain")]),
+            ("see example.com:8443/path", &[("example.com:8443", "authority")]),
+            ("mail user@mail.example.org", &[("mail.example.org", "domain")]),
+            (
+                "ip:192.0.2.1 host:example.com",
+                &[("192.0.2.1", "ip"), ("example.com", "domain")],
+            ),
+            ("std::vec e.g. v1.2.3 :: 12:30:45", &[]),
+            (
+                "-192.0.2.1- \u{fc}ber 10.0.0.1\u{fc}",
+                &[("192.0.2.1", "ip"), ("10.0.0.1", "ip")],
+            ),
+            ("https://example.com/", &[("example.com", "domain")]),
+        ];
+
+        for (text, expected) in test_cases {
+            let expected: Vec<(String, &str)> = expected.iter().map(|(span, kind)| (span.to_string(), *kind)).collect();
+            assert_eq!(scan(ScanKinds::ALL, text), expected, "text={}", text);
+        }
+    }
+
+    #[test]
+    fn scan_kinds() {
+        let text: &str = "192.0.2.1:443 [::1]:80 2001:db8::1 example.com:443 example.org";
+        let test_cases: &[(ScanKinds, &[(&str, &str)])] = &[
+            (ScanKinds::IPV4, &[("192.0.2.1", "ip")]),
+            (ScanKinds::IPV6, &[("[::1]", "ip"), ("2001:db8::1", "ip")]),
+            (
+                ScanKinds::SOCKET,
+                &[("192.0.2.1:443", "socket"), ("[::1]:80", "socket")],
+            ),
+            (
+                ScanKinds::DOMAIN,
+                &[("example.com", "domain"), ("example.org", "domain")],
+            ),
+            (ScanKinds::AUTHORITY, &[("example.com:443", "authority")]),
+            (ScanKinds::NONE, &[]),
+        ];
+
+        for (kinds, expected) in test_cases {
+            let expected: Vec<(String, &str)> = expected.iter().map(|(span, kind)| (span.to_string(), *kind)).collect();
+            assert_eq!(scan(*kinds, text), expected, "kinds={:?}", kinds);
+        }
+    }
+
+    #[test]
+    fn scan_values() {
+        let found: Vec<ScanMatch> = AddressScanner::default().scan(b"EXAMPLE.com");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].span(), 0..11);
+        assert_eq!(
+            found[0].clone().into_address(),
+            ScannedAddress::Domain(crate::Domain::example())
+        );
+    }
+}
@@ -0,0 +1,7 @@
+pub use address_scanner::*;
+pub use scan_kinds::*;
+pub use scan_match::*;
+
+mod address_scanner;
+mod scan_kinds;
+mod scan_match;
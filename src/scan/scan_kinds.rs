@@ -0,0 +1,82 @@
+use core::ops::BitOr;
+
+/// The kinds of addresses an [AddressScanner](crate::AddressScanner) searches for.
+///
+/// Kinds combine with `|`: `ScanKinds::IPV4 | ScanKinds::IPV6`.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct ScanKinds {
+    bits: u8,
+}
+
+impl ScanKinds {
+    //! Constants
+
+    /// No kinds.
+    pub const NONE: Self = Self { bits: 0 };
+
+    /// IPv4 addresses: `192.0.2.1`.
+    pub const IPV4: Self = Self { bits: 1 };
+
+    /// IPv6 addresses, bare or bracketed: `2001:db8::1` or `[2001:db8::1]`.
+    pub const IPV6: Self = Self { bits: 1 << 1 };
+
+    /// Socket addresses: `192.0.2.1:80` or `[2001:db8::1]:80`.
+    pub const SOCKET: Self = Self { bits: 1 << 2 };
+
+    /// Domains: `example.com`.
+    pub const DOMAIN: Self = Self { bits: 1 << 3 };
+
+    /// Authorities with a domain host: `example.com:443`.
+    pub const AUTHORITY: Self = Self { bits: 1 << 4 };
+
+    /// IPv4 & IPv6 addresses.
+    pub const IP: Self = Self {
+        bits: Self::IPV4.bits | Self::IPV6.bits,
+    };
+
+    /// Every kind.
+    pub const ALL: Self = Self {
+        bits: Self::IP.bits | Self::SOCKET.bits | Self::DOMAIN.bits | Self::AUTHORITY.bits,
+    };
+}
+
+impl ScanKinds {
+    //! Matching
+
+    /// Checks if the kinds contain all of the `other` kinds.
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.bits & other.bits == other.bits
+    }
+}
+
+impl Default for ScanKinds {
+    /// Every kind.
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl BitOr for ScanKinds {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self {
+            bits: self.bits | other.bits,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ScanKinds;
+
+    #[test]
+    fn contains() {
+        assert!(ScanKinds::ALL.contains(ScanKinds::SOCKET));
+        assert!(ScanKinds::IP.contains(ScanKinds::IPV4 | ScanKinds::IPV6));
+        assert!(!ScanKinds::IP.contains(ScanKinds::DOMAIN));
+        assert!(ScanKinds::NONE.contains(ScanKinds::NONE));
+        assert_eq!(ScanKinds::default(), ScanKinds::ALL);
+    }
+}
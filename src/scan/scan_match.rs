@@ -0,0 +1,57 @@
+use crate::{Authority, Domain, IPAddress, SocketAddress};
+use core::ops::Range;
+
+/// An address found in text by an [AddressScanner](crate::AddressScanner).
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct ScanMatch {
+    start: usize,
+    end: usize,
+    address: ScannedAddress,
+}
+
+/// The parsed value of a [ScanMatch].
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum ScannedAddress {
+    /// An IPv4 or IPv6 address.
+    IPAddress(IPAddress),
+
+    /// A socket address.
+    SocketAddress(SocketAddress),
+
+    /// A domain.
+    Domain(Domain),
+
+    /// An authority with a domain host.
+    Authority(Authority),
+}
+
+impl ScanMatch {
+    //! Construction
+
+    /// Creates a new [ScanMatch].
+    pub(crate) const fn new(start: usize, end: usize, address: ScannedAddress) -> Self {
+        Self { start, end, address }
+    }
+}
+
+impl ScanMatch {
+    //! Properties
+
+    /// Gets the byte span of the match in the text.
+    #[must_use]
+    pub const fn span(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Gets the parsed address.
+    #[must_use]
+    pub const fn address(&self) -> &ScannedAddress {
+        &self.address
+    }
+
+    /// Converts the match into the parsed address.
+    #[must_use]
+    pub fn into_address(self) -> ScannedAddress {
+        self.address
+    }
+}
@@ -0,0 +1,308 @@
+use crate::selection::rules::{common_prefix_len, scope};
+use crate::{IPAddress, PolicyTable, SocketAddress};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Orders destination addresses & chooses source addresses by the rules of RFC 6724.
+///
+/// The selector knows the local source addresses & a [PolicyTable], & nothing else about the host: rules that need
+/// interface state (deprecated, home, & temporary addresses, the outgoing interface, & native transport) are not
+/// applied. A destination with no source address of its version is unusable & sorts last.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct AddressSelector {
+    sources: Vec<IPAddress>,
+    policy: PolicyTable,
+}
+
+impl AddressSelector {
+    //! Construction
+
+    /// Creates a new [AddressSelector] for the local `sources` with the default RFC 6724 policy table.
+    #[must_use]
+    pub fn new(sources: Vec<IPAddress>) -> Self {
+        Self {
+            sources,
+            policy: PolicyTable::rfc6724(),
+        }
+    }
+
+    /// Sets the `policy` table.
+    #[must_use]
+    pub fn with_policy(mut self, policy: PolicyTable) -> Self {
+        self.policy = policy;
+        self
+    }
+}
+
+impl AddressSelector {
+    //! Properties
+
+    /// Gets the local source addresses.
+    pub fn sources(&self) -> &[IPAddress] {
+        self.sources.as_slice()
+    }
+
+    /// Gets the policy table.
+    #[must_use]
+    pub const fn policy(&self) -> &PolicyTable {
+        &self.policy
+    }
+}
+
+impl AddressSelector {
+    //! Source Selection
+
+    /// Chooses the source address for the `destination` by the rules of RFC 6724 section 5.
+    ///
+    /// Returns `None` when there is no source address of the version of the `destination`.
+    #[must_use]
+    pub fn select_source(&self, destination: IPAddress) -> Option<IPAddress> {
+        self.sources
+            .iter()
+            .copied()
+            .filter(|source| source.is_v4() == destination.is_v4())
+            .reduce(|best, source| match self.compare_sources(best, source, destination) {
+                Ordering::Greater => source,
+                _ => best,
+            })
+    }
+
+    /// Compares the sources `a` & `b` for the `destination`: `Less` prefers `a`.
+    fn compare_sources(&self, a: IPAddress, b: IPAddress, destination: IPAddress) -> Ordering {
+        // Rule 1: prefer the same address.
+        if a == destination || b == destination {
+            return (b == destination).cmp(&(a == destination));
+        }
+
+        // Rule 2: prefer the appropriate scope.
+        let (scope_a, scope_b, scope_d): (u8, u8, u8) = (scope(a), scope(b), scope(destination));
+        if scope_a != scope_b {
+            return if scope_a < scope_b {
+                if scope_a < scope_d {
+                    Ordering::Greater
+                } else {
+                    Ordering::Less
+                }
+            } else if scope_b < scope_d {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+
+        // Rule 6: prefer the matching label.
+        let label_d: Option<u8> = self.policy.label(destination);
+        let match_a: bool = self.policy.label(a) == label_d;
+        let match_b: bool = self.policy.label(b) == label_d;
+        if match_a != match_b {
+            return match_b.cmp(&match_a);
+        }
+
+        // Rule 8: use the longest matching prefix.
+        common_prefix_len(b, destination).cmp(&common_prefix_len(a, destination))
+    }
+}
+
+impl AddressSelector {
+    //! Destination Sorting
+
+    /// Sorts the `destinations` by the rules of RFC 6724 section 6, most preferred first.
+    ///
+    /// The sort is stable, so destinations no rule separates keep their order.
+    pub fn sort(&self, destinations: &mut [IPAddress]) {
+        self.sort_by_ip(destinations, |ip| *ip);
+    }
+
+    /// Sorts the socket `destinations` by their IP addresses by the rules of RFC 6724 section 6, most preferred first.
+    ///
+    /// The sort is stable, so destinations no rule separates keep their order.
+    pub fn sort_sockets(&self, destinations: &mut [SocketAddress]) {
+        self.sort_by_ip(destinations, |socket| socket.ip());
+    }
+
+    /// Sorts the `destinations` by their IP addresses.
+    ///
+    /// The rules are a pairwise comparison that is not a total order across address versions, so this is an insertion
+    /// sort, which needs no more than a consistent comparison of neighbors. Resolver results are short.
+    fn sort_by_ip<T: Copy>(&self, destinations: &mut [T], ip: fn(&T) -> IPAddress) {
+        let mut candidates: Vec<(T, IPAddress, Option<IPAddress>)> = destinations
+            .iter()
+            .map(|destination| {
+                let ip: IPAddress = ip(destination);
+                (*destination, ip, self.select_source(ip))
+            })
+            .collect();
+        for i in 1..candidates.len() {
+            let mut j: usize = i;
+            while j > 0 && self.compare_destinations(&candidates[j - 1], &candidates[j]) == Ordering::Greater {
+                candidates.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+        for (destination, (candidate, _, _)) in destinations.iter_mut().zip(candidates) {
+            *destination = candidate;
+        }
+    }
+
+    /// Compares the destinations `a` & `b` with their chosen sources: `Less` prefers `a`.
+    fn compare_destinations<T>(
+        &self,
+        (_, a, source_a): &(T, IPAddress, Option<IPAddress>),
+        (_, b, source_b): &(T, IPAddress, Option<IPAddress>),
+    ) -> Ordering {
+        // Rule 1: avoid unusable destinations.
+        let (source_a, source_b): (IPAddress, IPAddress) = match (source_a, source_b) {
+            (Some(source_a), Some(source_b)) => (*source_a, *source_b),
+            (source_a, source_b) => return source_b.is_some().cmp(&source_a.is_some()),
+        };
+
+        // Rule 2: prefer the matching scope.
+        let match_a: bool = scope(*a) == scope(source_a);
+        let match_b: bool = scope(*b) == scope(source_b);
+        if match_a != match_b {
+            return match_b.cmp(&match_a);
+        }
+
+        // Rule 5: prefer the matching label.
+        let match_a: bool = self.policy.label(*a) == self.policy.label(source_a);
+        let match_b: bool = self.policy.label(*b) == self.policy.label(source_b);
+        if match_a != match_b {
+            return match_b.cmp(&match_a);
+        }
+
+        // Rule 6: prefer the higher precedence.
+        let precedence: Ordering = self.policy.precedence(*b).cmp(&self.policy.precedence(*a));
+        if precedence != Ordering::Equal {
+            return precedence;
+        }
+
+        // Rule 8: prefer the smaller scope.
+        let smaller: Ordering = scope(*a).cmp(&scope(*b));
+        if smaller != Ordering::Equal {
+            return smaller;
+        }
+
+        // Rule 9: use the longest matching prefix, for destinations of the same version.
+        if a.is_v4() == b.is_v4() {
+            return common_prefix_len(source_b, *b).cmp(&common_prefix_len(source_a, *a));
+        }
+
+        // Rule 10: otherwise, leave the order unchanged.
+        Ordering::Equal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AddressSelector, IPAddress, PolicyEntry, PolicyTable, SocketAddress};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    fn ips(text: &[&str]) -> Vec<IPAddress> {
+        text.iter().map(|ip| ip.parse().unwrap()).collect()
+    }
+
+    /// The destination address selection examples of RFC 6724 section 10.2.
+    #[test]
+    fn sort() {
+        let test_cases: &[(&[&str], &[&str], &[&str])] = &[
+            (
+                &["2001:db8:1::2", "fe80::1", "169.254.13.78"],
+                &["2001:db8:1::1", "198.51.100.121"],
+                &["2001:db8:1::1", "198.51.100.121"],
+            ),
+            (
+                &["fe80::1", "198.51.100.117"],
+                &["2001:db8:1::1", "198.51.100.121"],
+                &["198.51.100.121", "2001:db8:1::1"],
+            ),
+            (
+                &["2001:db8:1::2", "fe80::1", "10.1.2.4"],
+                &["2001:db8:1::1", "10.1.2.3"],
+                &["2001:db8:1::1", "10.1.2.3"],
+            ),
+            (
+                &["2001:db8:1::2", "fe80::2"],
+                &["2001:db8:1::1", "fe80::1"],
+                &["fe80::1", "2001:db8:1::1"],
+            ),
+            (
+                &["2002:c633:6401::2", "fe80::2"],
+                &["2001:db8:1::1", "2002:c633:6401::1"],
+                &["2002:c633:6401::1", "2001:db8:1::1"],
+            ),
+            (
+                &["2002:c633:6401::2", "2001:db8:1::2", "fe80::2"],
+                &["2002:c633:6401::1", "2001:db8:1::1"],
+                &["2001:db8:1::1", "2002:c633:6401::1"],
+            ),
+            (
+                &["2001:db8:1::2", "2002:c633:6401::2", "fe80::2"],
+                &["2001:db8:1::1", "2002:c633:6401::1", "fe80::1"],
+                &["fe80::1", "2001:db8:1::1", "2002:c633:6401::1"],
+            ),
+        ];
+
+        // Each case: the local sources, the destinations, & the expected order.
+        for (sources, destinations, expected) in test_cases {
+            let selector: AddressSelector = AddressSelector::new(ips(sources));
+            let mut result: Vec<IPAddress> = ips(destinations);
+            selector.sort(&mut result);
+            assert_eq!(result, ips(expected), "destinations={:?}", destinations);
+        }
+    }
+
+    /// The source address selection examples of RFC 6724 section 10.1.
+    #[test]
+    fn select_source() {
+        let test_cases: &[(&[&str], &str, Option<&str>)] = &[
+            (&["2001:db8:1::1", "fe80::1"], "2001:db8:1::1", Some("2001:db8:1::1")),
+            (&["2001:db8:1::1", "fe80::1"], "ff05::1", Some("2001:db8:1::1")),
+            (&["2001:db8:1::1", "fe80::1"], "fe80::2", Some("fe80::1")),
+            (
+                &["2001:db8:1::1", "2002:c633:6401::1"],
+                "2001:db8:1::2",
+                Some("2001:db8:1::1"),
+            ),
+            (
+                &["2001:db8:1::1", "2002:c633:6401::1"],
+                "2002:c633:6401::2",
+                Some("2002:c633:6401::1"),
+            ),
+            (&["2001:db8:1::1", "10.0.0.1"], "192.0.2.1", Some("10.0.0.1")),
+            (&["2001:db8:1::1"], "192.0.2.1", None),
+        ];
+
+        for (sources, destination, expected) in test_cases {
+            let selector: AddressSelector = AddressSelector::new(ips(sources));
+            let result: Option<IPAddress> = selector.select_source(destination.parse().unwrap());
+            let expected: Option<IPAddress> = expected.map(|ip| ip.parse().unwrap());
+            assert_eq!(result, expected, "destination={}", destination);
+        }
+    }
+
+    /// A destination without a source of its version sorts last, & NAT64 addresses are ordinary global IPv6.
+    #[test]
+    fn sort_sockets() {
+        let selector: AddressSelector = AddressSelector::new(ips(&["2001:db8:1::1"]));
+        let mut sockets: Vec<SocketAddress> = vec![
+            "192.0.2.1:443".parse().unwrap(),
+            "[64:ff9b::c000:201]:443".parse().unwrap(),
+        ];
+        selector.sort_sockets(&mut sockets);
+        assert_eq!(sockets[0], "[64:ff9b::c000:201]:443".parse().unwrap());
+    }
+
+    /// A custom policy table that prefers IPv4 over IPv6.
+    #[test]
+    fn with_policy() {
+        let mut policy: PolicyTable = PolicyTable::rfc6724();
+        policy.insert(PolicyEntry::new("::ffff:0.0.0.0".parse().unwrap(), 96, 100, 4).unwrap());
+        let selector: AddressSelector =
+            AddressSelector::new(ips(&["2001:db8:1::1", "198.51.100.121"])).with_policy(policy);
+
+        let mut result: Vec<IPAddress> = ips(&["2001:db8:1::2", "198.51.100.1"]);
+        selector.sort(&mut result);
+        assert_eq!(result, ips(&["198.51.100.1", "2001:db8:1::2"]));
+    }
+}
@@ -0,0 +1,6 @@
+pub use address_selector::*;
+pub use policy_table::*;
+
+mod address_selector;
+mod policy_table;
+mod rules;
@@ -0,0 +1,261 @@
+use crate::{IPAddress, IPv6Address};
+use alloc::vec::Vec;
+
+/// An entry of a [PolicyTable]: an IPv6 prefix with its precedence & label.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct PolicyEntry {
+    prefix: IPv6Address,
+    prefix_len: u8,
+    precedence: u8,
+    label: u8,
+}
+
+impl PolicyEntry {
+    //! Construction
+
+    /// Creates a new [PolicyEntry].
+    ///
+    /// Returns `None` when the `prefix_len` exceeds 128.
+    #[must_use]
+    pub const fn new(prefix: IPv6Address, prefix_len: u8, precedence: u8, label: u8) -> Option<Self> {
+        if prefix_len > 128 {
+            None
+        } else {
+            Some(Self {
+                prefix,
+                prefix_len,
+                precedence,
+                label,
+            })
+        }
+    }
+
+    /// Creates a new [PolicyEntry] from the `segments` of the prefix.
+    const fn from_segments(segments: [u16; 8], prefix_len: u8, precedence: u8, label: u8) -> Self {
+        Self {
+            prefix: IPv6Address::from_segments(segments),
+            prefix_len,
+            precedence,
+            label,
+        }
+    }
+}
+
+impl PolicyEntry {
+    //! Properties
+
+    /// Gets the prefix.
+    pub const fn prefix(&self) -> IPv6Address {
+        self.prefix
+    }
+
+    /// Gets the prefix length in bits.
+    #[must_use]
+    pub const fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// Gets the precedence. Higher precedences are preferred.
+    #[must_use]
+    pub const fn precedence(&self) -> u8 {
+        self.precedence
+    }
+
+    /// Gets the label. Sources are preferred for destinations with the same label.
+    #[must_use]
+    pub const fn label(&self) -> u8 {
+        self.label
+    }
+}
+
+impl PolicyEntry {
+    //! Matching
+
+    /// Checks if the `ip` starts with the prefix.
+    #[must_use]
+    pub const fn contains(&self, ip: IPv6Address) -> bool {
+        let mask: u128 = if self.prefix_len == 0 {
+            0
+        } else {
+            u128::MAX << (128 - self.prefix_len as u32)
+        };
+        let prefix: u128 = u128::from_be_bytes(self.prefix.address());
+        let ip: u128 = u128::from_be_bytes(ip.address());
+        prefix & mask == ip & mask
+    }
+}
+
+/// The policy table of RFC 6724, which assigns a precedence & a label to each address by its longest matching prefix.
+///
+/// IPv4 addresses are looked up as IPv4-mapped IPv6 addresses (`::ffff:a.b.c.d`).
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct PolicyTable {
+    entries: Vec<PolicyEntry>,
+}
+
+impl PolicyTable {
+    //! Construction
+
+    /// The default policy table of RFC 6724 section 2.1.
+    const RFC_6724: [PolicyEntry; 9] = [
+        PolicyEntry::from_segments([0, 0, 0, 0, 0, 0, 0, 1], 128, 50, 0),
+        PolicyEntry::from_segments([0, 0, 0, 0, 0, 0, 0, 0], 0, 40, 1),
+        PolicyEntry::from_segments([0, 0, 0, 0, 0, 0xFFFF, 0, 0], 96, 35, 4),
+        PolicyEntry::from_segments([0x2002, 0, 0, 0, 0, 0, 0, 0], 16, 30, 2),
+        PolicyEntry::from_segments([0x2001, 0, 0, 0, 0, 0, 0, 0], 32, 5, 5),
+        PolicyEntry::from_segments([0xFC00, 0, 0, 0, 0, 0, 0, 0], 7, 3, 13),
+        PolicyEntry::from_segments([0, 0, 0, 0, 0, 0, 0, 0], 96, 1, 3),
+        PolicyEntry::from_segments([0xFEC0, 0, 0, 0, 0, 0, 0, 0], 10, 1, 11),
+        PolicyEntry::from_segments([0x3FFE, 0, 0, 0, 0, 0, 0, 0], 16, 1, 12),
+    ];
+
+    /// Creates an empty [PolicyTable].
+    ///
+    /// Addresses that match no entry have a precedence of 0 & no label.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { entries: Vec::new() }
+    }
+
+    /// Creates the default policy table of RFC 6724 section 2.1.
+    #[must_use]
+    pub fn rfc6724() -> Self {
+        Self {
+            entries: Self::RFC_6724.to_vec(),
+        }
+    }
+
+    /// Adds the `entry` to the table, replacing an entry with the same prefix & prefix length.
+    pub fn insert(&mut self, entry: PolicyEntry) {
+        self.entries
+            .retain(|e| e.prefix() != entry.prefix() || e.prefix_len() != entry.prefix_len());
+        self.entries.push(entry);
+    }
+}
+
+impl Default for PolicyTable {
+    /// The default policy table of RFC 6724 section 2.1.
+    fn default() -> Self {
+        Self::rfc6724()
+    }
+}
+
+impl From<Vec<PolicyEntry>> for PolicyTable {
+    fn from(entries: Vec<PolicyEntry>) -> Self {
+        Self { entries }
+    }
+}
+
+impl PolicyTable {
+    //! Properties
+
+    /// Gets the entries.
+    #[must_use]
+    pub fn entries(&self) -> &[PolicyEntry] {
+        self.entries.as_slice()
+    }
+}
+
+impl PolicyTable {
+    //! Lookups
+
+    /// Gets the entry with the longest prefix matching the `ip`.
+    ///
+    /// Of entries with equal prefix lengths, the first one wins.
+    #[must_use]
+    pub fn lookup(&self, ip: IPAddress) -> Option<&PolicyEntry> {
+        let ip: IPv6Address = match ip {
+            IPAddress::V4(ip) => ip.to_v6_mapped(),
+            IPAddress::V6(ip) => ip,
+        };
+        self.entries.iter().filter(|entry| entry.contains(ip)).fold(
+            None,
+            |best: Option<&PolicyEntry>, entry| match best {
+                Some(best) if best.prefix_len() >= entry.prefix_len() => Some(best),
+                _ => Some(entry),
+            },
+        )
+    }
+
+    /// Gets the precedence of the `ip`, or 0 when no entry matches.
+    #[must_use]
+    pub fn precedence(&self, ip: IPAddress) -> u8 {
+        self.lookup(ip).map_or(0, PolicyEntry::precedence)
+    }
+
+    /// Gets the label of the `ip`, or `None` when no entry matches.
+    #[must_use]
+    pub fn label(&self, ip: IPAddress) -> Option<u8> {
+        self.lookup(ip).map(PolicyEntry::label)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IPAddress, IPv4Address, IPv6Address, PolicyEntry, PolicyTable};
+
+    #[test]
+    fn entry_new() {
+        assert!(PolicyEntry::new(IPv6Address::UNSPECIFIED, 128, 1, 1).is_some());
+        assert!(PolicyEntry::new(IPv6Address::UNSPECIFIED, 129, 1, 1).is_none());
+    }
+
+    #[test]
+    fn lookup() {
+        let table: PolicyTable = PolicyTable::rfc6724();
+        let test_cases: &[(IPAddress, u8, Option<u8>)] = &[
+            (IPv6Address::LOCALHOST.to_ip(), 50, Some(0)),
+            (
+                IPv6Address::from_segments([0x2001, 0xDB8, 0, 0, 0, 0, 0, 1]).to_ip(),
+                40,
+                Some(1),
+            ),
+            (IPv4Address::LOCALHOST.to_ip(), 35, Some(4)),
+            (IPv4Address::new([192, 0, 2, 1]).to_v6_mapped().to_ip(), 35, Some(4)),
+            (
+                IPv6Address::from_segments([0x2002, 0xC000, 0x201, 0, 0, 0, 0, 1]).to_ip(),
+                30,
+                Some(2),
+            ),
+            (
+                IPv6Address::from_segments([0x2001, 0, 0, 0, 0, 0, 0, 1]).to_ip(),
+                5,
+                Some(5),
+            ),
+            (
+                IPv6Address::from_segments([0xFD00, 0, 0, 0, 0, 0, 0, 1]).to_ip(),
+                3,
+                Some(13),
+            ),
+            (
+                IPv6Address::from_segments([0, 0, 0, 0, 0, 0, 0xC000, 0x201]).to_ip(),
+                1,
+                Some(3),
+            ),
+            (
+                IPv6Address::from_segments([0xFEC0, 0, 0, 0, 0, 0, 0, 1]).to_ip(),
+                1,
+                Some(11),
+            ),
+            (
+                IPv6Address::from_segments([0x3FFE, 0, 0, 0, 0, 0, 0, 1]).to_ip(),
+                1,
+                Some(12),
+            ),
+            (
+                IPv6Address::from_segments([0x64, 0xFF9B, 0, 0, 0, 0, 0xC000, 0x201]).to_ip(),
+                40,
+                Some(1),
+            ),
+        ];
+
+        for (ip, precedence, label) in test_cases {
+            assert_eq!(table.precedence(*ip), *precedence, "ip={}", ip);
+            assert_eq!(table.label(*ip), *label, "ip={}", ip);
+        }
+
+        let table: PolicyTable = PolicyTable::new();
+        assert_eq!(table.precedence(IPv6Address::LOCALHOST.to_ip()), 0);
+        assert_eq!(table.label(IPv6Address::LOCALHOST.to_ip()), None);
+    }
+}
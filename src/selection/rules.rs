@@ -0,0 +1,111 @@
+use crate::IPAddress;
+
+/// The interface-local scope.
+const INTERFACE_LOCAL: u8 = 0x1;
+
+/// The link-local scope.
+const LINK_LOCAL: u8 = 0x2;
+
+/// The site-local scope.
+const SITE_LOCAL: u8 = 0x5;
+
+/// The global scope.
+const GLOBAL: u8 = 0xE;
+
+/// Gets the scope of the `ip`, as defined by RFC 6724 section 3.1.
+///
+/// IPv6 loopback & link-local addresses have the link-local scope, as do IPv4 loopback (`127.0.0.0/8`) & link-local
+/// (`169.254.0.0/16`) addresses. Multicast addresses have the scope of their scope field. Other addresses, including
+/// private IPv4 addresses, have the global scope.
+pub(crate) const fn scope(ip: IPAddress) -> u8 {
+    match ip {
+        IPAddress::V4(ip) => match ip.address() {
+            [127, ..] | [169, 254, ..] => LINK_LOCAL,
+            _ => GLOBAL,
+        },
+        IPAddress::V6(ip) => {
+            let address: [u8; 16] = ip.address();
+            if address[0] == 0xFF {
+                match address[1] & 0x0F {
+                    0 => INTERFACE_LOCAL,
+                    scope => scope,
+                }
+            } else if u128::from_be_bytes(address) == 1 || (address[0] == 0xFE && address[1] & 0xC0 == 0x80) {
+                LINK_LOCAL
+            } else if address[0] == 0xFE && address[1] & 0xC0 == 0xC0 {
+                SITE_LOCAL
+            } else {
+                GLOBAL
+            }
+        }
+    }
+}
+
+/// Gets the length of the longest common prefix of the `source` & `destination` of the same version, in bits.
+///
+/// IPv6 prefixes are limited to 64 bits, the usual length of an on-link prefix, so rule 9 does not order addresses
+/// by their interface identifiers. Addresses of different versions have no common prefix.
+pub(crate) const fn common_prefix_len(source: IPAddress, destination: IPAddress) -> u32 {
+    match (source, destination) {
+        (IPAddress::V4(source), IPAddress::V4(destination)) => {
+            let source: u32 = u32::from_be_bytes(source.address());
+            let destination: u32 = u32::from_be_bytes(destination.address());
+            (source ^ destination).leading_zeros()
+        }
+        (IPAddress::V6(source), IPAddress::V6(destination)) => {
+            let source: u128 = u128::from_be_bytes(source.address());
+            let destination: u128 = u128::from_be_bytes(destination.address());
+            let len: u32 = (source ^ destination).leading_zeros();
+            if len > 64 { 64 } else { len }
+        }
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IPAddress;
+    use crate::selection::rules::{common_prefix_len, scope};
+
+    #[test]
+    fn scopes() {
+        let test_cases: &[(&str, u8)] = &[
+            ("::1", 0x2),
+            ("fe80::1", 0x2),
+            ("febf::1", 0x2),
+            ("fec0::1", 0x5),
+            ("ff01::1", 0x1),
+            ("ff02::1", 0x2),
+            ("ff05::1", 0x5),
+            ("ff0e::1", 0xE),
+            ("2001:db8::1", 0xE),
+            ("fd00::1", 0xE),
+            ("127.0.0.1", 0x2),
+            ("169.254.1.1", 0x2),
+            ("10.0.0.1", 0xE),
+            ("192.0.2.1", 0xE),
+        ];
+
+        for (input, expected) in test_cases {
+            let ip: IPAddress = input.parse().unwrap();
+            assert_eq!(scope(ip), *expected, "input={}", input);
+        }
+    }
+
+    #[test]
+    fn common_prefix_lens() {
+        let test_cases: &[(&str, &str, u32)] = &[
+            ("10.0.0.1", "10.0.0.1", 32),
+            ("10.0.0.1", "10.0.0.2", 30),
+            ("10.0.0.1", "192.0.2.1", 0),
+            ("2001:db8::1", "2001:db8::2", 64),
+            ("2001:db8::1", "2001:db9::1", 31),
+            ("2001:db8::1", "10.0.0.1", 0),
+        ];
+
+        for (source, destination, expected) in test_cases {
+            let result: u32 = common_prefix_len(source.parse().unwrap(), destination.parse().unwrap());
+            assert_eq!(result, *expected, "source={} destination={}", source, destination);
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::serde::FromStringVisitor;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+use core::marker::PhantomData;
+use core::str::FromStr;
+use serde::de::{Error, Unexpected, Visitor};
+
+/// A serde visitor that borrows strings from the input with `TryFrom<&str>`, & parses strings it cannot borrow as
+/// the owned type `O` with the [FromStringVisitor].
+pub(crate) struct CowVisitor<'de, T, O> {
+    expecting: &'static str,
+    phantom: PhantomData<fn(&'de str) -> T>,
+    owned: PhantomData<fn() -> O>,
+}
+
+impl<'de, T, O> CowVisitor<'de, T, O> {
+    //! Construction
+
+    /// Creates a new visitor with the `expecting` message.
+    pub(crate) const fn new(expecting: &'static str) -> Self {
+        Self {
+            expecting,
+            phantom: PhantomData,
+            owned: PhantomData,
+        }
+    }
+
+    /// Creates the visitor for the owned type.
+    fn owned(&self) -> FromStringVisitor<O> {
+        FromStringVisitor::new(self.expecting)
+    }
+}
+
+impl<'de, T, O> Visitor<'de> for CowVisitor<'de, T, O>
+where
+    T: TryFrom<&'de str> + From<O>,
+    <T as TryFrom<&'de str>>::Error: Display,
+    O: FromStr + TryFrom<String> + TryFrom<Vec<u8>>,
+    <O as FromStr>::Err: Display,
+    <O as TryFrom<String>>::Error: Display,
+    <O as TryFrom<Vec<u8>>>::Error: Display,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        T::try_from(v).map_err(E::custom)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.owned().visit_str(v).map(T::from)
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.owned().visit_string(v).map(T::from)
+    }
+
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match core::str::from_utf8(v) {
+            Ok(s) => self.visit_borrowed_str(s),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(v), &self)),
+        }
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.owned().visit_bytes(v).map(T::from)
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.owned().visit_byte_buf(v).map(T::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::serde::CowVisitor;
+    use crate::{CowDomain, Domain, DomainRef};
+    use serde::Deserializer;
+    use serde::de::value::{BorrowedBytesDeserializer, BytesDeserializer, Error as ValueError};
+
+    /// Borrowed bytes are borrowed when normalized; transient bytes are always parsed as owned.
+    #[test]
+    fn visit_bytes() {
+        let visitor: CowVisitor<CowDomain, Domain> = CowVisitor::new("a domain string");
+        let deserializer: BorrowedBytesDeserializer<ValueError> = BorrowedBytesDeserializer::new(b"localhost");
+        let domain: CowDomain = deserializer.deserialize_str(visitor).unwrap();
+        assert!(domain.is_borrowed());
+        assert_eq!(domain, DomainRef::LOCALHOST);
+
+        let visitor: CowVisitor<CowDomain, Domain> = CowVisitor::new("a domain string");
+        let deserializer: BytesDeserializer<ValueError> = BytesDeserializer::new(b"LocalHost");
+        let domain: CowDomain = deserializer.deserialize_str(visitor).unwrap();
+        assert!(domain.is_owned());
+        assert_eq!(domain, DomainRef::LOCALHOST);
+
+        let visitor: CowVisitor<CowDomain, Domain> = CowVisitor::new("a domain string");
+        let deserializer: BorrowedBytesDeserializer<ValueError> = BorrowedBytesDeserializer::new(b"\xFF");
+        assert!(deserializer.deserialize_str(visitor).is_err());
+    }
+}
@@ -1,7 +1,7 @@
+use core::fmt::{Display, Formatter};
+use core::marker::PhantomData;
+use core::str::FromStr;
 use serde::de::{Error, Unexpected, Visitor};
-use std::fmt::{Display, Formatter};
-use std::marker::PhantomData;
-use std::str::FromStr;
 
 /// A serde visitor that parses a string with `FromStr`.
 pub(crate) struct FromStrVisitor<T> {
@@ -28,7 +28,7 @@ where
 {
     type Value = T;
 
-    fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn expecting(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         f.write_str(self.expecting)
     }
 
@@ -43,7 +43,7 @@ where
     where
         E: Error,
     {
-        match std::str::from_utf8(v) {
+        match core::str::from_utf8(v) {
             Ok(s) => self.visit_str(s),
             Err(_) => Err(E::invalid_value(Unexpected::Bytes(v), &self)),
         }
@@ -1,7 +1,9 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+use core::marker::PhantomData;
+use core::str::FromStr;
 use serde::de::{Error, Unexpected, Visitor};
-use std::fmt::{Display, Formatter};
-use std::marker::PhantomData;
-use std::str::FromStr;
 
 /// A serde visitor that parses a string with `FromStr`, reusing the buffers of owned strings and byte vectors
 /// with the consuming `TryFrom` conversions.
@@ -31,7 +33,7 @@ where
 {
     type Value = T;
 
-    fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn expecting(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         f.write_str(self.expecting)
     }
 
@@ -53,7 +55,7 @@ where
     where
         E: Error,
     {
-        match std::str::from_utf8(v) {
+        match core::str::from_utf8(v) {
             Ok(s) => self.visit_str(s),
             Err(_) => Err(E::invalid_value(Unexpected::Bytes(v), &self)),
         }
@@ -71,6 +73,7 @@ where
 mod tests {
     use crate::Domain;
     use crate::serde::FromStringVisitor;
+    use alloc::vec::Vec;
     use serde::Deserializer;
     use serde::de::Visitor;
     use serde::de::value::{BytesDeserializer, Error as ValueError};
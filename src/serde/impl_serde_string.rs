@@ -52,24 +52,74 @@ macro_rules! impl_serde_string_ref {
     };
 }
 
+/// Implements `Serialize` and `Deserialize` for a maybe-owned type that serializes as its `Display` string.
+macro_rules! impl_serde_string_cow {
+    ($ty:ident, $owned:ident, $expecting:literal) => {
+        impl<'a> ::serde::Serialize for crate::$ty<'a> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de: 'a, 'a> ::serde::Deserialize<'de> for crate::$ty<'a> {
+            /// The string is borrowed from the input when it is already normalized, and parsed as owned when it is
+            /// mixed-case, contains escape sequences, or cannot be borrowed from the format.
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                deserializer.deserialize_str(crate::serde::CowVisitor::<crate::$ty<'de>, crate::$owned>::new(
+                    $expecting,
+                ))
+            }
+        }
+    };
+}
+
+impl_serde_string!(Address, "an address string");
+impl_serde_string_ref!(AddressRef, Address, "a borrowed address string");
+
 impl_serde_string!(Authority, "an authority string");
 impl_serde_string_ref!(AuthorityRef, Authority, "a borrowed authority string");
+impl_serde_string_cow!(CowAuthority, Authority, "an authority string");
 
 impl_serde_string!(Domain, "a domain string");
 impl_serde_string_ref!(DomainRef, Domain, "a borrowed domain string");
+impl_serde_string_cow!(CowDomain, Domain, "a domain string");
 
 impl_serde_string!(Endpoint, "an endpoint string");
 impl_serde_string_ref!(EndpointRef, Endpoint, "a borrowed endpoint string");
+impl_serde_string_cow!(CowEndpoint, Endpoint, "an endpoint string");
 
 impl_serde_string!(Host, "a host string");
 impl_serde_string_ref!(HostRef, Host, "a borrowed host string");
+impl_serde_string_cow!(CowHost, Host, "a host string");
+
+impl_serde_string!(HostPort, "a host & optional port string");
+impl_serde_string_ref!(HostPortRef, HostPort, "a borrowed host & optional port string");
+
+impl_serde_string!(Origin, "an origin string");
+
+impl_serde_string!(Scheme, "a scheme string");
+
+impl_serde_string!(UriAuthority, "a URI authority string");
+impl_serde_string!(UriHost, "a URI host string");
+impl_serde_string!(RegName, "a registered name string");
+impl_serde_string!(IPvFuture, "an IPvFuture literal string");
 
 #[cfg(test)]
 mod tests {
     use crate::serde::test_util::{assert_json, assert_postcard};
     use crate::{
-        Authority, AuthorityRef, Domain, DomainRef, Endpoint, EndpointRef, Host, HostRef, IPv4Address, IPv6Address,
+        Address, AddressKind, AddressRef, Authority, AuthorityRef, CowAuthority, CowDomain, CowEndpoint, CowHost,
+        Domain, DomainRef, Endpoint, EndpointRef, Host, HostPort, HostRef, IPv4Address, IPv6Address, Origin, Scheme,
     };
+    use alloc::string::String;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
 
     #[test]
     fn json() {
@@ -80,6 +130,16 @@ mod tests {
         assert_json(IPv4Address::LOCALHOST.to_host(), "\"127.0.0.1\"");
         assert_json(Domain::localhost().to_host().to_authority(80), "\"localhost:80\"");
         assert_json(IPv6Address::LOCALHOST.to_host().to_authority(80), "\"[::1]:80\"");
+        assert_json(HostPort::new(IPv6Address::LOCALHOST.to_host(), None), "\"[::1]\"");
+        assert_json(
+            HostPort::new(Domain::localhost().to_host(), Some(80)),
+            "\"localhost:80\"",
+        );
+        assert_json(Scheme::https(), "\"https\"");
+        assert_json(
+            Origin::new(Scheme::https(), Domain::example().to_host().to_authority(443)),
+            "\"https://example.com\"",
+        );
     }
 
     /// Domain-bearing types are strings in every format, binary included.
@@ -144,6 +204,59 @@ mod tests {
         assert!(serde_json::from_str::<HostRef>(json).is_err());
     }
 
+    /// The maybe-owned types borrow normalized input & fall back to owned for mixed-case & escaped input.
+    #[test]
+    fn cow() {
+        let domain: CowDomain = serde_json::from_str("\"localhost\"").unwrap();
+        assert!(domain.is_borrowed());
+        assert_eq!(domain, Domain::localhost());
+
+        let domain: CowDomain = serde_json::from_str("\"LocalHost\"").unwrap();
+        assert!(domain.is_owned());
+        assert_eq!(domain, Domain::localhost());
+
+        let host: CowHost = serde_json::from_str("\"local\\u0068ost\"").unwrap();
+        assert!(host.is_owned());
+        assert_eq!(host, Domain::localhost().to_host());
+
+        let endpoint: CowEndpoint = serde_json::from_str("\"localhost:80\"").unwrap();
+        assert!(endpoint.is_borrowed());
+        assert_eq!(serde_json::to_string(&endpoint).unwrap(), "\"localhost:80\"");
+
+        let authority: CowAuthority = serde_json::from_str("\"[::1]:443\"").unwrap();
+        assert!(authority.is_borrowed());
+        assert_eq!(authority, IPv6Address::LOCALHOST.to_host().to_authority(443));
+
+        let bytes: Vec<u8> = postcard::to_allocvec(&Domain::example().to_endpoint(443)).unwrap();
+        let endpoint: CowEndpoint = postcard::from_bytes(bytes.as_slice()).unwrap();
+        assert!(endpoint.is_borrowed());
+        assert_eq!(endpoint, Domain::example().to_endpoint(443));
+
+        assert!(serde_json::from_str::<CowDomain>("\"Local!Host\"").is_err());
+        let error: String = serde_json::from_str::<CowAuthority>("42").unwrap_err().to_string();
+        assert!(error.contains("an authority string"), "error={}", error);
+    }
+
+    /// Addresses are classified by their text, so every kind round-trips through a single string field.
+    #[test]
+    fn address() {
+        assert_json(Address::from(IPv6Address::LOCALHOST), "\"::1\"");
+        assert_json(
+            Address::from(IPv4Address::LOCALHOST.to_socket(80).to_socket()),
+            "\"127.0.0.1:80\"",
+        );
+        assert_json(Address::from(Domain::localhost()), "\"localhost\"");
+        assert_json(Address::from(Domain::localhost().to_endpoint(80)), "\"localhost:80\"");
+        assert_postcard(Address::from(IPv6Address::LOCALHOST.to_socket(443).to_socket()));
+
+        let address: AddressRef = serde_json::from_str("\"[::1]:80\"").unwrap();
+        assert_eq!(address.kind(), AddressKind::SocketAddress);
+
+        let address: Address = serde_json::from_str("\"LocalHost:80\"").unwrap();
+        assert_eq!(address, Address::from(Domain::localhost().to_endpoint(80)));
+        assert!(serde_json::from_str::<AddressRef>("\"LocalHost:80\"").is_err());
+    }
+
     #[test]
     fn invalid_input() {
         assert!(serde_json::from_str::<Domain>("\"Local!Host\"").is_err());
@@ -43,7 +43,8 @@ impl_serde_string_or_binary!(SocketAddressV6, "an IPv6 socket address string", (
 mod tests {
     use crate::serde::test_util::{assert_json, assert_postcard};
     use crate::{IPv4Address, IPv6Address, SocketAddress, SocketAddressV4, SocketAddressV6};
-    use std::net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
+    use alloc::vec::Vec;
+    use core::net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
 
     #[test]
     fn json() {
@@ -1,8 +1,8 @@
 use crate::serde::FromStrVisitor;
 use crate::{IPAddress, IPv4Address, IPv6Address};
+use core::fmt::Formatter;
 use serde::de::{Error, SeqAccess, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use std::fmt::Formatter;
 
 impl Serialize for IPAddress {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -23,7 +23,7 @@ struct IPAddressBytesVisitor;
 impl<'de> Visitor<'de> for IPAddressBytesVisitor {
     type Value = IPAddress;
 
-    fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn expecting(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         f.write_str("4 or 16 IP address bytes")
     }
 
@@ -75,6 +75,7 @@ mod tests {
     use crate::serde::ip_address::IPAddressBytesVisitor;
     use crate::serde::test_util::{assert_json, assert_postcard};
     use crate::{IPAddress, IPv4Address, IPv6Address};
+    use alloc::vec::Vec;
     use serde::Deserializer;
     use serde::de::value::{Error as ValueError, SeqDeserializer};
 
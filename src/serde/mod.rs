@@ -3,10 +3,12 @@
 //! This module is private, so its docs are not published. The wire contract these impls must uphold is stated in
 //! the README, under `Serde Wire Contract`, which is the crate's front page.
 
+pub(crate) use cow_visitor::*;
 pub(crate) use from_str_visitor::*;
 pub(crate) use from_string_visitor::*;
 pub(crate) use try_from_str_visitor::*;
 
+mod cow_visitor;
 mod from_str_visitor;
 mod from_string_visitor;
 mod try_from_str_visitor;
@@ -14,12 +16,15 @@ mod try_from_str_visitor;
 mod impl_serde_string;
 mod impl_serde_string_or_binary;
 mod ip_address;
+mod port;
 
 #[cfg(test)]
 pub(crate) mod test_util {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use core::fmt::Debug;
     use serde::Serialize;
     use serde::de::DeserializeOwned;
-    use std::fmt::Debug;
 
     /// Asserts the value serializes to the `expected` JSON & deserializes back to an equal value.
     pub(crate) fn assert_json<T>(value: T, expected: &str)
@@ -0,0 +1,39 @@
+use crate::Port;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl Serialize for Port {
+    /// Serializes as the `u16` port number in every format, matching the port field of the socket address tuples.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u16(self.value())
+    }
+}
+
+impl<'de> Deserialize<'de> for Port {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        u16::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Port;
+    use crate::serde::test_util::{assert_json, assert_postcard};
+
+    #[test]
+    fn json() {
+        assert_json(Port::new(80), "80");
+        assert_json(Port::new(65535), "65535");
+    }
+
+    #[test]
+    fn postcard() {
+        assert_postcard(Port::new(0));
+        assert_postcard(Port::new(65535));
+    }
+}
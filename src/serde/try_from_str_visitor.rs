@@ -1,6 +1,6 @@
+use core::fmt::{Display, Formatter};
+use core::marker::PhantomData;
 use serde::de::{Error, Unexpected, Visitor};
-use std::fmt::{Display, Formatter};
-use std::marker::PhantomData;
 
 /// A serde visitor that parses a string with `TryFrom`.
 pub(crate) struct TryFromStrVisitor<'de, T> {
@@ -27,7 +27,7 @@ where
 {
     type Value = T;
 
-    fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn expecting(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         f.write_str(self.expecting)
     }
 
@@ -42,7 +42,7 @@ where
     where
         E: Error,
     {
-        match std::str::from_utf8(v) {
+        match core::str::from_utf8(v) {
             Ok(s) => self.visit_borrowed_str(s),
             Err(_) => Err(E::invalid_value(Unexpected::Bytes(v), &self)),
         }
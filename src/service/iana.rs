@@ -0,0 +1,124 @@
+use crate::TransportProtocol;
+use crate::TransportProtocol::{Sctp, Tcp, Udp};
+
+/// An embedded service entry: `(name, port, protocol, aliases)`.
+pub(crate) type IanaService = (&'static str, u16, TransportProtocol, &'static [&'static str]);
+
+/// Common service names from the
+/// [IANA Service Name & Transport Protocol Port Number Registry](https://www.iana.org/assignments/service-names-port-numbers),
+/// sorted by port.
+///
+/// This is a subset of the registry: the names in everyday use in URLs, configuration files, & `/etc/services`.
+/// Aliases are the historical names that `/etc/services` files carry alongside the IANA name.
+pub(crate) const IANA_SERVICES: &[IanaService] = &[
+    ("echo", 7, Tcp, &[]),
+    ("echo", 7, Udp, &[]),
+    ("discard", 9, Tcp, &["sink", "null"]),
+    ("discard", 9, Udp, &["sink", "null"]),
+    ("daytime", 13, Tcp, &[]),
+    ("daytime", 13, Udp, &[]),
+    ("ftp-data", 20, Tcp, &[]),
+    ("ftp", 21, Tcp, &[]),
+    ("ssh", 22, Tcp, &[]),
+    ("telnet", 23, Tcp, &[]),
+    ("smtp", 25, Tcp, &["mail"]),
+    ("time", 37, Tcp, &["timserver"]),
+    ("time", 37, Udp, &["timserver"]),
+    ("nicname", 43, Tcp, &["whois"]),
+    ("domain", 53, Tcp, &[]),
+    ("domain", 53, Udp, &[]),
+    ("bootps", 67, Udp, &[]),
+    ("bootpc", 68, Udp, &[]),
+    ("tftp", 69, Udp, &[]),
+    ("gopher", 70, Tcp, &[]),
+    ("finger", 79, Tcp, &[]),
+    ("http", 80, Tcp, &["www", "www-http"]),
+    ("http", 80, Udp, &["www", "www-http"]),
+    ("http", 80, Sctp, &["www", "www-http"]),
+    ("kerberos", 88, Tcp, &["kerberos5", "krb5"]),
+    ("kerberos", 88, Udp, &["kerberos5", "krb5"]),
+    ("pop3", 110, Tcp, &["pop-3"]),
+    ("sunrpc", 111, Tcp, &["portmapper"]),
+    ("sunrpc", 111, Udp, &["portmapper"]),
+    ("auth", 113, Tcp, &["ident"]),
+    ("nntp", 119, Tcp, &["readnews"]),
+    ("ntp", 123, Udp, &[]),
+    ("netbios-ns", 137, Udp, &[]),
+    ("netbios-dgm", 138, Udp, &[]),
+    ("netbios-ssn", 139, Tcp, &[]),
+    ("imap", 143, Tcp, &["imap2"]),
+    ("snmp", 161, Udp, &[]),
+    ("snmptrap", 162, Udp, &["snmp-trap"]),
+    ("bgp", 179, Tcp, &[]),
+    ("irc", 194, Tcp, &[]),
+    ("ldap", 389, Tcp, &[]),
+    ("ldap", 389, Udp, &[]),
+    ("https", 443, Tcp, &[]),
+    ("https", 443, Udp, &[]),
+    ("https", 443, Sctp, &[]),
+    ("microsoft-ds", 445, Tcp, &[]),
+    ("kpasswd", 464, Tcp, &[]),
+    ("kpasswd", 464, Udp, &[]),
+    ("submissions", 465, Tcp, &["smtps", "ssmtp"]),
+    ("syslog", 514, Udp, &[]),
+    ("printer", 515, Tcp, &["spooler"]),
+    ("submission", 587, Tcp, &[]),
+    ("ipp", 631, Tcp, &[]),
+    ("ldaps", 636, Tcp, &[]),
+    ("domain-s", 853, Tcp, &[]),
+    ("domain-s", 853, Udp, &[]),
+    ("rsync", 873, Tcp, &[]),
+    ("ftps-data", 989, Tcp, &[]),
+    ("ftps", 990, Tcp, &[]),
+    ("telnets", 992, Tcp, &[]),
+    ("imaps", 993, Tcp, &[]),
+    ("pop3s", 995, Tcp, &[]),
+    ("socks", 1080, Tcp, &[]),
+    ("openvpn", 1194, Tcp, &[]),
+    ("openvpn", 1194, Udp, &[]),
+    ("ms-sql-s", 1433, Tcp, &[]),
+    ("radius", 1812, Udp, &[]),
+    ("radius-acct", 1813, Udp, &[]),
+    ("mqtt", 1883, Tcp, &[]),
+    ("nfs", 2049, Tcp, &[]),
+    ("nfs", 2049, Udp, &[]),
+    ("mysql", 3306, Tcp, &[]),
+    ("ms-wbt-server", 3389, Tcp, &[]),
+    ("svn", 3690, Tcp, &[]),
+    ("sip", 5060, Tcp, &[]),
+    ("sip", 5060, Udp, &[]),
+    ("sips", 5061, Tcp, &[]),
+    ("xmpp-client", 5222, Tcp, &[]),
+    ("xmpp-server", 5269, Tcp, &[]),
+    ("postgresql", 5432, Tcp, &["postgres"]),
+    ("amqps", 5671, Tcp, &[]),
+    ("amqp", 5672, Tcp, &[]),
+    ("x11", 6000, Tcp, &[]),
+    ("redis", 6379, Tcp, &[]),
+    ("ircs-u", 6697, Tcp, &[]),
+    ("http-alt", 8080, Tcp, &["webcache"]),
+    ("secure-mqtt", 8883, Tcp, &[]),
+    ("memcache", 11211, Tcp, &[]),
+    ("memcache", 11211, Udp, &[]),
+    ("mongodb", 27017, Tcp, &[]),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::service::iana::IANA_SERVICES;
+
+    /// The table is sorted by port & has no duplicate `(name, protocol)` pairs.
+    #[test]
+    fn well_formed() {
+        for pair in IANA_SERVICES.windows(2) {
+            assert!(pair[0].1 <= pair[1].1, "unsorted at {}", pair[1].0);
+        }
+
+        for (i, (name, _, protocol, _)) in IANA_SERVICES.iter().enumerate() {
+            let duplicate: bool = IANA_SERVICES[i + 1..]
+                .iter()
+                .any(|(other, _, other_protocol, _)| other == name && other_protocol == protocol);
+            assert!(!duplicate, "duplicate {}/{:?}", name, protocol);
+        }
+    }
+}
@@ -0,0 +1,14 @@
+#[cfg(feature = "alloc")]
+pub use service::*;
+#[cfg(feature = "alloc")]
+pub use service_registry::*;
+pub use transport_protocol::*;
+
+#[cfg(feature = "alloc")]
+mod service;
+#[cfg(feature = "alloc")]
+mod service_registry;
+mod transport_protocol;
+
+#[cfg(feature = "alloc")]
+mod iana;
@@ -0,0 +1,105 @@
+use crate::{Port, TransportProtocol};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A named service: a port & protocol with a name & optional aliases.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct Service {
+    name: String,
+    port: Port,
+    protocol: TransportProtocol,
+    aliases: Vec<String>,
+}
+
+impl Service {
+    //! Construction
+
+    /// Creates a new [Service].
+    pub fn new<S>(name: S, port: Port, protocol: TransportProtocol) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            port,
+            protocol,
+            aliases: Vec::default(),
+        }
+    }
+
+    /// Adds the `alias` to the service.
+    #[must_use]
+    pub fn with_alias<S>(mut self, alias: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.aliases.push(alias.into());
+        self
+    }
+}
+
+impl Service {
+    //! Properties
+
+    /// Gets the name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Gets the port.
+    pub const fn port(&self) -> Port {
+        self.port
+    }
+
+    /// Gets the protocol.
+    #[must_use]
+    pub const fn protocol(&self) -> TransportProtocol {
+        self.protocol
+    }
+
+    /// Gets the aliases.
+    pub fn aliases(&self) -> impl Iterator<Item = &str> {
+        self.aliases.iter().map(String::as_str)
+    }
+
+    /// Gets the name followed by the aliases.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        core::iter::once(self.name()).chain(self.aliases())
+    }
+}
+
+impl Service {
+    //! Matching
+
+    /// Checks if the `name` case-insensitively matches the service name or one of its aliases.
+    #[must_use]
+    pub fn is_named(&self, name: &str) -> bool {
+        self.names().any(|n| n.eq_ignore_ascii_case(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Port, Service, TransportProtocol};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn construction() {
+        let service: Service = Service::new("http", Port::new(80), TransportProtocol::Tcp).with_alias("www");
+        assert_eq!(service.name(), "http");
+        assert_eq!(service.port(), 80);
+        assert_eq!(service.protocol(), TransportProtocol::Tcp);
+        assert_eq!(service.aliases().collect::<Vec<&str>>(), ["www"]);
+        assert_eq!(service.names().collect::<Vec<&str>>(), ["http", "www"]);
+    }
+
+    #[test]
+    fn is_named() {
+        let service: Service = Service::new("http", Port::new(80), TransportProtocol::Tcp).with_alias("www");
+        assert!(service.is_named("http"));
+        assert!(service.is_named("HTTP"));
+        assert!(service.is_named("www"));
+        assert!(!service.is_named("https"));
+    }
+}
@@ -0,0 +1,169 @@
+use crate::service::iana::IANA_SERVICES;
+use crate::{Port, Service, TransportProtocol};
+use alloc::vec::Vec;
+
+/// A registry of named services, for looking up ports by service name & service names by port.
+///
+/// Lookups are case-insensitive & match aliases. Use [`Self::iana`] for the embedded IANA names, or
+/// [`Self::parse_services`] to build a registry from an `/etc/services` file.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct ServiceRegistry {
+    services: Vec<Service>,
+}
+
+impl ServiceRegistry {
+    //! Construction
+
+    /// Creates an empty [ServiceRegistry].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { services: Vec::new() }
+    }
+
+    /// Creates a [ServiceRegistry] with the embedded IANA service names.
+    ///
+    /// The embedded table is the subset of the IANA registry in everyday use, not the full registry.
+    #[must_use]
+    pub fn iana() -> Self {
+        let services: Vec<Service> = IANA_SERVICES
+            .iter()
+            .map(|(name, port, protocol, aliases)| {
+                aliases
+                    .iter()
+                    .fold(Service::new(*name, Port::new(*port), *protocol), |service, alias| {
+                        service.with_alias(*alias)
+                    })
+            })
+            .collect();
+        Self { services }
+    }
+
+    /// Adds the `service` to the registry.
+    ///
+    /// Earlier services take precedence in name lookups, matching the first-match rule of `getservbyname`.
+    pub fn insert(&mut self, service: Service) {
+        self.services.push(service);
+    }
+}
+
+impl From<Vec<Service>> for ServiceRegistry {
+    fn from(services: Vec<Service>) -> Self {
+        Self { services }
+    }
+}
+
+impl ServiceRegistry {
+    //! Properties
+
+    /// Gets the services.
+    #[must_use]
+    pub fn services(&self) -> &[Service] {
+        self.services.as_slice()
+    }
+
+    /// Checks if the registry is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.services.is_empty()
+    }
+}
+
+impl ServiceRegistry {
+    //! Lookups
+
+    /// Gets the port of the service `name` for the `protocol`.
+    #[must_use]
+    pub fn port(&self, name: &str, protocol: TransportProtocol) -> Option<Port> {
+        self.services
+            .iter()
+            .find(|service| service.protocol() == protocol && service.is_named(name))
+            .map(Service::port)
+    }
+
+    /// Gets the services on the `port` for the `protocol`.
+    pub fn services_on(&self, port: Port, protocol: TransportProtocol) -> impl Iterator<Item = &Service> {
+        self.services
+            .iter()
+            .filter(move |service| service.port() == port && service.protocol() == protocol)
+    }
+
+    /// Gets the service names & aliases on the `port` for the `protocol`.
+    pub fn names(&self, port: Port, protocol: TransportProtocol) -> impl Iterator<Item = &str> {
+        self.services_on(port, protocol).flat_map(Service::names)
+    }
+}
+
+impl Port {
+    //! IANA Services
+
+    /// Gets the port of the embedded IANA service `name` for the `protocol`. (see [`ServiceRegistry::iana`])
+    ///
+    /// Unlike a registry lookup, this does not allocate.
+    #[must_use]
+    pub fn from_iana_name(name: &str, protocol: TransportProtocol) -> Option<Self> {
+        IANA_SERVICES
+            .iter()
+            .find(|(n, _, p, aliases)| {
+                *p == protocol && (n.eq_ignore_ascii_case(name) || aliases.iter().any(|a| a.eq_ignore_ascii_case(name)))
+            })
+            .map(|(_, port, _, _)| Self::new(*port))
+    }
+
+    /// Gets the embedded IANA service names on the port for the `protocol`, excluding aliases.
+    pub fn iana_names(self, protocol: TransportProtocol) -> impl Iterator<Item = &'static str> {
+        IANA_SERVICES
+            .iter()
+            .filter(move |(_, port, p, _)| *port == self.value() && *p == protocol)
+            .map(|(name, _, _, _)| *name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TransportProtocol::{Tcp, Udp};
+    use crate::{Port, Service, ServiceRegistry};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn port() {
+        let registry: ServiceRegistry = ServiceRegistry::iana();
+        let test_cases: &[(&str, crate::TransportProtocol, Option<u16>)] = &[
+            ("http", Tcp, Some(80)),
+            ("HTTPS", Tcp, Some(443)),
+            ("www", Tcp, Some(80)),
+            ("postgres", Tcp, Some(5432)),
+            ("domain", Udp, Some(53)),
+            ("ntp", Tcp, None),
+            ("unknown", Tcp, None),
+        ];
+
+        for (name, protocol, expected) in test_cases {
+            let expected: Option<Port> = expected.map(Port::new);
+            assert_eq!(registry.port(name, *protocol), expected, "name={}", name);
+            assert_eq!(Port::from_iana_name(name, *protocol), expected, "name={}", name);
+        }
+    }
+
+    #[test]
+    fn names() {
+        let registry: ServiceRegistry = ServiceRegistry::iana();
+        let names: Vec<&str> = registry.names(Port::new(80), Tcp).collect();
+        assert_eq!(names, ["http", "www", "www-http"]);
+
+        let names: Vec<&str> = Port::new(80).iana_names(Tcp).collect();
+        assert_eq!(names, ["http"]);
+
+        assert_eq!(registry.names(Port::new(1), Tcp).count(), 0);
+    }
+
+    #[test]
+    fn insert() {
+        let mut registry: ServiceRegistry = ServiceRegistry::new();
+        assert!(registry.is_empty());
+
+        registry.insert(Service::new("app", Port::new(8000), Tcp));
+        registry.insert(Service::new("app", Port::new(9000), Tcp));
+        assert_eq!(registry.port("app", Tcp), Some(Port::new(8000)));
+        assert_eq!(registry.services().len(), 2);
+    }
+}
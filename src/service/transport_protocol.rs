@@ -0,0 +1,60 @@
+/// A transport protocol of a service name.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum TransportProtocol {
+    /// The Transmission Control Protocol.
+    Tcp,
+
+    /// The User Datagram Protocol.
+    Udp,
+
+    /// The Stream Control Transmission Protocol.
+    Sctp,
+
+    /// The Datagram Congestion Control Protocol.
+    Dccp,
+}
+
+impl TransportProtocol {
+    //! Properties
+
+    /// Gets the lowercase protocol name, as written in a services file. (`tcp`)
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Tcp => "tcp",
+            Self::Udp => "udp",
+            Self::Sctp => "sctp",
+            Self::Dccp => "dccp",
+        }
+    }
+
+    /// Gets the protocol with the case-insensitive `name`.
+    #[must_use]
+    pub fn from_name(name: &[u8]) -> Option<Self> {
+        [Self::Tcp, Self::Udp, Self::Sctp, Self::Dccp]
+            .into_iter()
+            .find(|protocol| protocol.name().as_bytes().eq_ignore_ascii_case(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TransportProtocol;
+
+    #[test]
+    fn from_name() {
+        let test_cases: &[(&str, Option<TransportProtocol>)] = &[
+            ("tcp", Some(TransportProtocol::Tcp)),
+            ("UDP", Some(TransportProtocol::Udp)),
+            ("sctp", Some(TransportProtocol::Sctp)),
+            ("dccp", Some(TransportProtocol::Dccp)),
+            ("ddp", None),
+            ("", None),
+        ];
+
+        for (name, expected) in test_cases {
+            let result: Option<TransportProtocol> = TransportProtocol::from_name(name.as_bytes());
+            assert_eq!(result, *expected, "name={}", name);
+        }
+    }
+}
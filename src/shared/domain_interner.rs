@@ -0,0 +1,125 @@
+use crate::{AuthorityRef, DomainRef, EndpointRef, HostRef, SharedAuthority, SharedDomain, SharedEndpoint, SharedHost};
+use alloc::collections::BTreeSet;
+
+/// Deduplicates domain names: every domain interned with the same name shares one allocation.
+///
+/// Interned domains with equal names are [pointer-equal](SharedDomain::ptr_eq), so code holding only interned
+/// domains can compare them without reading the names. The interner keeps each name alive until it is
+/// [purged](Self::purge).
+#[derive(Clone, Debug, Default)]
+pub struct DomainInterner {
+    domains: BTreeSet<SharedDomain>,
+}
+
+impl DomainInterner {
+    //! Construction
+
+    /// Creates a new empty [DomainInterner].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            domains: BTreeSet::new(),
+        }
+    }
+}
+
+impl DomainInterner {
+    //! Properties
+
+    /// Gets the number of interned names.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.domains.len()
+    }
+
+    /// Checks if no names are interned.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.domains.is_empty()
+    }
+
+    /// Gets the interned domain with the name of the `domain`, if it was interned.
+    #[must_use]
+    pub fn get(&self, domain: DomainRef) -> Option<&SharedDomain> {
+        self.domains.get(domain.name())
+    }
+}
+
+impl DomainInterner {
+    //! Interning
+
+    /// Interns the `domain`, returning the shared domain with its name.
+    pub fn intern(&mut self, domain: DomainRef) -> SharedDomain {
+        if let Some(shared) = self.get(domain) {
+            shared.clone()
+        } else {
+            let shared: SharedDomain = domain.into();
+            self.domains.insert(shared.clone());
+            shared
+        }
+    }
+
+    /// Interns the domain of the `host`, if it has one.
+    pub fn intern_host(&mut self, host: HostRef) -> SharedHost {
+        match host {
+            HostRef::Name(domain) => SharedHost::Name(self.intern(domain)),
+            HostRef::Address(ip) => SharedHost::Address(ip),
+        }
+    }
+
+    /// Interns the domain of the `endpoint`.
+    pub fn intern_endpoint(&mut self, endpoint: EndpointRef) -> SharedEndpoint {
+        SharedEndpoint::new(self.intern(endpoint.domain()), endpoint.port())
+    }
+
+    /// Interns the domain of the `authority`, if it has one.
+    pub fn intern_authority(&mut self, authority: AuthorityRef) -> SharedAuthority {
+        SharedAuthority::new(self.intern_host(authority.host()), authority.port())
+    }
+
+    /// Removes the names that are only held by the interner.
+    pub fn purge(&mut self) {
+        self.domains.retain(|domain| domain.share_count() > 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AuthorityRef, DomainInterner, DomainRef, EndpointRef, IPv4Address, SharedAuthority, SharedDomain};
+
+    #[test]
+    fn intern() {
+        let mut interner: DomainInterner = DomainInterner::new();
+        let a: SharedDomain = interner.intern(DomainRef::LOCALHOST);
+        let b: SharedDomain = interner.intern("localhost".try_into().unwrap());
+        assert!(a.ptr_eq(&b));
+        assert_eq!(interner.len(), 1);
+
+        let endpoint: SharedDomain = interner
+            .intern_endpoint(EndpointRef::new(DomainRef::LOCALHOST, 80))
+            .domain()
+            .clone();
+        assert!(endpoint.ptr_eq(&a));
+
+        let authority: SharedAuthority = interner.intern_authority(AuthorityRef::from((DomainRef::EXAMPLE, 443)));
+        assert_eq!(authority.to_ref(), AuthorityRef::from((DomainRef::EXAMPLE, 443)));
+        assert!(interner.get(DomainRef::EXAMPLE).is_some());
+        assert_eq!(interner.len(), 2);
+
+        let address: SharedAuthority = interner.intern_authority(AuthorityRef::from((IPv4Address::LOCALHOST, 80)));
+        assert!(address.host().is_ip());
+        assert_eq!(interner.len(), 2);
+    }
+
+    #[test]
+    fn purge() {
+        let mut interner: DomainInterner = DomainInterner::new();
+        let kept: SharedDomain = interner.intern(DomainRef::LOCALHOST);
+        drop(interner.intern(DomainRef::EXAMPLE));
+
+        interner.purge();
+        assert_eq!(interner.len(), 1);
+        assert!(interner.get(DomainRef::LOCALHOST).unwrap().ptr_eq(&kept));
+        assert!(interner.get(DomainRef::EXAMPLE).is_none());
+    }
+}
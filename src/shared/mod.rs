@@ -0,0 +1,11 @@
+pub use domain_interner::*;
+pub use shared_authority::*;
+pub use shared_domain::*;
+pub use shared_endpoint::*;
+pub use shared_host::*;
+
+mod domain_interner;
+mod shared_authority;
+mod shared_domain;
+mod shared_endpoint;
+mod shared_host;
@@ -0,0 +1,125 @@
+use crate::{Authority, AuthorityRef, SharedEndpoint, SharedHost};
+
+/// An [Authority] whose domain name is shared: a [SharedHost] & a port.
+#[must_use]
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct SharedAuthority {
+    host: SharedHost,
+    port: u16,
+}
+
+impl SharedAuthority {
+    //! Construction
+
+    /// Creates a new [SharedAuthority].
+    pub const fn new(host: SharedHost, port: u16) -> Self {
+        Self { host, port }
+    }
+}
+
+impl<H: Into<SharedHost>> From<(H, u16)> for SharedAuthority {
+    fn from(tuple: (H, u16)) -> Self {
+        Self::new(tuple.0.into(), tuple.1)
+    }
+}
+
+impl<'a> From<AuthorityRef<'a>> for SharedAuthority {
+    fn from(authority: AuthorityRef<'a>) -> Self {
+        Self::new(authority.host().into(), authority.port())
+    }
+}
+
+impl From<SharedEndpoint> for SharedAuthority {
+    fn from(endpoint: SharedEndpoint) -> Self {
+        Self::new(SharedHost::Name(endpoint.domain().clone()), endpoint.port())
+    }
+}
+
+impl From<&Authority> for SharedAuthority {
+    fn from(authority: &Authority) -> Self {
+        authority.to_ref().into()
+    }
+}
+
+impl From<Authority> for SharedAuthority {
+    fn from(authority: Authority) -> Self {
+        authority.to_ref().into()
+    }
+}
+
+impl From<&SharedAuthority> for Authority {
+    fn from(authority: &SharedAuthority) -> Self {
+        authority.to_authority()
+    }
+}
+
+impl<'a> PartialEq<AuthorityRef<'a>> for SharedAuthority {
+    fn eq(&self, other: &AuthorityRef<'a>) -> bool {
+        self.to_ref() == *other
+    }
+}
+
+impl<'a> PartialEq<SharedAuthority> for AuthorityRef<'a> {
+    fn eq(&self, other: &SharedAuthority) -> bool {
+        *self == other.to_ref()
+    }
+}
+
+impl PartialEq<Authority> for SharedAuthority {
+    fn eq(&self, other: &Authority) -> bool {
+        self.to_ref() == other.to_ref()
+    }
+}
+
+impl PartialEq<SharedAuthority> for Authority {
+    fn eq(&self, other: &SharedAuthority) -> bool {
+        self.to_ref() == other.to_ref()
+    }
+}
+
+impl SharedAuthority {
+    //! Properties
+
+    /// Gets the host.
+    pub const fn host(&self) -> &SharedHost {
+        &self.host
+    }
+
+    /// Gets the port.
+    #[must_use]
+    pub const fn port(&self) -> u16 {
+        self.port
+    }
+}
+
+impl SharedAuthority {
+    //! Conversions
+
+    /// Converts the authority to an authority reference.
+    pub fn to_ref(&self) -> AuthorityRef<'_> {
+        AuthorityRef::new(self.host.to_ref(), self.port)
+    }
+
+    /// Converts the authority to an unshared authority.
+    pub fn to_authority(&self) -> Authority {
+        self.to_ref().to_authority()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Authority, AuthorityRef, Domain, DomainRef, IPv4Address, SharedAuthority, SharedEndpoint};
+
+    #[test]
+    fn conversions() {
+        let authority: SharedAuthority = SharedEndpoint::from((Domain::localhost(), 80)).into();
+        assert_eq!(authority, AuthorityRef::from((DomainRef::LOCALHOST, 80)));
+        assert_eq!(authority.port(), 80);
+        assert!(authority.host().is_domain());
+
+        let authority: SharedAuthority = (IPv4Address::LOCALHOST, 443).into();
+        let owned: Authority = Authority::from(&authority);
+        assert_eq!(owned, authority);
+        assert_eq!(SharedAuthority::from(owned), authority);
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{Domain, DomainRef};
+use alloc::sync::Arc;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// A [Domain] whose name is shared behind an [Arc], so cloning is O(1) & never allocates.
+///
+/// Equality, ordering, & hashing compare the names, exactly as for [Domain]. Use [Self::ptr_eq] to check if two
+/// domains share a name, such as the domains of a [DomainInterner](crate::DomainInterner).
+#[must_use]
+#[derive(Clone)]
+pub struct SharedDomain {
+    name: Arc<str>,
+}
+
+impl SharedDomain {
+    //! Construction
+
+    /// Creates a new [SharedDomain] from a copy of the `domain` name.
+    pub fn new(domain: DomainRef) -> Self {
+        Self {
+            name: Arc::from(domain.name()),
+        }
+    }
+}
+
+impl<'a> From<DomainRef<'a>> for SharedDomain {
+    fn from(domain: DomainRef<'a>) -> Self {
+        Self::new(domain)
+    }
+}
+
+impl From<&Domain> for SharedDomain {
+    fn from(domain: &Domain) -> Self {
+        Self::new(domain.to_ref())
+    }
+}
+
+impl From<Domain> for SharedDomain {
+    fn from(domain: Domain) -> Self {
+        Self::new(domain.to_ref())
+    }
+}
+
+impl From<&SharedDomain> for Domain {
+    fn from(domain: &SharedDomain) -> Self {
+        domain.to_domain()
+    }
+}
+
+impl SharedDomain {
+    //! Properties
+
+    /// Gets the name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Checks if the domain shares its name with the `other` domain.
+    #[must_use]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.name, &other.name)
+    }
+
+    /// Gets the number of domains sharing the name.
+    #[must_use]
+    pub fn share_count(&self) -> usize {
+        Arc::strong_count(&self.name)
+    }
+}
+
+impl SharedDomain {
+    //! Conversions
+
+    /// Converts the domain to a domain reference.
+    pub fn to_ref(&self) -> DomainRef<'_> {
+        unsafe { DomainRef::new_unchecked(self.name()) }
+    }
+
+    /// Converts the domain to an unshared domain.
+    pub fn to_domain(&self) -> Domain {
+        self.to_ref().to_domain()
+    }
+}
+
+impl PartialEq for SharedDomain {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other) || self.name() == other.name()
+    }
+}
+
+impl Eq for SharedDomain {}
+
+impl PartialOrd for SharedDomain {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SharedDomain {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.name().cmp(other.name())
+    }
+}
+
+impl Hash for SharedDomain {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name().hash(state);
+    }
+}
+
+impl<'a> PartialEq<DomainRef<'a>> for SharedDomain {
+    fn eq(&self, other: &DomainRef<'a>) -> bool {
+        self.to_ref() == *other
+    }
+}
+
+impl<'a> PartialEq<SharedDomain> for DomainRef<'a> {
+    fn eq(&self, other: &SharedDomain) -> bool {
+        *self == other.to_ref()
+    }
+}
+
+impl PartialEq<Domain> for SharedDomain {
+    fn eq(&self, other: &Domain) -> bool {
+        self.to_ref() == other.to_ref()
+    }
+}
+
+impl PartialEq<SharedDomain> for Domain {
+    fn eq(&self, other: &SharedDomain) -> bool {
+        self.to_ref() == other.to_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::hash;
+    use crate::{Domain, DomainRef, SharedDomain};
+
+    #[test]
+    fn clone() {
+        let domain: SharedDomain = SharedDomain::new(DomainRef::LOCALHOST);
+        let clone: SharedDomain = domain.clone();
+        assert!(clone.ptr_eq(&domain));
+        assert_eq!(domain.share_count(), 2);
+
+        let copy: SharedDomain = SharedDomain::new(DomainRef::LOCALHOST);
+        assert!(!copy.ptr_eq(&domain));
+        assert_eq!(copy, domain);
+    }
+
+    #[test]
+    fn conversions() {
+        let domain: SharedDomain = Domain::example().into();
+        assert_eq!(domain.name(), "example.com");
+        assert_eq!(domain, Domain::example());
+        assert_eq!(Domain::example(), domain);
+        assert_eq!(domain, DomainRef::EXAMPLE);
+        assert_eq!(DomainRef::EXAMPLE, domain);
+        assert_eq!(Domain::from(&domain), Domain::example());
+        assert_eq!(domain.to_ref(), DomainRef::EXAMPLE);
+    }
+
+    /// Equality, ordering, & hashing match [Domain], so shared & unshared domains sort & hash alike.
+    #[test]
+    fn comparison() {
+        let names: &[&str] = &["a", "a.b", "a-b", "b", "example.com"];
+
+        for a in names {
+            for b in names {
+                let (domain_a, domain_b): (Domain, Domain) = (a.parse().unwrap(), b.parse().unwrap());
+                let (shared_a, shared_b): (SharedDomain, SharedDomain) = ((&domain_a).into(), (&domain_b).into());
+                assert_eq!(shared_a.cmp(&shared_b), domain_a.cmp(&domain_b), "a={} b={}", a, b);
+                assert_eq!(shared_a == shared_b, domain_a == domain_b, "a={} b={}", a, b);
+            }
+            let domain: Domain = a.parse().unwrap();
+            assert_eq!(hash(SharedDomain::from(&domain)), hash(&domain), "a={}", a);
+        }
+    }
+}
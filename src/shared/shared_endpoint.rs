@@ -0,0 +1,119 @@
+use crate::{Endpoint, EndpointRef, SharedDomain};
+
+/// An [Endpoint] whose domain name is shared: a [SharedDomain] & a port.
+#[must_use]
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct SharedEndpoint {
+    domain: SharedDomain,
+    port: u16,
+}
+
+impl SharedEndpoint {
+    //! Construction
+
+    /// Creates a new [SharedEndpoint].
+    pub const fn new(domain: SharedDomain, port: u16) -> Self {
+        Self { domain, port }
+    }
+}
+
+impl<D: Into<SharedDomain>> From<(D, u16)> for SharedEndpoint {
+    fn from(tuple: (D, u16)) -> Self {
+        Self::new(tuple.0.into(), tuple.1)
+    }
+}
+
+impl<'a> From<EndpointRef<'a>> for SharedEndpoint {
+    fn from(endpoint: EndpointRef<'a>) -> Self {
+        Self::new(endpoint.domain().into(), endpoint.port())
+    }
+}
+
+impl From<&Endpoint> for SharedEndpoint {
+    fn from(endpoint: &Endpoint) -> Self {
+        endpoint.to_ref().into()
+    }
+}
+
+impl From<Endpoint> for SharedEndpoint {
+    fn from(endpoint: Endpoint) -> Self {
+        endpoint.to_ref().into()
+    }
+}
+
+impl From<&SharedEndpoint> for Endpoint {
+    fn from(endpoint: &SharedEndpoint) -> Self {
+        endpoint.to_endpoint()
+    }
+}
+
+impl<'a> PartialEq<EndpointRef<'a>> for SharedEndpoint {
+    fn eq(&self, other: &EndpointRef<'a>) -> bool {
+        self.to_ref() == *other
+    }
+}
+
+impl<'a> PartialEq<SharedEndpoint> for EndpointRef<'a> {
+    fn eq(&self, other: &SharedEndpoint) -> bool {
+        *self == other.to_ref()
+    }
+}
+
+impl PartialEq<Endpoint> for SharedEndpoint {
+    fn eq(&self, other: &Endpoint) -> bool {
+        self.to_ref() == other.to_ref()
+    }
+}
+
+impl PartialEq<SharedEndpoint> for Endpoint {
+    fn eq(&self, other: &SharedEndpoint) -> bool {
+        self.to_ref() == other.to_ref()
+    }
+}
+
+impl SharedEndpoint {
+    //! Properties
+
+    /// Gets the domain.
+    pub const fn domain(&self) -> &SharedDomain {
+        &self.domain
+    }
+
+    /// Gets the port.
+    #[must_use]
+    pub const fn port(&self) -> u16 {
+        self.port
+    }
+}
+
+impl SharedEndpoint {
+    //! Conversions
+
+    /// Converts the endpoint to an endpoint reference.
+    pub fn to_ref(&self) -> EndpointRef<'_> {
+        EndpointRef::new(self.domain.to_ref(), self.port)
+    }
+
+    /// Converts the endpoint to an unshared endpoint.
+    pub fn to_endpoint(&self) -> Endpoint {
+        self.to_ref().to_endpoint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Domain, DomainRef, Endpoint, EndpointRef, SharedEndpoint};
+
+    #[test]
+    fn conversions() {
+        let endpoint: SharedEndpoint = (Domain::localhost(), 80).into();
+        assert_eq!(endpoint.domain().name(), "localhost");
+        assert_eq!(endpoint.port(), 80);
+        assert_eq!(endpoint, EndpointRef::new(DomainRef::LOCALHOST, 80));
+        assert_eq!(endpoint, Endpoint::new(Domain::localhost(), 80));
+
+        let clone: SharedEndpoint = endpoint.clone();
+        assert!(clone.domain().ptr_eq(endpoint.domain()));
+        assert_eq!(Endpoint::from(&clone), Endpoint::new(Domain::localhost(), 80));
+    }
+}
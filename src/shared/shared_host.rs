@@ -0,0 +1,133 @@
+use crate::{DomainRef, Host, HostRef, IPAddress, SharedDomain};
+
+/// A [Host] whose domain name is shared: either a [SharedDomain] or an [IPAddress].
+#[must_use]
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum SharedHost {
+    /// A [SharedDomain].
+    Name(SharedDomain),
+
+    /// An [IPAddress].
+    Address(IPAddress),
+}
+
+impl<'a> From<HostRef<'a>> for SharedHost {
+    fn from(host: HostRef<'a>) -> Self {
+        match host {
+            HostRef::Name(domain) => Self::Name(domain.into()),
+            HostRef::Address(ip) => Self::Address(ip),
+        }
+    }
+}
+
+impl From<&Host> for SharedHost {
+    fn from(host: &Host) -> Self {
+        host.to_ref().into()
+    }
+}
+
+impl From<Host> for SharedHost {
+    fn from(host: Host) -> Self {
+        host.to_ref().into()
+    }
+}
+
+impl From<&SharedHost> for Host {
+    fn from(host: &SharedHost) -> Self {
+        host.to_host()
+    }
+}
+
+impl From<SharedDomain> for SharedHost {
+    fn from(domain: SharedDomain) -> Self {
+        Self::Name(domain)
+    }
+}
+
+impl<'a> From<DomainRef<'a>> for SharedHost {
+    fn from(domain: DomainRef<'a>) -> Self {
+        Self::Name(domain.into())
+    }
+}
+
+impl<A: Into<IPAddress>> From<A> for SharedHost {
+    fn from(ip: A) -> Self {
+        Self::Address(ip.into())
+    }
+}
+
+impl<'a> PartialEq<HostRef<'a>> for SharedHost {
+    fn eq(&self, other: &HostRef<'a>) -> bool {
+        self.to_ref() == *other
+    }
+}
+
+impl<'a> PartialEq<SharedHost> for HostRef<'a> {
+    fn eq(&self, other: &SharedHost) -> bool {
+        *self == other.to_ref()
+    }
+}
+
+impl PartialEq<Host> for SharedHost {
+    fn eq(&self, other: &Host) -> bool {
+        self.to_ref() == other.to_ref()
+    }
+}
+
+impl PartialEq<SharedHost> for Host {
+    fn eq(&self, other: &SharedHost) -> bool {
+        self.to_ref() == other.to_ref()
+    }
+}
+
+impl SharedHost {
+    //! Matching
+
+    /// Checks if the host is a domain.
+    #[must_use]
+    pub const fn is_domain(&self) -> bool {
+        matches!(self, Self::Name(_))
+    }
+
+    /// Checks if the host is an IP address.
+    #[must_use]
+    pub const fn is_ip(&self) -> bool {
+        matches!(self, Self::Address(_))
+    }
+}
+
+impl SharedHost {
+    //! Conversions
+
+    /// Converts the host to a host reference.
+    pub fn to_ref(&self) -> HostRef<'_> {
+        match self {
+            Self::Name(domain) => HostRef::Name(domain.to_ref()),
+            Self::Address(ip) => HostRef::Address(*ip),
+        }
+    }
+
+    /// Converts the host to an unshared host.
+    pub fn to_host(&self) -> Host {
+        self.to_ref().to_host()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Domain, DomainRef, Host, HostRef, IPv4Address, SharedDomain, SharedHost};
+
+    #[test]
+    fn conversions() {
+        let host: SharedHost = Host::from(Domain::localhost()).into();
+        assert_eq!(host, SharedHost::Name(SharedDomain::new(DomainRef::LOCALHOST)));
+        assert_eq!(host, HostRef::Name(DomainRef::LOCALHOST));
+        assert_eq!(host, Host::from(Domain::localhost()));
+        assert_eq!(Host::from(&host), Host::from(Domain::localhost()));
+        assert!(host.is_domain());
+
+        let host: SharedHost = IPv4Address::LOCALHOST.into();
+        assert_eq!(host.to_ref(), IPv4Address::LOCALHOST.to_host_ref());
+        assert!(host.is_ip());
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{Authority, AuthorityRef, IPAddress, SocketAddress, SocketAddressV4, SocketAddressV6};
+#[cfg(feature = "alloc")]
+use crate::Authority;
+use crate::{AuthorityRef, IPAddress, SocketAddress, SocketAddressV4, SocketAddressV6};
 
 impl SocketAddress {
     //! Conversions
@@ -24,6 +26,7 @@ impl SocketAddress {
     }
 
     /// Converts the socket address to an authority.
+    #[cfg(feature = "alloc")]
     pub const fn to_authority(self) -> Authority {
         Authority::new(self.ip().to_host(), self.port())
     }
@@ -48,10 +51,10 @@ impl From<SocketAddressV6> for SocketAddress {
 
 #[cfg(test)]
 mod tests {
-    use crate::{
-        Authority, AuthorityRef, Host, HostRef, IPAddress, IPv4Address, IPv6Address, SocketAddress, SocketAddressV4,
-        SocketAddressV6,
-    };
+    use crate::{IPAddress, IPv4Address, IPv6Address, SocketAddress, SocketAddressV4, SocketAddressV6};
+
+    #[cfg(feature = "alloc")]
+    use crate::{Authority, AuthorityRef, Host, HostRef};
 
     #[test]
     fn socket_to_v4() {
@@ -79,6 +82,7 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn socket_to_authority() {
         let socket: SocketAddress = SocketAddress::new(IPAddress::V4(IPv4Address::LOCALHOST), 80);
@@ -1,5 +1,5 @@
 use crate::{IPAddress, SocketAddress, SocketAddressV4, SocketAddressV6};
-use std::net::{SocketAddr, SocketAddrV4, SocketAddrV6};
+use core::net::{SocketAddr, SocketAddrV4, SocketAddrV6};
 
 impl SocketAddress {
     //! Standard Library Conversions
@@ -49,7 +49,7 @@ impl From<SocketAddress> for SocketAddr {
 #[cfg(test)]
 mod tests {
     use crate::{IPv4Address, IPv6Address, SocketAddress};
-    use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+    use core::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
 
     #[test]
     fn socket_to_std() {
@@ -1,5 +1,5 @@
 use crate::SocketAddressV4;
-use std::net::SocketAddrV4;
+use core::net::SocketAddrV4;
 
 impl SocketAddressV4 {
     //! Standard Library Conversions
@@ -26,7 +26,7 @@ impl From<SocketAddressV4> for SocketAddrV4 {
 #[cfg(test)]
 mod tests {
     use crate::{IPv4Address, SocketAddressV4};
-    use std::net::{Ipv4Addr, SocketAddrV4};
+    use core::net::{Ipv4Addr, SocketAddrV4};
 
     #[test]
     fn v4_to_std() {
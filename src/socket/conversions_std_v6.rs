@@ -1,5 +1,5 @@
 use crate::SocketAddressV6;
-use std::net::SocketAddrV6;
+use core::net::SocketAddrV6;
 
 impl SocketAddressV6 {
     //! Standard Library Conversions
@@ -33,7 +33,7 @@ impl From<SocketAddressV6> for SocketAddrV6 {
 #[cfg(test)]
 mod tests {
     use crate::{IPv6Address, SocketAddressV6};
-    use std::net::{Ipv6Addr, SocketAddrV6};
+    use core::net::{Ipv6Addr, SocketAddrV6};
 
     #[test]
     fn v6_to_std() {
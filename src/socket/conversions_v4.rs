@@ -1,4 +1,6 @@
-use crate::{Authority, AuthorityRef, SocketAddress, SocketAddressV4};
+#[cfg(feature = "alloc")]
+use crate::Authority;
+use crate::{AuthorityRef, SocketAddress, SocketAddressV4};
 
 impl SocketAddressV4 {
     //! Conversions
@@ -9,6 +11,7 @@ impl SocketAddressV4 {
     }
 
     /// Converts the IPv4 socket address to an authority.
+    #[cfg(feature = "alloc")]
     pub const fn to_authority(self) -> Authority {
         Authority::new(self.ip().to_host(), self.port())
     }
@@ -21,7 +24,10 @@ impl SocketAddressV4 {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Authority, AuthorityRef, Host, HostRef, IPAddress, IPv4Address, SocketAddress, SocketAddressV4};
+    use crate::{IPAddress, IPv4Address, SocketAddress, SocketAddressV4};
+
+    #[cfg(feature = "alloc")]
+    use crate::{Authority, AuthorityRef, Host, HostRef};
 
     #[test]
     fn v4_to_socket() {
@@ -31,6 +37,7 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn v4_to_authority() {
         let socket: SocketAddressV4 = IPv4Address::LOCALHOST.to_socket(80);
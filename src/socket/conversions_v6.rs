@@ -1,4 +1,6 @@
-use crate::{Authority, AuthorityRef, SocketAddress, SocketAddressV6};
+#[cfg(feature = "alloc")]
+use crate::Authority;
+use crate::{AuthorityRef, SocketAddress, SocketAddressV6};
 
 impl SocketAddressV6 {
     //! Conversions
@@ -9,6 +11,7 @@ impl SocketAddressV6 {
     }
 
     /// Converts the IPv6 socket address to an authority.
+    #[cfg(feature = "alloc")]
     pub const fn to_authority(self) -> Authority {
         Authority::new(self.ip().to_host(), self.port())
     }
@@ -21,7 +24,10 @@ impl SocketAddressV6 {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Authority, AuthorityRef, Host, HostRef, IPAddress, IPv6Address, SocketAddress, SocketAddressV6};
+    use crate::{IPAddress, IPv6Address, SocketAddress, SocketAddressV6};
+
+    #[cfg(feature = "alloc")]
+    use crate::{Authority, AuthorityRef, Host, HostRef};
 
     #[test]
     fn v6_to_socket() {
@@ -31,6 +37,7 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn v6_to_authority() {
         let socket: SocketAddressV6 = IPv6Address::LOCALHOST.to_socket(80);
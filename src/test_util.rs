@@ -0,0 +1,71 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+
+/// Hashes the `value` with a deterministic FNV-1a hasher, which needs neither `std` nor a random seed.
+pub(crate) fn hash(value: impl Hash) -> u64 {
+    let mut hasher: FnvHasher = FnvHasher(0xcbf2_9ce4_8422_2325);
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The 64-bit FNV-1a hasher.
+struct FnvHasher(u64);
+
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+/// A deterministic xorshift generator, so differential failures reproduce.
+pub(crate) struct Generator {
+    state: u64,
+}
+
+impl Generator {
+    /// Creates a new generator from the nonzero `seed`.
+    pub(crate) fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Gets the next random number.
+    pub(crate) fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// Gets a random number in `0..max`.
+    pub(crate) fn below(&mut self, max: usize) -> usize {
+        (self.next() % max as u64) as usize
+    }
+
+    /// Joins 0 to 11 random `pieces`.
+    pub(crate) fn pieces(&mut self, pieces: &[&str]) -> String {
+        let count: usize = self.below(12);
+        (0..count).map(|_| pieces[self.below(pieces.len())]).collect()
+    }
+
+    /// Applies 1 to 3 random edits to the `text`: deleting, inserting, or replacing a byte of the `alphabet`.
+    pub(crate) fn mutate(&mut self, text: String, alphabet: &[u8]) -> String {
+        let mut text: Vec<u8> = text.into_bytes();
+        for _ in 0..1 + self.below(3) {
+            let byte: u8 = alphabet[self.below(alphabet.len())];
+            let index: usize = self.below(text.len() + 1);
+            match self.below(3) {
+                0 if index < text.len() => drop(text.remove(index)),
+                1 if index < text.len() => text[index] = byte,
+                _ => text.insert(index, byte),
+            }
+        }
+        String::from_utf8(text).unwrap()
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{Authority, AuthorityRef, HostRef, SocketAddress};
+use ::tokio::net::TcpStream;
+use std::io;
+
+impl Authority {
+    //! Tokio
+
+    /// Resolves the authority to its socket addresses with `tokio::net::lookup_host`.
+    ///
+    /// An IP address host resolves to its single socket address without a lookup.
+    pub async fn lookup_host(&self) -> io::Result<std::vec::IntoIter<SocketAddress>> {
+        self.to_ref().lookup_host().await
+    }
+
+    /// Opens a TCP connection to the authority, trying each resolved socket address in turn.
+    ///
+    /// An IP address host is connected to without a lookup.
+    pub async fn connect(&self) -> io::Result<TcpStream> {
+        self.to_ref().connect().await
+    }
+}
+
+impl<'a> AuthorityRef<'a> {
+    //! Tokio
+
+    /// Resolves the authority to its socket addresses with `tokio::net::lookup_host`.
+    ///
+    /// An IP address host resolves to its single socket address without a lookup.
+    pub async fn lookup_host(self) -> io::Result<std::vec::IntoIter<SocketAddress>> {
+        match self.host() {
+            HostRef::Name(domain) => domain.to_endpoint_ref(self.port()).lookup_host().await,
+            HostRef::Address(ip) => Ok(vec![ip.to_socket(self.port())].into_iter()),
+        }
+    }
+
+    /// Opens a TCP connection to the authority, trying each resolved socket address in turn.
+    ///
+    /// An IP address host is connected to without a lookup.
+    pub async fn connect(self) -> io::Result<TcpStream> {
+        match self.host() {
+            HostRef::Name(domain) => domain.to_endpoint_ref(self.port()).connect().await,
+            HostRef::Address(ip) => ip.to_socket(self.port()).connect().await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Authority, IPv4Address, IPv6Address, SocketAddress};
+    use ::tokio::net::{TcpListener, TcpStream};
+
+    #[::tokio::test]
+    async fn lookup_host() {
+        let test_cases: &[Authority] = &[
+            IPv4Address::LOCALHOST.to_host().to_authority(80),
+            IPv6Address::LOCALHOST.to_host().to_authority(443),
+        ];
+
+        for authority in test_cases {
+            let result: Vec<SocketAddress> = authority.lookup_host().await.unwrap().collect();
+            assert_eq!(result, [authority.to_socket().unwrap()], "authority={}", authority);
+        }
+    }
+
+    #[::tokio::test]
+    async fn connect() {
+        let listener: TcpListener = IPv4Address::LOCALHOST.to_ip().to_socket(0).bind().await.unwrap();
+        let socket: SocketAddress = listener.local_addr().unwrap().into();
+
+        let stream: TcpStream = socket.to_authority().connect().await.unwrap();
+        assert_eq!(SocketAddress::from(stream.peer_addr().unwrap()), socket);
+    }
+}
@@ -0,0 +1,35 @@
+use crate::{Endpoint, EndpointRef, SocketAddress};
+use ::tokio::net::TcpStream;
+use std::io;
+
+impl Endpoint {
+    //! Tokio
+
+    /// Resolves the endpoint to its socket addresses with `tokio::net::lookup_host`.
+    pub async fn lookup_host(&self) -> io::Result<std::vec::IntoIter<SocketAddress>> {
+        self.to_ref().lookup_host().await
+    }
+
+    /// Opens a TCP connection to the endpoint, trying each resolved socket address in turn.
+    pub async fn connect(&self) -> io::Result<TcpStream> {
+        self.to_ref().connect().await
+    }
+}
+
+impl<'a> EndpointRef<'a> {
+    //! Tokio
+
+    /// Resolves the endpoint to its socket addresses with `tokio::net::lookup_host`.
+    pub async fn lookup_host(self) -> io::Result<std::vec::IntoIter<SocketAddress>> {
+        let sockets: Vec<SocketAddress> = ::tokio::net::lookup_host((self.domain().name(), self.port()))
+            .await?
+            .map(SocketAddress::from)
+            .collect();
+        Ok(sockets.into_iter())
+    }
+
+    /// Opens a TCP connection to the endpoint, trying each resolved socket address in turn.
+    pub async fn connect(self) -> io::Result<TcpStream> {
+        TcpStream::connect((self.domain().name(), self.port())).await
+    }
+}
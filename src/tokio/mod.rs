@@ -0,0 +1,8 @@
+//! Tokio support for the address types.
+//!
+//! The helpers pass the parts of the addresses to `tokio` directly, so nothing is formatted & parsed again, & IP
+//! address hosts never reach the resolver.
+
+mod authority;
+mod endpoint;
+mod socket;
@@ -0,0 +1,33 @@
+use crate::SocketAddress;
+use ::tokio::net::{TcpListener, TcpStream};
+use std::io;
+
+impl SocketAddress {
+    //! Tokio
+
+    /// Opens a TCP connection to the socket address.
+    pub async fn connect(self) -> io::Result<TcpStream> {
+        TcpStream::connect(self.to_std()).await
+    }
+
+    /// Creates a TCP listener bound to the socket address. Port 0 binds an unused port.
+    pub async fn bind(self) -> io::Result<TcpListener> {
+        TcpListener::bind(self.to_std()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IPv4Address, SocketAddress};
+    use ::tokio::net::{TcpListener, TcpStream};
+
+    #[::tokio::test]
+    async fn bind_connect() {
+        let listener: TcpListener = IPv4Address::LOCALHOST.to_ip().to_socket(0).bind().await.unwrap();
+        let socket: SocketAddress = listener.local_addr().unwrap().into();
+        assert_ne!(socket.port(), 0);
+
+        let stream: TcpStream = socket.connect().await.unwrap();
+        assert_eq!(SocketAddress::from(stream.peer_addr().unwrap()), socket);
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{Authority, Domain, Host, HostPort, IPAddress, IPvFuture, RegName, UriAuthority, UriHost, percent_decode};
+
+impl UriHost {
+    //! Conversions
+
+    /// Converts the host to an optional [Host].
+    ///
+    /// A registered name converts when it percent-decodes to a valid domain name; IPvFuture literals never convert.
+    #[must_use]
+    pub fn to_host(&self) -> Option<Host> {
+        match self {
+            Self::Name(name) => Domain::parse_text(percent_decode(name.name().as_bytes()).as_slice())
+                .ok()
+                .map(Domain::to_host),
+            Self::Address(ip) => Some(ip.to_host()),
+            Self::Future(_) => None,
+        }
+    }
+
+    /// Converts the host to an optional IP address.
+    #[must_use]
+    pub const fn to_ip(&self) -> Option<IPAddress> {
+        if let Self::Address(ip) = self { Some(*ip) } else { None }
+    }
+}
+
+impl From<Host> for UriHost {
+    fn from(host: Host) -> Self {
+        match host {
+            Host::Name(domain) => Self::Name(domain.into()),
+            Host::Address(ip) => Self::Address(ip),
+        }
+    }
+}
+
+impl From<IPAddress> for UriHost {
+    fn from(ip: IPAddress) -> Self {
+        Self::Address(ip)
+    }
+}
+
+impl From<RegName> for UriHost {
+    fn from(name: RegName) -> Self {
+        Self::Name(name)
+    }
+}
+
+impl From<IPvFuture> for UriHost {
+    fn from(literal: IPvFuture) -> Self {
+        Self::Future(literal)
+    }
+}
+
+impl From<Domain> for RegName {
+    /// Every domain name is a valid, normalized registered name.
+    fn from(domain: Domain) -> Self {
+        unsafe { Self::new_unchecked(domain.into()) }
+    }
+}
+
+impl UriAuthority {
+    //! Conversions
+
+    /// Converts the URI authority to an optional [Authority].
+    ///
+    /// Returns `None` if the port is absent or the host does not convert with [`UriHost::to_host`]. The user-info
+    /// is dropped.
+    #[must_use]
+    pub fn to_authority(&self) -> Option<Authority> {
+        let port: u16 = self.port()?;
+        Some(self.host().to_host()?.to_authority(port))
+    }
+
+    /// Converts the URI authority to an optional [HostPort].
+    ///
+    /// Returns `None` if the host does not convert with [`UriHost::to_host`]. The user-info is dropped.
+    #[must_use]
+    pub fn to_host_port(&self) -> Option<HostPort> {
+        Some(HostPort::new(self.host().to_host()?, self.port()))
+    }
+}
+
+impl From<Authority> for UriAuthority {
+    fn from(authority: Authority) -> Self {
+        let (host, port): (Host, u16) = authority.into();
+        Self::new(host.into(), Some(port))
+    }
+}
+
+impl From<HostPort> for UriAuthority {
+    fn from(host_port: HostPort) -> Self {
+        let (host, port): (Host, Option<u16>) = host_port.into();
+        Self::new(host.into(), port)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Authority, Domain, HostPort, IPv4Address, IPvFuture, RegName, UriAuthority, UriHost};
+    use alloc::string::String;
+    use alloc::string::ToString;
+    use core::str::FromStr;
+
+    #[test]
+    fn uri_host_to_host() {
+        let test_cases: &[(UriHost, Option<&str>)] = &[
+            (
+                UriHost::Name(RegName::try_from("example.com").unwrap()),
+                Some("example.com"),
+            ),
+            (
+                UriHost::Name(RegName::try_from("ex%61mple.com").unwrap()),
+                Some("example.com"),
+            ),
+            (UriHost::Name(RegName::try_from("my_host").unwrap()), None),
+            (UriHost::Name(RegName::try_from("").unwrap()), None),
+            (UriHost::Address(IPv4Address::LOCALHOST.to_ip()), Some("127.0.0.1")),
+            (UriHost::Future(IPvFuture::try_from("v1.x").unwrap()), None),
+        ];
+
+        for (host, expected) in test_cases {
+            let result: Option<String> = host.to_host().map(|host| host.to_string());
+            assert_eq!(result.as_deref(), *expected, "host={:?}", host);
+        }
+    }
+
+    #[test]
+    fn uri_authority_to_authority() {
+        let test_cases: &[(&str, Option<&str>)] = &[
+            ("user:pass@example.com:443", Some("example.com:443")),
+            ("[::1]:5432", Some("[::1]:5432")),
+            ("git@github.com", None),
+            ("[v1.x]:80", None),
+            ("my_host:80", None),
+        ];
+
+        for (input, expected) in test_cases {
+            let authority: UriAuthority = UriAuthority::from_str(input).unwrap();
+            let expected: Option<Authority> = expected.map(|expected| Authority::from_str(expected).unwrap());
+            assert_eq!(authority.to_authority(), expected, "input={}", input);
+        }
+    }
+
+    #[test]
+    fn uri_authority_to_host_port() {
+        let authority: UriAuthority = UriAuthority::from_str("git@github.com").unwrap();
+        let expected: HostPort = HostPort::from_str("github.com").unwrap();
+        assert_eq!(authority.to_host_port(), Some(expected));
+    }
+
+    #[test]
+    fn from_authority() {
+        let authority: UriAuthority = Domain::example().to_host().to_authority(443).into();
+        assert_eq!(authority, UriAuthority::from_str("example.com:443").unwrap());
+
+        let authority: UriAuthority = HostPort::new(Domain::example().to_host(), None).into();
+        assert_eq!(authority, UriAuthority::from_str("example.com").unwrap());
+    }
+}
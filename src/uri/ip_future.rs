@@ -0,0 +1,49 @@
+use alloc::string::String;
+
+/// An IPvFuture literal of a [UriHost](crate::UriHost), without its brackets: `v1.fe80::a+en1`.
+#[must_use]
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct IPvFuture {
+    literal: String,
+}
+
+impl IPvFuture {
+    //! Construction
+
+    /// Creates a new [IPvFuture].
+    ///
+    /// # Safety
+    /// The `literal` must be a valid IPvFuture literal without its brackets.
+    pub(crate) unsafe fn new_unchecked(literal: String) -> Self {
+        Self { literal }
+    }
+}
+
+impl From<IPvFuture> for String {
+    fn from(literal: IPvFuture) -> Self {
+        literal.literal
+    }
+}
+
+impl IPvFuture {
+    //! Properties
+
+    /// Gets the literal, without its brackets.
+    #[must_use]
+    pub const fn literal(&self) -> &str {
+        self.literal.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IPvFuture;
+    use alloc::string::String;
+
+    #[test]
+    fn properties() {
+        let literal: IPvFuture = IPvFuture::try_from("v1.fe80::a+en1").unwrap();
+        assert_eq!(literal.literal(), "v1.fe80::a+en1");
+        assert_eq!(String::from(literal), "v1.fe80::a+en1");
+    }
+}
@@ -0,0 +1,11 @@
+pub use ip_future::*;
+pub use reg_name::*;
+pub use uri_authority::*;
+pub use uri_host::*;
+
+mod ip_future;
+mod reg_name;
+mod uri_authority;
+mod uri_host;
+
+mod conversions;
@@ -0,0 +1,51 @@
+use alloc::string::String;
+
+/// A registered name of a [UriHost](crate::UriHost), percent-encoded & normalized: `example.com` or `my_host`.
+///
+/// Letters are lowercase & percent-encoded triplets use uppercase hex digits. The name may be empty.
+#[must_use]
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct RegName {
+    name: String,
+}
+
+impl RegName {
+    //! Construction
+
+    /// Creates a new [RegName].
+    ///
+    /// # Safety
+    /// The `name` must be a valid, normalized registered name.
+    pub(crate) unsafe fn new_unchecked(name: String) -> Self {
+        Self { name }
+    }
+}
+
+impl From<RegName> for String {
+    fn from(name: RegName) -> Self {
+        name.name
+    }
+}
+
+impl RegName {
+    //! Properties
+
+    /// Gets the percent-encoded name.
+    #[must_use]
+    pub const fn name(&self) -> &str {
+        self.name.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RegName;
+    use alloc::string::String;
+
+    #[test]
+    fn properties() {
+        let name: RegName = RegName::try_from("My%c3%a4_Host").unwrap();
+        assert_eq!(name.name(), "my%C3%A4_host");
+        assert_eq!(String::from(name), "my%C3%A4_host");
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{UriHost, percent_decode};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A URI authority: optional user-info, a host, & an optional port. (`user:pass@[::1]:5432` or `git@github.com`)
+///
+/// This is the full authority of [RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-3.2). Use
+/// [`Self::to_authority`] to convert to an [Authority](crate::Authority) when the host is an IP address or a domain
+/// name & the port is present.
+///
+/// The user-info is stored percent-encoded & decoded on access. It is never shown by `Debug`, which redacts it, so
+/// credentials do not leak into logs. `Display` shows it, to re-emit valid URI syntax.
+#[must_use]
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct UriAuthority {
+    userinfo: Option<String>,
+    host: UriHost,
+    port: Option<u16>,
+}
+
+impl UriAuthority {
+    //! Construction
+
+    /// Creates a new [UriAuthority].
+    ///
+    /// # Safety
+    /// The `userinfo` must be valid percent-encoded user-info that decodes to UTF-8.
+    pub unsafe fn new_unchecked(userinfo: Option<String>, host: UriHost, port: Option<u16>) -> Self {
+        debug_assert!(
+            userinfo
+                .as_ref()
+                .is_none_or(|userinfo| Self::is_valid_userinfo(userinfo.as_bytes()))
+        );
+
+        Self { userinfo, host, port }
+    }
+
+    /// Creates a new [UriAuthority] without user-info.
+    pub const fn new(host: UriHost, port: Option<u16>) -> Self {
+        Self {
+            userinfo: None,
+            host,
+            port,
+        }
+    }
+}
+
+impl UriAuthority {
+    //! Properties
+
+    /// Gets the percent-encoded user-info, as it appears in the URI.
+    #[must_use]
+    pub fn encoded_userinfo(&self) -> Option<&str> {
+        self.userinfo.as_deref()
+    }
+
+    /// Gets the percent-decoded user name: the user-info before the first `:`.
+    #[must_use]
+    pub fn username(&self) -> Option<String> {
+        let userinfo: &str = self.userinfo.as_deref()?;
+        let username: &str = userinfo.split_once(':').map_or(userinfo, |(username, _)| username);
+        Some(Self::decode(username))
+    }
+
+    /// Gets the percent-decoded password: the user-info after the first `:`.
+    ///
+    /// Returns `None` if the user-info has no `:`.
+    #[must_use]
+    pub fn password(&self) -> Option<String> {
+        let (_, password): (&str, &str) = self.userinfo.as_deref()?.split_once(':')?;
+        Some(Self::decode(password))
+    }
+
+    /// Gets the host.
+    pub const fn host(&self) -> &UriHost {
+        &self.host
+    }
+
+    /// Gets the optional port.
+    #[must_use]
+    pub const fn port(&self) -> Option<u16> {
+        self.port
+    }
+
+    /// Decodes the validated percent-encoded user-info `part`.
+    fn decode(part: &str) -> String {
+        let decoded: Vec<u8> = percent_decode(part.as_bytes());
+
+        debug_assert!(core::str::from_utf8(decoded.as_slice()).is_ok());
+
+        unsafe { String::from_utf8_unchecked(decoded) }
+    }
+}
+
+impl UriAuthority {
+    //! Matching
+
+    /// Checks if the user-info is present.
+    #[must_use]
+    pub const fn has_userinfo(&self) -> bool {
+        self.userinfo.is_some()
+    }
+
+    /// Checks if the port is present.
+    #[must_use]
+    pub const fn has_port(&self) -> bool {
+        self.port.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IPv6Address, RegName, UriAuthority, UriHost};
+    use alloc::string::ToString;
+
+    #[test]
+    fn construction() {
+        let authority: UriAuthority =
+            UriAuthority::new(UriHost::Name(RegName::try_from("example.com").unwrap()), Some(80));
+        assert_eq!(authority.userinfo, None);
+        assert_eq!(authority.host, UriHost::Name(RegName::try_from("example.com").unwrap()));
+        assert_eq!(authority.port, Some(80));
+    }
+
+    #[test]
+    fn userinfo() {
+        let test_cases: &[(Option<&str>, Option<&str>, Option<&str>)] = &[
+            (None, None, None),
+            (Some(""), Some(""), None),
+            (Some("git"), Some("git"), None),
+            (Some("user:pass"), Some("user"), Some("pass")),
+            (Some("user:"), Some("user"), Some("")),
+            (Some("us%3Aer:p%40ss:word"), Some("us:er"), Some("p@ss:word")),
+            (Some("%C3%BC"), Some("ü"), None),
+        ];
+
+        for (userinfo, username, password) in test_cases {
+            let authority: UriAuthority = unsafe {
+                UriAuthority::new_unchecked(
+                    userinfo.map(str::to_string),
+                    UriHost::Address(IPv6Address::LOCALHOST.to_ip()),
+                    None,
+                )
+            };
+            assert_eq!(authority.encoded_userinfo(), *userinfo, "userinfo={:?}", userinfo);
+            assert_eq!(authority.username().as_deref(), *username, "userinfo={:?}", userinfo);
+            assert_eq!(authority.password().as_deref(), *password, "userinfo={:?}", userinfo);
+            assert_eq!(authority.has_userinfo(), userinfo.is_some(), "userinfo={:?}", userinfo);
+        }
+    }
+
+    #[test]
+    fn properties() {
+        let authority: UriAuthority = UriAuthority::new(UriHost::Address(IPv6Address::LOCALHOST.to_ip()), Some(5432));
+        assert_eq!(authority.host(), &UriHost::Address(IPv6Address::LOCALHOST.to_ip()));
+        assert_eq!(authority.port(), Some(5432));
+        assert!(authority.has_port());
+
+        let authority: UriAuthority = UriAuthority::new(UriHost::Address(IPv6Address::LOCALHOST.to_ip()), None);
+        assert_eq!(authority.port(), None);
+        assert!(!authority.has_port());
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{IPAddress, IPvFuture, RegName};
+
+/// The host of a [UriAuthority](crate::UriAuthority), per [RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-3.2.2).
+///
+/// Unlike [Host](crate::Host), a URI host may be a future IP-literal or a registered name that is not a domain name.
+#[must_use]
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum UriHost {
+    /// A registered name, percent-encoded & normalized: `example.com` or `my_host`.
+    Name(RegName),
+
+    /// An IPv4 address or a bracketed IPv6 address.
+    Address(IPAddress),
+
+    /// An IPvFuture literal, without its brackets: `v1.fe80::a+en1`.
+    Future(IPvFuture),
+}
+
+impl UriHost {
+    //! Properties
+
+    /// Gets the percent-encoded registered name.
+    ///
+    /// Returns `None` if the host is not a registered name.
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        if let Self::Name(name) = self {
+            Some(name.name())
+        } else {
+            None
+        }
+    }
+}
+
+impl UriHost {
+    //! Matching
+
+    /// Checks if the host is a registered name.
+    #[must_use]
+    pub const fn is_name(&self) -> bool {
+        matches!(self, Self::Name(_))
+    }
+
+    /// Checks if the host is an IP address.
+    #[must_use]
+    pub const fn is_ip(&self) -> bool {
+        matches!(self, Self::Address(_))
+    }
+
+    /// Checks if the host is an IPvFuture literal.
+    #[must_use]
+    pub const fn is_future(&self) -> bool {
+        matches!(self, Self::Future(_))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IPv4Address, IPvFuture, RegName, UriHost};
+
+    #[test]
+    fn properties() {
+        assert_eq!(
+            UriHost::Name(RegName::try_from("my_host").unwrap()).name(),
+            Some("my_host")
+        );
+        assert_eq!(UriHost::Address(IPv4Address::LOCALHOST.to_ip()).name(), None);
+        assert_eq!(UriHost::Future(IPvFuture::try_from("v1.x").unwrap()).name(), None);
+    }
+
+    #[test]
+    fn matching() {
+        let host: UriHost = UriHost::Name(RegName::try_from("example.com").unwrap());
+        assert!(host.is_name());
+        assert!(!host.is_ip());
+        assert!(!host.is_future());
+
+        let host: UriHost = UriHost::Address(IPv4Address::LOCALHOST.to_ip());
+        assert!(!host.is_name());
+        assert!(host.is_ip());
+        assert!(!host.is_future());
+
+        let host: UriHost = UriHost::Future(IPvFuture::try_from("v1.x").unwrap());
+        assert!(!host.is_name());
+        assert!(!host.is_ip());
+        assert!(host.is_future());
+    }
+}
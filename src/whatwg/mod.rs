@@ -0,0 +1,3 @@
+pub use whatwg_host::*;
+
+mod whatwg_host;
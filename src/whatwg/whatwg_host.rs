@@ -0,0 +1,73 @@
+use crate::Host;
+use alloc::string::String;
+
+/// A host as parsed by the [WHATWG URL Standard](https://url.spec.whatwg.org/#host-parsing).
+///
+/// URLs with special schemes (`http`, `https`, `ws`, `wss`, `ftp`, & `file`) have a domain or an IP address host,
+/// which [`Host::parse_whatwg`] returns as a [Host]. Other schemes have an opaque host: the percent-encoded text the
+/// browser keeps as is, unless it is a bracketed IPv6 address.
+#[must_use]
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum WhatwgHost {
+    /// A domain or an IP address.
+    Host(Host),
+
+    /// An opaque host, percent-encoded with the C0 control percent-encode set. The empty host is `Opaque("")`.
+    Opaque(String),
+}
+
+impl From<Host> for WhatwgHost {
+    fn from(host: Host) -> Self {
+        Self::Host(host)
+    }
+}
+
+impl WhatwgHost {
+    //! Matching
+
+    /// Checks if the host is a domain or an IP address.
+    #[must_use]
+    pub const fn is_host(&self) -> bool {
+        matches!(self, Self::Host(_))
+    }
+
+    /// Checks if the host is opaque.
+    #[must_use]
+    pub const fn is_opaque(&self) -> bool {
+        matches!(self, Self::Opaque(_))
+    }
+}
+
+impl WhatwgHost {
+    //! Conversions
+
+    /// Converts the host to an optional [Host].
+    #[must_use]
+    pub fn to_host(self) -> Option<Host> {
+        if let Self::Host(host) = self { Some(host) } else { None }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Domain, WhatwgHost};
+    use alloc::string::ToString;
+
+    #[test]
+    fn matching() {
+        let host: WhatwgHost = Domain::localhost().to_host().into();
+        assert!(host.is_host());
+        assert!(!host.is_opaque());
+
+        let host: WhatwgHost = WhatwgHost::Opaque("x".to_string());
+        assert!(!host.is_host());
+        assert!(host.is_opaque());
+    }
+
+    #[test]
+    fn to_host() {
+        let host: WhatwgHost = Domain::localhost().to_host().into();
+        assert_eq!(host.to_host(), Some(Domain::localhost().to_host()));
+        assert_eq!(WhatwgHost::Opaque("x".to_string()).to_host(), None);
+    }
+}